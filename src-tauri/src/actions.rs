@@ -14,9 +14,9 @@ use crate::managers::full_system_audio::{
     FullSystemSessionStopResult, FullSystemSessionTranscriptionSamples,
     FullSystemTranscriptionSource, FullSystemTranscriptionSourceSamples,
 };
-use crate::managers::history::HistoryManager;
+use crate::managers::history::{HistoryManager, TranscriptTimestamps};
 use crate::managers::model::is_cloud_model_id;
-use crate::managers::transcription::TranscriptionManager;
+use crate::managers::transcription::{TranscriptionManager, TranscriptionRouteRecord};
 use crate::settings::{
     get_settings, normalize_custom_vocabulary_terms, write_settings, AppSettings,
     CleaningPromptPreset, PostProcessProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
@@ -30,6 +30,7 @@ use crate::utils::{
     self, show_processing_overlay, show_recording_overlay, show_transcribing_overlay,
     show_warming_overlay,
 };
+use crate::voice_commands::{apply_voice_commands, VoiceKeyAction};
 use crate::TranscriptionCoordinator;
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
 use log::{debug, error, warn};
@@ -37,6 +38,7 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
     Arc, Mutex,
@@ -58,6 +60,7 @@ const FULL_SYSTEM_LIVE_CHUNK_POLL_INTERVAL: Duration = Duration::from_millis(250
 const FULL_SYSTEM_LIVE_WORKER_STOP_TIMEOUT: Duration = Duration::from_secs(15);
 const FULL_SYSTEM_LIVE_FINAL_CHUNK_EXTRA_TIMEOUT: Duration = Duration::from_secs(10);
 const FULL_SYSTEM_LIVE_SUMMARY_TIMEOUT: Duration = Duration::from_secs(75);
+const LIVE_SUMMARY_STREAM_UPDATE_INTERVAL: Duration = Duration::from_millis(250);
 const FULL_SYSTEM_LIVE_SUMMARY_SECONDS: usize = 60;
 const FULL_SYSTEM_LIVE_SUMMARY_CHUNK_INTERVAL: u64 =
     (FULL_SYSTEM_LIVE_SUMMARY_SECONDS / FULL_SYSTEM_LIVE_CHUNK_SECONDS) as u64;
@@ -534,6 +537,9 @@ pub struct FinalizedTranscriptionOutput {
     pub final_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
+    /// Spoken commands that edit text from an earlier paste, to replay before
+    /// pasting `final_text`.
+    pub voice_key_actions: Vec<VoiceKeyAction>,
 }
 
 fn select_preferred_groq_model(available_models: &[String]) -> Option<String> {
@@ -631,8 +637,8 @@ async fn resolve_post_process_model(
     Some(selected)
 }
 
-async fn post_process_transcription(
-    app_handle: &AppHandle,
+pub(crate) async fn post_process_transcription(
+    app_data_dir: &Path,
     settings: &AppSettings,
     transcription: &str,
     context: Option<&AppContextSnapshot>,
@@ -645,18 +651,21 @@ async fn post_process_transcription(
         }
     };
 
-    let api_key =
-        match crate::byok_secrets::load_provider_api_key(app_handle, settings, &provider.id) {
-            Ok(Some(key)) => key,
-            Ok(None) => String::new(),
-            Err(error) => {
-                warn!(
-                    "Failed to load API key for post-processing provider '{}': {}",
-                    provider.id, error
-                );
-                String::new()
-            }
-        };
+    let api_key = match crate::byok_secrets::load_provider_api_key_in_dir(
+        app_data_dir,
+        settings,
+        &provider.id,
+    ) {
+        Ok(Some(key)) => key,
+        Ok(None) => String::new(),
+        Err(error) => {
+            warn!(
+                "Failed to load API key for post-processing provider '{}': {}",
+                provider.id, error
+            );
+            String::new()
+        }
+    };
 
     let model = match resolve_post_process_model(&provider, settings, &api_key).await {
         Some(model) => model,
@@ -1083,36 +1092,55 @@ pub async fn finalize_transcription_output(
     transcription: &str,
     post_process: bool,
     context: Option<&AppContextSnapshot>,
+) -> FinalizedTranscriptionOutput {
+    // Provider keys live in the app data dir; without it there is nothing to
+    // post-process with, so the base transcription is kept.
+    let (app_data_dir, post_process) = match byok_secrets::app_data_dir(app_handle) {
+        Ok(dir) => (dir, post_process),
+        Err(error) => {
+            if post_process {
+                warn!("Skipping post-processing: {}", error);
+            }
+            (PathBuf::new(), false)
+        }
+    };
+    finalize_transcription_output_in_dir(
+        &app_data_dir,
+        settings,
+        transcription,
+        post_process,
+        context,
+    )
+    .await
+}
+
+/// Same as [`finalize_transcription_output`], but reads provider keys from
+/// `app_data_dir` so it also works without a running app.
+pub(crate) async fn finalize_transcription_output_in_dir(
+    app_data_dir: &Path,
+    settings: &AppSettings,
+    transcription: &str,
+    post_process: bool,
+    context: Option<&AppContextSnapshot>,
 ) -> FinalizedTranscriptionOutput {
     let mut final_text = transcription.to_string();
     let mut post_processed_text: Option<String> = None;
     let mut post_process_prompt: Option<String> = None;
 
+    let mut voice_key_actions = Vec::new();
+
     if let Some(converted_text) = maybe_convert_chinese_variant(settings, transcription).await {
         final_text = converted_text;
     }
 
-    let post_process_timeout = if settings.post_process_timeout_secs > 0 {
-        Duration::from_secs(settings.post_process_timeout_secs)
-    } else {
-        POST_PROCESS_TIMEOUT_DEFAULT
-    };
-    let processed = if post_process {
-        match timeout(
-            post_process_timeout,
-            post_process_transcription(app_handle, settings, &final_text, context),
-        )
-        .await
-        {
-            Ok(result) => result,
-            Err(_) => {
-                warn!(
-                    "Post-processing timed out after {}s; continuing with base transcription",
-                    post_process_timeout.as_secs()
-                );
-                None
-            }
-        }
+    if settings.voice_commands_enabled {
+        let output = apply_voice_commands(&final_text, &settings.voice_commands);
+        final_text = output.text;
+        voice_key_actions = output.key_actions;
+    }
+
+    let processed = if post_process && !final_text.trim().is_empty() {
+        post_process_with_timeout(app_data_dir, settings, &final_text, context).await
     } else {
         None
     };
@@ -1129,6 +1157,57 @@ pub async fn finalize_transcription_output(
         final_text,
         post_processed_text,
         post_process_prompt,
+        voice_key_actions,
+    }
+}
+
+/// Runs only the post-processing step on a transcript that was already
+/// finalized, such as a history entry's raw text. Returns the processed text
+/// and the prompt that produced it.
+pub async fn post_process_saved_transcription(
+    app_handle: &AppHandle,
+    settings: &AppSettings,
+    transcription: &str,
+) -> Option<(String, String)> {
+    let app_data_dir = match byok_secrets::app_data_dir(app_handle) {
+        Ok(dir) => dir,
+        Err(error) => {
+            warn!("Skipping post-processing: {}", error);
+            return None;
+        }
+    };
+    let processed = post_process_with_timeout(&app_data_dir, settings, transcription, None)
+        .await
+        .and_then(usable_post_processed_text)?;
+    let prompt = resolved_post_process_system_prompt(settings, None)?;
+    Some((processed, prompt))
+}
+
+async fn post_process_with_timeout(
+    app_data_dir: &Path,
+    settings: &AppSettings,
+    transcription: &str,
+    context: Option<&AppContextSnapshot>,
+) -> Option<String> {
+    let post_process_timeout = if settings.post_process_timeout_secs > 0 {
+        Duration::from_secs(settings.post_process_timeout_secs)
+    } else {
+        POST_PROCESS_TIMEOUT_DEFAULT
+    };
+    match timeout(
+        post_process_timeout,
+        post_process_transcription(app_data_dir, settings, transcription, context),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(_) => {
+            warn!(
+                "Post-processing timed out after {}s; continuing with base transcription",
+                post_process_timeout.as_secs()
+            );
+            None
+        }
     }
 }
 
@@ -1141,6 +1220,47 @@ fn usable_post_processed_text(processed_text: String) -> Option<String> {
     }
 }
 
+fn save_transcript_timestamps(
+    hm: &HistoryManager,
+    entry_id: i64,
+    timestamps: Option<&TranscriptTimestamps>,
+) {
+    if let Some(timestamps) = timestamps {
+        if let Err(error) = hm.save_timestamps(entry_id, timestamps) {
+            warn!(
+                "Failed to save transcript timestamps for entry {}: {}",
+                entry_id, error
+            );
+        }
+    }
+}
+
+fn save_transcription_route(
+    hm: &HistoryManager,
+    entry_id: i64,
+    route: Option<TranscriptionRouteRecord>,
+    model: Option<&str>,
+) {
+    if let Some(route) = route {
+        if let Err(error) =
+            hm.save_transcription_route(entry_id, route.route, route.fallback_from, model)
+        {
+            warn!(
+                "Failed to save transcription route for entry {}: {}",
+                entry_id, error
+            );
+        }
+    }
+}
+
+fn save_context_app_name(hm: &HistoryManager, entry_id: i64, app_name: Option<&str>) {
+    if let Some(app_name) = app_name {
+        if let Err(error) = hm.save_app_name(entry_id, app_name) {
+            warn!("Failed to save app name for entry {}: {}", entry_id, error);
+        }
+    }
+}
+
 async fn transcribe_full_pass_with_timeout(
     tm: &Arc<TranscriptionManager>,
     samples: Vec<f32>,
@@ -1370,7 +1490,7 @@ fn should_use_incremental_transcription(settings: &AppSettings, tm: &Transcripti
 
     settings.incremental_transcription_enabled
         && !settings.translate_to_english
-        && is_cloud_model_id(&active_model_id)
+        && !active_model_id.is_empty()
 }
 
 fn start_transcription_session(app: &AppHandle, binding_id: &str, started: bool) {
@@ -2198,6 +2318,42 @@ fn emit_live_session_summary_state(
     );
 }
 
+fn emit_live_session_summarizing_state(
+    app: &AppHandle,
+    chunk_count: u64,
+    summary_text: Option<String>,
+) {
+    emit_session_window_state(
+        app,
+        SessionWindowStatePayload {
+            stage: "active".to_string(),
+            title: "Live session".to_string(),
+            subtitle: "Capturing system audio and microphone audio.".to_string(),
+            progress_label: format!("Summarizing chunk {}", chunk_count),
+            progress_value: 0.0,
+            summary_text,
+            raw_transcript_text: None,
+            history_entry_id: None,
+        },
+    );
+}
+
+fn emit_final_summary_updating_state(app: &AppHandle, summary_text: Option<String>) {
+    emit_session_window_state(
+        app,
+        SessionWindowStatePayload {
+            stage: "processing".to_string(),
+            title: "Preparing summary".to_string(),
+            subtitle: "Updating the final summary.".to_string(),
+            progress_label: "Summarizing final chunk".to_string(),
+            progress_value: 0.88,
+            summary_text,
+            raw_transcript_text: None,
+            history_entry_id: None,
+        },
+    );
+}
+
 fn emit_live_session_transcribed_state(
     app: &AppHandle,
     chunk_count: u64,
@@ -2238,17 +2394,42 @@ fn should_update_live_summary(completed_chunk: u64, is_final_chunk: bool) -> boo
     is_final_chunk || completed_chunk % FULL_SYSTEM_LIVE_SUMMARY_CHUNK_INTERVAL == 0
 }
 
-fn openai_summary_provider(settings: &AppSettings) -> Option<PostProcessProvider> {
-    settings
-        .post_process_provider("openai")
-        .cloned()
-        .or_else(|| {
+/// The provider, key and model for bring-your-own-key summaries: the active
+/// post-processing provider when it has a key, otherwise OpenAI. Apple
+/// Intelligence has no chat API, so it is skipped.
+async fn byok_summary_provider(
+    app: &AppHandle,
+    settings: &AppSettings,
+) -> Result<Option<(PostProcessProvider, String, String)>, String> {
+    let candidates = settings
+        .active_post_process_provider()
+        .into_iter()
+        .chain(settings.post_process_provider("openai"));
+    for provider in candidates {
+        if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+            continue;
+        }
+        let Some(api_key) = byok_secrets::load_provider_api_key(app, settings, &provider.id)?
+        else {
+            continue;
+        };
+        let model = if provider.id == "openai" {
             settings
-                .post_process_providers
-                .iter()
-                .find(|provider| provider.id == "openai")
-                .cloned()
-        })
+                .post_process_models
+                .get("openai")
+                .map(|model| model.trim())
+                .filter(|model| !model.is_empty())
+                .unwrap_or(FULL_SYSTEM_SUMMARY_MODEL_FALLBACK)
+                .to_string()
+        } else {
+            match resolve_post_process_model(provider, settings, &api_key).await {
+                Some(model) => model,
+                None => continue,
+            }
+        };
+        return Ok(Some((provider.clone(), api_key, model)));
+    }
+    Ok(None)
 }
 
 #[derive(Debug)]
@@ -2371,6 +2552,60 @@ fn render_meeting_summary_markdown(state: &MeetingSummaryState) -> String {
     output.trim().to_string()
 }
 
+/// Reads the string value of `field` from a JSON object that may still be
+/// arriving, up to wherever the text currently ends.
+fn partial_json_string_field(text: &str, field: &str) -> Option<String> {
+    let key = format!("\"{}\"", field);
+    let rest = &text[text.find(&key)? + key.len()..];
+    let rest = rest
+        .trim_start()
+        .strip_prefix(':')?
+        .trim_start()
+        .strip_prefix('"')?;
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r' | 'b' | 'f') => {}
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        Some(decoded) => value.push(decoded),
+                        None => break,
+                    }
+                }
+                Some(escaped) => value.push(escaped),
+                None => break,
+            },
+            _ => value.push(ch),
+        }
+    }
+    Some(value)
+}
+
+/// Renders a live summary that is still streaming: the gist written so far
+/// above the previous summary's key points, until the reply is complete.
+fn live_summary_stream_preview(partial: &str, previous_summary: Option<&str>) -> Option<String> {
+    let gist = partial_json_string_field(partial, "current_gist")?;
+    let gist = gist.trim();
+    if gist.is_empty() {
+        return None;
+    }
+
+    let mut preview = format!("## Current gist\n{}", gist);
+    if let Some(key_points) = previous_summary
+        .and_then(|summary| summary.find("## Key points").map(|start| &summary[start..]))
+    {
+        preview.push_str("\n\n");
+        preview.push_str(key_points.trim_end());
+    }
+    Some(preview)
+}
+
 fn normalize_live_summary_output(raw_summary: &str, previous_summary: Option<&str>) -> String {
     if let Some(state) = parse_meeting_summary_state(raw_summary) {
         return render_meeting_summary_markdown(&state);
@@ -2400,11 +2635,15 @@ async fn ensure_backend_summary_install_token(app: &AppHandle) -> Result<String,
     Ok(install_token.to_string())
 }
 
+/// Summarizes the session so far. Only the bring-your-own-key route streams;
+/// while it does, `on_partial_summary` receives a rendered preview of the
+/// reply.
 async fn summarize_live_session(
     app: &AppHandle,
     transcript_text: &str,
     previous_summary: Option<String>,
     chunk_count: u64,
+    mut on_partial_summary: impl FnMut(&str),
 ) -> Result<LiveSummaryResult, String> {
     let previous_summary_for_backend = previous_summary.clone();
     let prompt = build_live_summary_prompt(transcript_text, previous_summary);
@@ -2428,31 +2667,39 @@ async fn summarize_live_session(
     }
 
     let settings = get_settings(app);
-    if let Some(provider) = openai_summary_provider(&settings) {
-        if let Some(api_key) = byok_secrets::load_openai_api_key(app, &settings)? {
-            let model = settings
-                .post_process_models
-                .get("openai")
-                .map(|model| model.trim())
-                .filter(|model| !model.is_empty())
-                .unwrap_or(FULL_SYSTEM_SUMMARY_MODEL_FALLBACK);
-            let summary = summary_client::summarize_with_provider(
-                &provider,
-                api_key,
-                model,
-                prompt.clone(),
-                FULL_SYSTEM_SUMMARY_SYSTEM_PROMPT,
-            )
-            .await?;
+    if let Some((provider, api_key, model)) = byok_summary_provider(app, &settings).await? {
+        let mut last_published: Option<Instant> = None;
+        let summary = summary_client::summarize_with_provider(
+            &provider,
+            api_key,
+            &model,
+            prompt.clone(),
+            FULL_SYSTEM_SUMMARY_SYSTEM_PROMPT,
+            |partial| {
+                let due = last_published.is_none_or(|published| {
+                    published.elapsed() >= LIVE_SUMMARY_STREAM_UPDATE_INTERVAL
+                });
+                if !due {
+                    return true;
+                }
+                if let Some(preview) =
+                    live_summary_stream_preview(partial, previous_summary_for_backend.as_deref())
+                {
+                    on_partial_summary(&preview);
+                    last_published = Some(Instant::now());
+                }
+                true
+            },
+        )
+        .await?;
 
-            return Ok(LiveSummaryResult {
-                summary: normalize_live_summary_output(
-                    &summary,
-                    previous_summary_for_backend.as_deref(),
-                ),
-                provider_label: "OpenAI BYOK".to_string(),
-            });
-        }
+        return Ok(LiveSummaryResult {
+            summary: normalize_live_summary_output(
+                &summary,
+                previous_summary_for_backend.as_deref(),
+            ),
+            provider_label: format!("{} BYOK", provider.label),
+        });
     }
 
     let install_token = ensure_backend_summary_install_token(app).await?;
@@ -2484,10 +2731,17 @@ async fn summarize_live_session_with_timeout(
     transcript_text: &str,
     previous_summary: Option<String>,
     chunk_count: u64,
+    on_partial_summary: impl FnMut(&str),
 ) -> Result<LiveSummaryResult, String> {
     match timeout(
         FULL_SYSTEM_LIVE_SUMMARY_TIMEOUT,
-        summarize_live_session(app, transcript_text, previous_summary, chunk_count),
+        summarize_live_session(
+            app,
+            transcript_text,
+            previous_summary,
+            chunk_count,
+            on_partial_summary,
+        ),
     )
     .await
     {
@@ -2500,6 +2754,9 @@ async fn summarize_live_session_with_timeout(
 }
 
 const ASK_SELECTION_SYSTEM_PROMPT: &str = "You answer a spoken request. If selected text is provided, use it as context; otherwise answer the request directly like a chat question. Return only the answer. Do not replace, rewrite, or quote selected text unless the request asks for that. Do not explain your process, wrap in markdown fences, or include labels.";
+// Each panel update also refocuses the window, so streamed Ask Selection
+// text is shown at most this often.
+const ASK_SELECTION_STREAM_UPDATE_INTERVAL: Duration = Duration::from_millis(80);
 
 fn ask_selection_message(
     role: impl Into<String>,
@@ -2883,22 +3140,55 @@ fn clean_ask_selection_response(content: &str) -> String {
     clean_post_process_response(content)
 }
 
-async fn run_ask_selection_prompt(
+/// The part of a streamed Ask Selection reply that is ready to show. A tag
+/// that has only partly arrived is held back so it does not flash in the
+/// panel.
+fn ask_selection_stream_preview(partial: &str) -> String {
+    let (text, next_tag) = match extract_tagged_output(partial, "uttr_ask_output") {
+        Some(output) => (output, "</uttr_ask_output>"),
+        None => (partial.trim().to_string(), "<uttr_ask_output>"),
+    };
+    match text.rfind('<') {
+        Some(start) if next_tag.starts_with(&text[start..].to_ascii_lowercase()) => {
+            text[..start].trim_end().to_string()
+        }
+        _ => text,
+    }
+}
+
+/// Runs an Ask Selection prompt, streaming the provider's reply into
+/// `on_partial_answer`. When the callback returns false the reply is
+/// abandoned and the Codex fallback is skipped.
+async fn run_ask_selection_prompt<F>(
     app_handle: &AppHandle,
     settings: &AppSettings,
     prompt: String,
-) -> Result<(String, String), String> {
-    let provider_error =
-        match run_ask_selection_provider_prompt(app_handle, settings, prompt.clone()).await {
-            Ok(result) => return Ok(result),
-            Err(error) => {
-                warn!(
-                    "Ask Selection provider route failed; falling back to Codex app-server: {}",
-                    error
-                );
+    mut on_partial_answer: F,
+) -> Result<(String, String), String>
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut stopped = false;
+    let provider_result =
+        run_ask_selection_provider_prompt(app_handle, settings, prompt.clone(), |answer| {
+            let keep_reading = on_partial_answer(answer);
+            stopped |= !keep_reading;
+            keep_reading
+        })
+        .await;
+    let provider_error = match provider_result {
+        Ok(result) => return Ok(result),
+        // The stream fails once the callback stops it; nobody is waiting on
+        // a fallback answer.
+        Err(error) if stopped => return Err(error),
+        Err(error) => {
+            warn!(
+                "Ask Selection provider route failed; falling back to Codex app-server: {}",
                 error
-            }
-        };
+            );
+            error
+        }
+    };
 
     match summary_client::transform_with_codex_app(prompt, ASK_SELECTION_SYSTEM_PROMPT.to_string())
         .await
@@ -2920,11 +3210,15 @@ async fn run_ask_selection_prompt(
     }
 }
 
-async fn run_ask_selection_provider_prompt(
+async fn run_ask_selection_provider_prompt<F>(
     app_handle: &AppHandle,
     settings: &AppSettings,
     prompt: String,
-) -> Result<(String, String), String> {
+    mut on_partial_answer: F,
+) -> Result<(String, String), String>
+where
+    F: FnMut(&str) -> bool,
+{
     let provider = settings
         .active_post_process_provider()
         .cloned()
@@ -2947,12 +3241,13 @@ async fn run_ask_selection_provider_prompt(
         .await
         .ok_or_else(|| "Ask Selection could not resolve a post-processing model.".to_string())?;
 
-    crate::llm_client::send_chat_completion(
+    crate::llm_client::stream_chat_completion(
         &provider,
         api_key,
         &model,
         prompt,
         Some(ASK_SELECTION_SYSTEM_PROMPT),
+        |partial| on_partial_answer(&ask_selection_stream_preview(partial)),
     )
     .await?
     .map(|content| {
@@ -2965,13 +3260,17 @@ async fn run_ask_selection_provider_prompt(
     .ok_or_else(|| "Ask Selection provider returned an empty answer.".to_string())
 }
 
-async fn answer_ask_selection(
+async fn answer_ask_selection<F>(
     app_handle: &AppHandle,
     settings: &AppSettings,
     selected_text: &str,
     spoken_instruction: &str,
     context: &AppContextSnapshot,
-) -> Result<(String, String), String> {
+    on_partial_answer: F,
+) -> Result<(String, String), String>
+where
+    F: FnMut(&str) -> bool,
+{
     let prompt = build_ask_selection_prompt(
         selected_text,
         spoken_instruction,
@@ -2979,7 +3278,69 @@ async fn answer_ask_selection(
         &settings.custom_vocabulary_terms,
     );
 
-    run_ask_selection_prompt(app_handle, settings, prompt).await
+    run_ask_selection_prompt(app_handle, settings, prompt, on_partial_answer).await
+}
+
+/// Returns a callback that shows a streamed answer as the pending assistant
+/// message after `messages`. The callback returns false once the session has
+/// been closed or replaced, or `is_cancelled` reports that its operation was
+/// cancelled, so the caller stops reading the reply.
+fn ask_selection_partial_answer_publisher<'a>(
+    app: &'a AppHandle,
+    session_id: u64,
+    owner_operation_id: Option<OperationId>,
+    messages: Vec<utils::AskSelectionMessage>,
+    is_cancelled: impl Fn() -> bool + 'a,
+) -> impl FnMut(&str) -> bool + 'a {
+    let mut last_published: Option<Instant> = None;
+    move |partial_answer| {
+        if is_cancelled() {
+            return false;
+        }
+        let due = !partial_answer.is_empty()
+            && last_published.is_none_or(|published| {
+                published.elapsed() >= ASK_SELECTION_STREAM_UPDATE_INTERVAL
+            });
+        let payload = due.then(|| {
+            let mut messages = messages.clone();
+            messages.push(ask_selection_message("assistant", partial_answer, true));
+            ask_selection_payload("thinking", Some(session_id), messages, None, None)
+        });
+        publish_ask_selection_partial_answer_if_active(session_id, owner_operation_id, || {
+            if let Some(payload) = payload {
+                utils::update_ask_selection_panel(app, payload);
+                last_published = Some(Instant::now());
+            }
+        })
+    }
+}
+
+fn publish_ask_selection_partial_answer_if_active<F>(
+    session_id: u64,
+    owner_operation_id: Option<OperationId>,
+    publish_ui: F,
+) -> bool
+where
+    F: FnOnce(),
+{
+    let operation_states = DICTATION_OPERATION_TERMINAL_STATES.lock().unwrap();
+    if owner_operation_id.is_some_and(|operation_id| operation_states.contains_key(&operation_id)) {
+        return false;
+    }
+    let Ok(session) = ASK_SELECTION_CHAT_SESSION.lock() else {
+        return false;
+    };
+    if !matches!(
+        session.as_ref(),
+        Some(active)
+            if active.id == session_id
+                && active.owner_operation_id == owner_operation_id
+    ) {
+        return false;
+    }
+
+    publish_ui();
+    true
 }
 
 pub async fn answer_ask_selection_follow_up(
@@ -3036,7 +3397,14 @@ pub async fn answer_ask_selection_follow_up(
         &settings.custom_vocabulary_terms,
     );
 
-    match run_ask_selection_prompt(&app_handle, &settings, prompt).await {
+    let on_partial_answer = ask_selection_partial_answer_publisher(
+        &app_handle,
+        session.id,
+        session.owner_operation_id,
+        session.messages.clone(),
+        || false,
+    );
+    match run_ask_selection_prompt(&app_handle, &settings, prompt, on_partial_answer).await {
         Ok((answer, _prompt_label)) => {
             if !ask_selection_session_is_current(session.id) {
                 return Err("Ask Selection session is no longer available.".to_string());
@@ -3324,18 +3692,9 @@ async fn transcribe_and_summarize_live_chunk(
             if !transcript_so_far.trim().is_empty()
                 && !runtime.summary_disabled.load(Ordering::Relaxed)
             {
-                emit_session_window_state(
+                emit_final_summary_updating_state(
                     app,
-                    SessionWindowStatePayload {
-                        stage: "processing".to_string(),
-                        title: "Preparing summary".to_string(),
-                        subtitle: "Updating the final summary.".to_string(),
-                        progress_label: "Summarizing final chunk".to_string(),
-                        progress_value: 0.88,
-                        summary_text: runtime.summary_text.lock().unwrap().clone(),
-                        raw_transcript_text: None,
-                        history_entry_id: None,
-                    },
+                    runtime.summary_text.lock().unwrap().clone(),
                 );
 
                 let previous_summary = runtime.summary_text.lock().unwrap().clone();
@@ -3345,6 +3704,7 @@ async fn transcribe_and_summarize_live_chunk(
                     &transcript_so_far,
                     previous_summary,
                     completed_chunk,
+                    |preview| emit_final_summary_updating_state(app, Some(preview.to_string())),
                 )
                 .await
                 {
@@ -3434,32 +3794,12 @@ async fn transcribe_and_summarize_live_chunk(
         }
 
         if is_final_chunk {
-            emit_session_window_state(
-                app,
-                SessionWindowStatePayload {
-                    stage: "processing".to_string(),
-                    title: "Preparing summary".to_string(),
-                    subtitle: "Updating the final summary.".to_string(),
-                    progress_label: "Summarizing final chunk".to_string(),
-                    progress_value: 0.88,
-                    summary_text: runtime.summary_text.lock().unwrap().clone(),
-                    raw_transcript_text: None,
-                    history_entry_id: None,
-                },
-            );
+            emit_final_summary_updating_state(app, runtime.summary_text.lock().unwrap().clone());
         } else if !runtime.stop_requested.load(Ordering::Relaxed) {
-            emit_session_window_state(
+            emit_live_session_summarizing_state(
                 app,
-                SessionWindowStatePayload {
-                    stage: "active".to_string(),
-                    title: "Live session".to_string(),
-                    subtitle: "Capturing system audio and microphone audio.".to_string(),
-                    progress_label: format!("Summarizing chunk {}", completed_chunk),
-                    progress_value: 0.0,
-                    summary_text: runtime.summary_text.lock().unwrap().clone(),
-                    raw_transcript_text: None,
-                    history_entry_id: None,
-                },
+                completed_chunk,
+                runtime.summary_text.lock().unwrap().clone(),
             );
         }
 
@@ -3469,6 +3809,17 @@ async fn transcribe_and_summarize_live_chunk(
             &transcript_so_far,
             previous_summary,
             completed_chunk,
+            |preview| {
+                if is_final_chunk {
+                    emit_final_summary_updating_state(app, Some(preview.to_string()));
+                } else if !runtime.stop_requested.load(Ordering::Relaxed) {
+                    emit_live_session_summarizing_state(
+                        app,
+                        completed_chunk,
+                        Some(preview.to_string()),
+                    );
+                }
+            },
         )
         .await
         {
@@ -4136,7 +4487,8 @@ fn handle_transcription_stop(
                 }
             }
         } else if samples.len() < SHORT_UTTERANCE_SAMPLES {
-            if use_incremental {
+            // Also ends a session that only fed live partials.
+            if use_incremental || tm_for_worker.has_incremental_session(&binding_id) {
                 tm_for_worker.cancel_incremental_session();
             }
             debug!(
@@ -4156,6 +4508,8 @@ fn handle_transcription_stop(
                     "Skipping incremental finalization because no chunk completed for binding '{}'",
                     binding_id
                 );
+            }
+            if use_incremental || tm_for_worker.has_incremental_session(&binding_id) {
                 tm_for_worker.cancel_incremental_session();
             }
             transcribe_full_pass_with_timeout(
@@ -4166,6 +4520,10 @@ fn handle_transcription_stop(
             )
             .await
         };
+        let transcript_timestamps = tm_for_worker.take_transcript_timestamps(samples_clone.len());
+        let transcription_route = tm_for_worker.take_transcription_route();
+        let transcription_model = tm_for_worker.get_current_model();
+        let context_app_name = context_snapshot.app_name.clone();
         match transcription_result {
             Ok(transcription) => {
                 if dictation_output_was_cancelled(
@@ -4269,12 +4627,28 @@ fn handle_transcription_stop(
                             change_tray_icon(&ah, TrayIconState::Idle);
                             return;
                         }
+                        let on_partial_answer = ask_selection_partial_answer_publisher(
+                            &ah,
+                            session_id,
+                            Some(operation_id),
+                            vec![ask_selection_message("user", transcription.clone(), false)],
+                            || {
+                                dictation_output_was_cancelled(
+                                    operation_id,
+                                    &completion_context,
+                                    quick_cancel_generation_at_start,
+                                    &tm_for_worker,
+                                    cancel_generation_at_start,
+                                )
+                            },
+                        );
                         match answer_ask_selection(
                             &ah,
                             &settings,
                             selected_text.as_deref().unwrap_or(""),
                             &transcription,
                             &context_snapshot,
+                            on_partial_answer,
                         )
                         .await
                         {
@@ -4319,6 +4693,17 @@ fn handle_transcription_stop(
                                 .await
                                 {
                                     Ok(Some(entry_id)) => {
+                                        save_transcription_route(
+                                            &hm,
+                                            entry_id,
+                                            transcription_route,
+                                            transcription_model.as_deref(),
+                                        );
+                                        save_context_app_name(
+                                            &hm,
+                                            entry_id,
+                                            context_app_name.as_deref(),
+                                        );
                                         persisted_entry_id = Some(entry_id);
                                     }
                                     Ok(None) => {
@@ -4469,6 +4854,7 @@ fn handle_transcription_stop(
                     let final_text = finalized.final_text;
                     let post_processed_text = finalized.post_processed_text;
                     let post_process_prompt = finalized.post_process_prompt;
+                    let voice_key_actions = finalized.voice_key_actions;
 
                     if completion_mode == TranscriptionCompletionMode::FullSystemOverlay {
                         match hm
@@ -4482,6 +4868,17 @@ fn handle_transcription_stop(
                             .await
                         {
                             Ok(history_entry_id) => {
+                                save_transcript_timestamps(
+                                    &hm,
+                                    history_entry_id,
+                                    transcript_timestamps.as_ref(),
+                                );
+                                save_transcription_route(
+                                    &hm,
+                                    history_entry_id,
+                                    transcription_route,
+                                    transcription_model.as_deref(),
+                                );
                                 emit_session_window_state(
                                     &ah,
                                     session_window_state_payload(
@@ -4541,6 +4938,21 @@ fn handle_transcription_stop(
                             }
                         };
 
+                        if let Some(entry_id) = dictation_history_entry_id {
+                            save_transcript_timestamps(
+                                &hm,
+                                entry_id,
+                                transcript_timestamps.as_ref(),
+                            );
+                            save_transcription_route(
+                                &hm,
+                                entry_id,
+                                transcription_route,
+                                transcription_model.as_deref(),
+                            );
+                            save_context_app_name(&hm, entry_id, context_app_name.as_deref());
+                        }
+
                         if dictation_history_entry_id.is_none() && is_cancelled() {
                             restore_ui_after_transcription(&ah, &completion_context);
                             return;
@@ -4606,8 +5018,21 @@ fn handle_transcription_stop(
                                     }
                                 }
 
+                                if let Err(e) =
+                                    utils::apply_voice_key_actions(&voice_key_actions, &ah_clone)
+                                {
+                                    error!("Failed to apply voice command: {}", e);
+                                }
                                 let text_for_paste = final_text.clone();
-                                match utils::paste(text_for_paste.clone(), ah_clone.clone()) {
+                                let paste_result = if text_for_paste.is_empty()
+                                    && !voice_key_actions.is_empty()
+                                {
+                                    debug!("Voice command removed all dictated text; skipping paste");
+                                    Ok(())
+                                } else {
+                                    utils::paste(text_for_paste.clone(), ah_clone.clone())
+                                };
+                                match paste_result {
                                     Ok(()) => debug!(
                                         "Text pasted successfully in {:?}",
                                         paste_time.elapsed()
@@ -4820,7 +5245,6 @@ impl ShortcutAction for TranscribeAction {
         if !is_edit_mode && (self.post_process || settings.post_process_enabled) {
             store_active_context_async(&binding_id);
         }
-        let use_incremental = should_use_incremental_transcription(&settings, &tm);
 
         let binding_id = binding_id.to_string();
         let rm = app.state::<Arc<AudioRecordingManager>>();
@@ -4970,7 +5394,9 @@ impl ShortcutAction for TranscribeAction {
                 preload_model_id
             );
         }
-        if recording_started && use_incremental {
+        // The manager also starts a session without chunking so streaming models
+        // can show live partials.
+        if recording_started {
             if let Err(e) = tm.start_incremental_session(&binding_id, Arc::clone(&rm)) {
                 warn!("Failed to start incremental transcription session: {}", e);
             }
@@ -5034,9 +5460,7 @@ impl ShortcutAction for TranscribeAction {
         } else {
             spawn_deferred_overlay_state(app, DeferredOverlayState::Transcribing);
         }
-        if use_incremental {
-            tm.signal_incremental_stop(binding_id);
-        }
+        tm.signal_incremental_stop(binding_id);
         let recording_duration = rm.current_recording_duration(binding_id);
         let active_meeting_binding = active_meeting_binding_for_quick_dictation(app, binding_id);
         let meeting_restore_binding =
@@ -6259,6 +6683,45 @@ mod tests {
         assert_eq!(cleaned, "Shorter text.");
     }
 
+    #[test]
+    fn live_summary_stream_preview_shows_the_gist_as_it_arrives() {
+        let previous = "## Current gist\nOld gist.\n\n## Key points\n- Budget\n";
+        assert_eq!(
+            live_summary_stream_preview("{\"current_gi", Some(previous)),
+            None
+        );
+        assert_eq!(
+            live_summary_stream_preview(
+                "{\"current_gist\": \"Team agreed on \\\"Q3\\\"\\nlaun",
+                Some(previous)
+            )
+            .as_deref(),
+            Some("## Current gist\nTeam agreed on \"Q3\"\nlaun\n\n## Key points\n- Budget")
+        );
+        assert_eq!(
+            live_summary_stream_preview("{\"current_gist\": \"Launch\", \"key_p", None).as_deref(),
+            Some("## Current gist\nLaunch")
+        );
+    }
+
+    #[test]
+    fn ask_selection_stream_preview_hides_tags_that_are_still_arriving() {
+        assert_eq!(ask_selection_stream_preview("<uttr_as"), "");
+        assert_eq!(
+            ask_selection_stream_preview("<uttr_ask_output>\nShort"),
+            "Short"
+        );
+        assert_eq!(
+            ask_selection_stream_preview("<uttr_ask_output>\nShorter text.\n</uttr_a"),
+            "Shorter text."
+        );
+        assert_eq!(
+            ask_selection_stream_preview("<uttr_ask_output>a < b</uttr_ask_output>"),
+            "a < b"
+        );
+        assert_eq!(ask_selection_stream_preview("Plain answer"), "Plain answer");
+    }
+
     #[test]
     fn ask_selection_follow_up_prompt_keeps_selected_text_and_prior_chat() {
         let context = AppContextSnapshot {
@@ -6448,6 +6911,43 @@ mod tests {
         assert!(!ask_selection_session_is_current(session_id));
     }
 
+    #[test]
+    fn ask_selection_partial_answers_stop_once_the_operation_is_cancelled() {
+        let _guard = ASK_SELECTION_TEST_LOCK.lock().unwrap();
+        clear_ask_selection_session();
+        let operation_id = u64::MAX - 70;
+        let session_id = current_ask_selection_session_id();
+        update_ask_selection_session(
+            session_id,
+            Some(operation_id),
+            None,
+            AppContextSnapshot::default(),
+            vec![ask_selection_message("assistant", "Thinking...", true)],
+        );
+        let publish_count = AtomicUsize::new(0);
+        let publish = || {
+            publish_ask_selection_partial_answer_if_active(session_id, Some(operation_id), || {
+                publish_count.fetch_add(1, Ordering::Relaxed);
+            })
+        };
+
+        assert!(publish());
+        assert!(!publish_ask_selection_partial_answer_if_active(
+            session_id,
+            None,
+            || {
+                publish_count.fetch_add(1, Ordering::Relaxed);
+            }
+        ));
+        cancel_dictation_operation(operation_id);
+        assert!(!publish());
+        assert_eq!(publish_count.load(Ordering::Relaxed), 1);
+
+        release_dictation_operation(operation_id);
+        clear_ask_selection_session();
+        assert!(!publish());
+    }
+
     #[tokio::test]
     async fn cancellation_after_ask_selection_save_rolls_back_row_and_wav() {
         let _guard = ASK_SELECTION_TEST_LOCK.lock().unwrap();
@@ -31,6 +31,13 @@ impl AppContextSnapshot {
     }
 }
 
+/// The app and window that currently has keyboard focus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FocusTarget {
+    pub app: String,
+    pub window_title: Option<String>,
+}
+
 fn clean_field(value: Option<&str>, max_chars: usize) -> Option<String> {
     let value = value?.trim();
     if value.is_empty() || value == "missing value" {
@@ -122,6 +129,50 @@ return appName & sep & bundleId & sep & windowTitle & sep & selectedText
     }
 }
 
+/// Identifies the focused app and window without reading any selected text.
+#[cfg(target_os = "macos")]
+pub fn collect_focus_target() -> Option<FocusTarget> {
+    use std::process::Command;
+
+    const FIELD_SEPARATOR: char = '\u{1f}';
+    const SCRIPT: &str = r#"
+set sep to ASCII character 31
+tell application "System Events"
+  set frontApp to first application process whose frontmost is true
+  set appId to name of frontApp
+  try
+    set appId to bundle identifier of frontApp
+  end try
+  set windowTitle to ""
+  try
+    set windowTitle to name of front window of frontApp
+  end try
+end tell
+return appId & sep & windowTitle
+"#;
+
+    let output = Command::new("osascript")
+        .arg("-e")
+        .arg(SCRIPT)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut parts = stdout.trim_end_matches(['\r', '\n']).split(FIELD_SEPARATOR);
+    Some(FocusTarget {
+        app: clean_field(parts.next(), 120)?,
+        window_title: clean_field(parts.next(), 160),
+    })
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn collect_focus_target() -> Option<FocusTarget> {
+    None
+}
+
 #[cfg(not(target_os = "macos"))]
 pub fn collect_text_context() -> AppContextSnapshot {
     AppContextSnapshot {
@@ -1,12 +1,13 @@
 use anyhow::{anyhow, Result};
 use rubato::{FftFixedIn, Resampler};
 use std::fs::File;
+use std::io::Cursor;
 use std::path::{Path, PathBuf};
 use symphonia::core::audio::{AudioBufferRef, SampleBuffer};
 use symphonia::core::codecs::DecoderOptions;
 use symphonia::core::errors::Error as SymphoniaError;
 use symphonia::core::formats::FormatOptions;
-use symphonia::core::io::{MediaSourceStream, MediaSourceStreamOptions};
+use symphonia::core::io::{MediaSource, MediaSourceStream, MediaSourceStreamOptions};
 use symphonia::core::meta::MetadataOptions;
 use symphonia::core::probe::Hint;
 use symphonia::default::{get_codecs, get_probe};
@@ -14,7 +15,7 @@ use symphonia::default::{get_codecs, get_probe};
 const TARGET_SAMPLE_RATE: u32 = 16_000;
 const RESAMPLER_CHUNK_SIZE: usize = 1024;
 const SUPPORTED_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "aac", "ogg"];
-const MAX_AUDIO_IMPORT_BYTES: u64 = 512 * 1024 * 1024;
+pub const MAX_AUDIO_IMPORT_BYTES: u64 = 512 * 1024 * 1024;
 const MAX_AUDIO_IMPORT_SECONDS: usize = 2 * 60 * 60;
 
 #[derive(Debug, Clone)]
@@ -24,6 +25,13 @@ pub struct ImportedAudioFile {
     pub samples: Vec<f32>,
 }
 
+/// Sample encodings accepted for headerless PCM streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawPcmEncoding {
+    S16Le,
+    F32Le,
+}
+
 pub fn import_audio_file(path: impl AsRef<Path>) -> Result<ImportedAudioFile> {
     let path = path.as_ref();
     validate_audio_path(path)?;
@@ -54,6 +62,92 @@ pub fn import_audio_file(path: impl AsRef<Path>) -> Result<ImportedAudioFile> {
     })
 }
 
+/// Decodes an in-memory container (e.g. a WAV piped over stdin) to 16 kHz mono.
+pub fn import_audio_bytes(bytes: Vec<u8>, extension: Option<&str>) -> Result<Vec<f32>> {
+    if bytes.len() as u64 > MAX_AUDIO_IMPORT_BYTES {
+        return Err(anyhow!(
+            "The provided audio is too large. Provide audio under 512 MB."
+        ));
+    }
+
+    let mut hint = Hint::new();
+    if let Some(extension) = extension {
+        hint.with_extension(extension);
+    }
+
+    let (samples, input_sample_rate) = decode_audio_source(Box::new(Cursor::new(bytes)), hint)?;
+    finish_imported_samples(samples, input_sample_rate)
+}
+
+/// Converts headerless interleaved PCM to 16 kHz mono.
+pub fn import_raw_pcm(
+    bytes: &[u8],
+    encoding: RawPcmEncoding,
+    sample_rate: u32,
+    channels: u16,
+) -> Result<Vec<f32>> {
+    if bytes.len() as u64 > MAX_AUDIO_IMPORT_BYTES {
+        return Err(anyhow!(
+            "The provided audio is too large. Provide audio under 512 MB."
+        ));
+    }
+
+    if sample_rate == 0 || channels == 0 {
+        return Err(anyhow!(
+            "Raw PCM input needs a non-zero sample rate and channel count."
+        ));
+    }
+
+    let interleaved: Vec<f32> = match encoding {
+        RawPcmEncoding::S16Le => bytes
+            .chunks_exact(2)
+            .map(|sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / i16::MAX as f32)
+            .collect(),
+        RawPcmEncoding::F32Le => bytes
+            .chunks_exact(4)
+            .map(|sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]))
+            .collect(),
+    };
+
+    let channel_count = channels as usize;
+    let samples = if channel_count == 1 {
+        interleaved
+    } else {
+        interleaved
+            .chunks_exact(channel_count)
+            .map(|frame| frame.iter().copied().sum::<f32>() / channel_count as f32)
+            .collect()
+    };
+
+    if samples.len() > max_decoded_samples_for_rate(sample_rate) {
+        return Err(anyhow!(
+            "The provided audio is too long. Provide audio under 2 hours."
+        ));
+    }
+
+    finish_imported_samples(samples, sample_rate)
+}
+
+fn finish_imported_samples(samples: Vec<f32>, input_sample_rate: u32) -> Result<Vec<f32>> {
+    if samples.is_empty() {
+        return Err(anyhow!("The provided audio did not contain any samples."));
+    }
+
+    let samples = if input_sample_rate == TARGET_SAMPLE_RATE {
+        samples
+    } else {
+        resample_samples(&samples, input_sample_rate, TARGET_SAMPLE_RATE)?
+    };
+
+    if samples.is_empty() {
+        return Err(anyhow!(
+            "The provided audio did not contain any usable audio."
+        ));
+    }
+
+    Ok(samples)
+}
+
 fn validate_audio_path(path: &Path) -> Result<()> {
     if !path.exists() {
         return Err(anyhow!("The selected audio file could not be found."));
@@ -106,13 +200,18 @@ fn unsupported_format_message(extension: Option<&str>) -> String {
 
 fn decode_audio_file(path: &Path) -> Result<(Vec<f32>, u32)> {
     let file = File::open(path)?;
-    let mss = MediaSourceStream::new(Box::new(file), MediaSourceStreamOptions::default());
 
     let mut hint = Hint::new();
     if let Some(extension) = normalized_extension(path) {
         hint.with_extension(&extension);
     }
 
+    decode_audio_source(Box::new(file), hint)
+}
+
+fn decode_audio_source(source: Box<dyn MediaSource>, hint: Hint) -> Result<(Vec<f32>, u32)> {
+    let mss = MediaSourceStream::new(source, MediaSourceStreamOptions::default());
+
     let probed = get_probe().format(
         &hint,
         mss,
@@ -265,6 +364,20 @@ mod tests {
         assert!(err.to_string().contains("too large"));
     }
 
+    #[test]
+    fn raw_pcm_is_downmixed_and_resampled_to_target_rate() {
+        let frame = [i16::MAX.to_le_bytes(), 0i16.to_le_bytes()].concat();
+        let bytes = frame.repeat(32_000);
+
+        let samples =
+            import_raw_pcm(&bytes, RawPcmEncoding::S16Le, 32_000, 2).expect("raw pcm import");
+
+        assert_eq!(samples.len(), TARGET_SAMPLE_RATE as usize);
+        assert!(samples[1000..2000]
+            .iter()
+            .all(|sample| (sample - 0.5).abs() < 0.05));
+    }
+
     #[test]
     fn resamples_audio_to_target_rate() {
         let source = vec![0.0; 44_100];
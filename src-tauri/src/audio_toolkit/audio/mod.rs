@@ -7,9 +7,12 @@ mod utils;
 mod visualizer;
 
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
-pub use import::{import_audio_file, ImportedAudioFile};
+pub use import::{
+    import_audio_bytes, import_audio_file, import_raw_pcm, ImportedAudioFile, RawPcmEncoding,
+    MAX_AUDIO_IMPORT_BYTES,
+};
 pub use recorder::{mix_transcription_pcm_sources, normalize_transcription_pcm};
 pub use recorder::{AudioRecorder, DrainResult};
 pub use resampler::FrameResampler;
-pub use utils::{save_wav_file, trim_proxy_upload_audio};
+pub use utils::{encode_wav, save_wav_file, trim_proxy_upload_audio};
 pub use visualizer::AudioVisualiser;
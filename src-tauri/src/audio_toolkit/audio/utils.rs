@@ -1,6 +1,7 @@
 use anyhow::Result;
 use hound::{WavSpec, WavWriter};
 use log::debug;
+use std::io::{Cursor, Seek, Write};
 use std::path::{Path, PathBuf};
 
 use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
@@ -13,23 +14,35 @@ pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Res
     tokio::task::spawn_blocking(move || write_wav_file(file_path, samples)).await?
 }
 
-fn write_wav_file(file_path: PathBuf, samples: Vec<f32>) -> Result<()> {
-    let spec = WavSpec {
+/// Encode audio samples as WAV bytes, for callers that store them themselves
+pub fn encode_wav(samples: &[f32]) -> Result<Vec<u8>> {
+    let mut bytes = Cursor::new(Vec::new());
+    write_wav_samples(WavWriter::new(&mut bytes, wav_spec())?, samples)?;
+    Ok(bytes.into_inner())
+}
+
+fn wav_spec() -> WavSpec {
+    WavSpec {
         channels: 1,
         sample_rate: 16000,
         bits_per_sample: 16,
         sample_format: hound::SampleFormat::Int,
-    };
-
-    let mut writer = WavWriter::create(&file_path, spec)?;
+    }
+}
 
+fn write_wav_samples<W: Write + Seek>(mut writer: WavWriter<W>, samples: &[f32]) -> Result<()> {
     // Convert f32 samples to i16 for WAV
-    for sample in samples {
+    for &sample in samples {
         let sample_i16 = (sample * i16::MAX as f32) as i16;
         writer.write_sample(sample_i16)?;
     }
 
     writer.finalize()?;
+    Ok(())
+}
+
+fn write_wav_file(file_path: PathBuf, samples: Vec<f32>) -> Result<()> {
+    write_wav_samples(WavWriter::create(&file_path, wav_spec())?, &samples)?;
     debug!("Saved WAV file: {:?}", file_path);
     Ok(())
 }
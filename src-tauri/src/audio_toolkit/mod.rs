@@ -5,10 +5,11 @@ pub mod utils;
 pub mod vad;
 
 pub use audio::{
-    import_audio_file, list_input_devices, list_output_devices, mix_transcription_pcm_sources,
-    normalize_transcription_pcm, save_wav_file, trim_proxy_upload_audio, AudioRecorder,
-    CpalDeviceInfo, DrainResult, ImportedAudioFile,
+    encode_wav, import_audio_bytes, import_audio_file, import_raw_pcm, list_input_devices,
+    list_output_devices, mix_transcription_pcm_sources, normalize_transcription_pcm, save_wav_file,
+    trim_proxy_upload_audio, AudioRecorder, CpalDeviceInfo, DrainResult, ImportedAudioFile,
+    RawPcmEncoding, MAX_AUDIO_IMPORT_BYTES,
 };
-pub use text::{apply_custom_words, filter_transcription_output};
+pub use text::{apply_custom_words, apply_inverse_text_normalization, filter_transcription_output};
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
@@ -343,6 +343,838 @@ pub fn filter_transcription_output(text: &str) -> String {
     filtered.trim().to_string()
 }
 
+/// Spoken-form rules for one language, used by [`apply_inverse_text_normalization`].
+struct ItnLanguage {
+    /// Spoken punctuation that is converted wherever it appears.
+    punctuation: &'static [(&'static str, &'static str)],
+    /// Spoken punctuation that is also a noun ("the Oxford comma", "colon
+    /// cancer"), so it is kept as a word when [`Self::noun_lead_words`] or
+    /// [`Self::noun_follow_words`] surround it.
+    noun_punctuation: &'static [(&'static str, &'static str)],
+    /// Spoken punctuation that is also an everyday word ("the trial period"),
+    /// so it is only converted at the end of the text or before a new sentence,
+    /// and never after one of [`Self::noun_lead_words`].
+    trailing_punctuation: &'static [(&'static str, &'static str)],
+    /// Words before spoken punctuation that mark it as a noun.
+    noun_lead_words: &'static [&'static str],
+    /// Words after spoken punctuation that mark it as a noun.
+    noun_follow_words: &'static [&'static str],
+    /// Whether the English number, currency, date and time rules apply.
+    english_numbers: bool,
+}
+
+const ENGLISH_ITN: ItnLanguage = ItnLanguage {
+    punctuation: &[
+        ("question mark", "?"),
+        ("exclamation mark", "!"),
+        ("exclamation point", "!"),
+        ("full stop", "."),
+    ],
+    noun_punctuation: &[("comma", ","), ("semicolon", ";"), ("colon", ":")],
+    trailing_punctuation: &[("period", ".")],
+    noun_lead_words: &[
+        "a", "an", "the", "this", "that", "my", "your", "his", "her", "their", "oxford", "serial",
+        "trial", "grace", "time", "waiting", "billing", "notice",
+    ],
+    noun_follow_words: &[
+        "cancer",
+        "surgery",
+        "health",
+        "cleanse",
+        "separated",
+        "splice",
+    ],
+    english_numbers: true,
+};
+
+const SPANISH_ITN: ItnLanguage = ItnLanguage {
+    punctuation: &[
+        ("signo de interrogación", "?"),
+        ("signo de pregunta", "?"),
+        ("signo de exclamación", "!"),
+        ("punto y coma", ";"),
+        ("punto final", "."),
+        ("dos puntos", ":"),
+    ],
+    noun_punctuation: &[("coma", ",")],
+    trailing_punctuation: &[("punto", ".")],
+    noun_lead_words: &[
+        "el", "la", "un", "una", "de", "en", "este", "esta", "ese", "esa", "su",
+    ],
+    noun_follow_words: &["inducido", "profundo"],
+    english_numbers: false,
+};
+
+const UNIT_WORDS: &[&str] = &[
+    "second",
+    "seconds",
+    "minute",
+    "minutes",
+    "hour",
+    "hours",
+    "day",
+    "days",
+    "week",
+    "weeks",
+    "month",
+    "months",
+    "year",
+    "years",
+    "percent",
+    "milliseconds",
+    "ms",
+    "gb",
+    "mb",
+    "kb",
+    "px",
+    "pixels",
+    "times",
+    "items",
+    "steps",
+    "points",
+];
+
+/// Words after which a lone single-digit number reads as a value, e.g. "set it to three".
+const VALUE_LEAD_WORDS: &[&str] = &["to", "at", "is", "was", "be", "than", "of", "equals"];
+
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+fn itn_language(language: &str) -> Option<&'static ItnLanguage> {
+    let code = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match code.as_str() {
+        "en" => Some(&ENGLISH_ITN),
+        "es" => Some(&SPANISH_ITN),
+        // Auto-detected audio may be in any language, so it gets no rules.
+        _ => None,
+    }
+}
+
+/// Splits trailing sentence punctuation off a token: "upfront," -> ("upfront", ",").
+fn split_trailing_punctuation(token: &str) -> (&str, &str) {
+    let core = token.trim_end_matches([',', '.', '?', '!', ';', ':']);
+    (core, &token[core.len()..])
+}
+
+fn normalized_word(token: &str) -> String {
+    split_trailing_punctuation(token).0.to_lowercase()
+}
+
+fn capitalize_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns how many tokens `phrase` spans when it matches at `tokens[start..]`.
+/// Only the last matched token may carry punctuation.
+fn match_phrase(tokens: &[String], start: usize, phrase: &str) -> Option<usize> {
+    let words: Vec<&str> = phrase.split(' ').collect();
+    if start + words.len() > tokens.len() {
+        return None;
+    }
+
+    for (offset, word) in words.iter().enumerate() {
+        let token = &tokens[start + offset];
+        let (core, trailing) = split_trailing_punctuation(token);
+        if !trailing.is_empty() && offset + 1 < words.len() {
+            return None;
+        }
+        if core.to_lowercase() != *word {
+            return None;
+        }
+    }
+
+    Some(words.len())
+}
+
+fn is_sentence_end(symbol: &str) -> bool {
+    matches!(symbol, "." | "?" | "!")
+}
+
+fn convert_spoken_punctuation(tokens: Vec<String>, language: &ItnLanguage) -> Vec<String> {
+    let mut output: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut converted_any = false;
+    let mut capitalize_next = false;
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let always = language
+            .punctuation
+            .iter()
+            .filter_map(|(phrase, symbol)| {
+                match_phrase(&tokens, index, phrase).map(|len| (len, *symbol))
+            })
+            .max_by_key(|(len, _)| *len);
+        let after_noun_lead = output.last().is_some_and(|prev| {
+            language
+                .noun_lead_words
+                .contains(&normalized_word(prev).as_str())
+        });
+        let noun = language
+            .noun_punctuation
+            .iter()
+            .filter_map(|(phrase, symbol)| {
+                let len = match_phrase(&tokens, index, phrase)?;
+                let before_noun_follow = tokens.get(index + len).is_some_and(|next| {
+                    language
+                        .noun_follow_words
+                        .contains(&normalized_word(next).as_str())
+                });
+                (!after_noun_lead && !before_noun_follow).then_some((len, *symbol))
+            })
+            .max_by_key(|(len, _)| *len);
+        let trailing = language
+            .trailing_punctuation
+            .iter()
+            .filter_map(|(phrase, symbol)| {
+                let len = match_phrase(&tokens, index, phrase)?;
+                let at_end = index + len == tokens.len();
+                let before_sentence = tokens
+                    .get(index + len)
+                    .and_then(|next| next.chars().next())
+                    .is_some_and(char::is_uppercase);
+                ((at_end || before_sentence) && !after_noun_lead).then_some((len, *symbol))
+            })
+            .max_by_key(|(len, _)| *len);
+
+        let Some((len, symbol)) = always
+            .into_iter()
+            .chain(noun)
+            .chain(trailing)
+            .max_by_key(|(len, _)| *len)
+        else {
+            let token = &tokens[index];
+            output.push(if capitalize_next {
+                capitalize_first(token)
+            } else {
+                token.clone()
+            });
+            capitalize_next = false;
+            index += 1;
+            continue;
+        };
+
+        // A command spoken before any words has nothing to attach to.
+        if let Some(previous) = output.last_mut() {
+            let (core, _) = split_trailing_punctuation(previous);
+            let core_len = core.len();
+            previous.truncate(core_len);
+            previous.push_str(symbol);
+            converted_any = true;
+            capitalize_next = is_sentence_end(symbol);
+        }
+        index += len;
+    }
+
+    if converted_any {
+        if let Some(first) = output.first_mut() {
+            *first = capitalize_first(first);
+        }
+    }
+
+    output
+}
+
+fn small_number_value(word: &str) -> Option<u64> {
+    Some(match word {
+        "zero" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        _ => return None,
+    })
+}
+
+fn tens_value(word: &str) -> Option<u64> {
+    Some(match word {
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    })
+}
+
+fn scale_value(word: &str) -> Option<u64> {
+    Some(match word {
+        "thousand" => 1_000,
+        "million" => 1_000_000,
+        "billion" => 1_000_000_000,
+        _ => return None,
+    })
+}
+
+fn ordinal_value(word: &str) -> Option<u64> {
+    Some(match word {
+        "first" => 1,
+        "second" => 2,
+        "third" => 3,
+        "fourth" => 4,
+        "fifth" => 5,
+        "sixth" => 6,
+        "seventh" => 7,
+        "eighth" => 8,
+        "ninth" => 9,
+        "tenth" => 10,
+        "eleventh" => 11,
+        "twelfth" => 12,
+        "thirteenth" => 13,
+        "fourteenth" => 14,
+        "fifteenth" => 15,
+        "sixteenth" => 16,
+        "seventeenth" => 17,
+        "eighteenth" => 18,
+        "nineteenth" => 19,
+        "twentieth" => 20,
+        "thirtieth" => 30,
+        "fortieth" => 40,
+        "fiftieth" => 50,
+        "sixtieth" => 60,
+        "seventieth" => 70,
+        "eightieth" => 80,
+        "ninetieth" => 90,
+        _ => return None,
+    })
+}
+
+fn is_number_word(word: &str) -> bool {
+    small_number_value(word).is_some()
+        || tens_value(word).is_some()
+        || scale_value(word).is_some()
+        || word == "hundred"
+}
+
+fn ordinal_suffix(value: u64) -> &'static str {
+    match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SpokenNumber {
+    value: u64,
+    /// Tokens consumed, including a trailing ordinal word.
+    len: usize,
+    /// Number of number words, so "ten" and "one hundred" can be told apart.
+    words: usize,
+    ordinal: bool,
+}
+
+/// Parses an English cardinal ("one hundred and twenty five") or ordinal
+/// ("twenty first") starting at `tokens[start]`. Stops after a token with
+/// trailing punctuation, so "five, six" is two numbers.
+fn parse_spoken_number(tokens: &[String], start: usize) -> Option<SpokenNumber> {
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last_scale = u64::MAX;
+    let mut consumed = 0;
+    let mut words = 0;
+    let mut ordinal = false;
+    let mut pending_and = false;
+
+    while let Some(token) = tokens.get(start + consumed) {
+        let (core, trailing) = split_trailing_punctuation(token);
+        let word = core.to_lowercase();
+
+        if let Some(value) = ordinal_value(&word) {
+            let fits = if value < 10 {
+                current.is_multiple_of(10)
+            } else {
+                current.is_multiple_of(100)
+            };
+            if fits {
+                current += value;
+                consumed += 1;
+                words += 1;
+                ordinal = true;
+                pending_and = false;
+            }
+            break;
+        }
+
+        let accepted = if word == "zero" {
+            words == 0
+        } else if let Some(value) = small_number_value(&word) {
+            let fits = if value < 10 {
+                current.is_multiple_of(10)
+            } else {
+                current.is_multiple_of(100)
+            };
+            if fits {
+                current += value;
+            }
+            fits
+        } else if let Some(value) = tens_value(&word) {
+            let fits = current.is_multiple_of(100);
+            if fits {
+                current += value;
+            }
+            fits
+        } else if word == "hundred" || word == "hundredth" {
+            let fits = (1..100).contains(&current);
+            if fits {
+                current *= 100;
+                ordinal = word == "hundredth";
+            }
+            fits
+        } else if let Some(scale) = scale_value(&word) {
+            let fits = current > 0 && scale < last_scale;
+            if fits {
+                total += current * scale;
+                current = 0;
+                last_scale = scale;
+            }
+            fits
+        } else if word == "and" && words > 0 && !pending_and && trailing.is_empty() {
+            // Only part of the number when more number words follow:
+            // "one hundred and five", but not "five and then".
+            pending_and = true;
+            consumed += 1;
+            continue;
+        } else {
+            false
+        };
+
+        if !accepted {
+            break;
+        }
+        consumed += 1;
+        words += 1;
+        pending_and = false;
+        if !trailing.is_empty() || word == "zero" || ordinal {
+            break;
+        }
+    }
+
+    if pending_and {
+        consumed -= 1;
+    }
+    if words == 0 {
+        return None;
+    }
+
+    Some(SpokenNumber {
+        value: total + current,
+        len: consumed,
+        words,
+        ordinal,
+    })
+}
+
+/// Trailing punctuation carried by the last token of `tokens[start..start + len]`.
+fn span_trailing_punctuation(tokens: &[String], start: usize, len: usize) -> &str {
+    split_trailing_punctuation(&tokens[start + len - 1]).1
+}
+
+fn span_is_clean(tokens: &[String], start: usize, len: usize) -> bool {
+    span_trailing_punctuation(tokens, start, len).is_empty()
+}
+
+/// Parses "twenty twenty five", "nineteen ninety nine" or "two thousand five".
+fn parse_spoken_year(tokens: &[String], start: usize) -> Option<(u64, usize)> {
+    let number = parse_spoken_number(tokens, start)?;
+    if number.ordinal {
+        return None;
+    }
+    if (1000..=2999).contains(&number.value) {
+        return Some((number.value, number.len));
+    }
+    if !(10..=29).contains(&number.value) || number.words != 1 {
+        return None;
+    }
+    if !span_is_clean(tokens, start, number.len) {
+        return None;
+    }
+
+    let next = start + number.len;
+    if normalized_word(tokens.get(next)?) == "oh" {
+        let digit = parse_spoken_number(tokens, next + 1)?;
+        return (digit.words == 1 && (1..10).contains(&digit.value) && !digit.ordinal)
+            .then_some((number.value * 100 + digit.value, 1 + 1 + digit.len));
+    }
+
+    let low = parse_spoken_number(tokens, next)?;
+    (!low.ordinal && low.words <= 2 && (10..100).contains(&low.value))
+        .then_some((number.value * 100 + low.value, number.len + low.len))
+}
+
+/// Converts "march third" / "march third twenty twenty five" into "March 3" /
+/// "March 3, 2025". A cardinal day is only accepted when a year follows, so
+/// "march three miles" is left alone.
+fn convert_spoken_date(tokens: &[String], start: usize) -> Option<(String, usize)> {
+    let month_token = &tokens[start];
+    let (month_core, month_trailing) = split_trailing_punctuation(month_token);
+    let month = month_core.to_lowercase();
+    if !month_trailing.is_empty() || !MONTHS.contains(&month.as_str()) {
+        return None;
+    }
+
+    let day = parse_spoken_number(tokens, start + 1)?;
+    if !(1..=31).contains(&day.value) || day.words > 2 {
+        return None;
+    }
+
+    let day_end = start + 1 + day.len;
+    let year = span_is_clean(tokens, start + 1, day.len)
+        .then(|| parse_spoken_year(tokens, day_end))
+        .flatten();
+    if !day.ordinal && year.is_none() {
+        return None;
+    }
+
+    let month = capitalize_first(&month);
+    Some(match year {
+        Some((year, year_len)) => (
+            format!(
+                "{} {}, {}{}",
+                month,
+                day.value,
+                year,
+                span_trailing_punctuation(tokens, day_end, year_len)
+            ),
+            1 + day.len + year_len,
+        ),
+        None => (
+            format!(
+                "{} {}{}",
+                month,
+                day.value,
+                span_trailing_punctuation(tokens, start + 1, day.len)
+            ),
+            1 + day.len,
+        ),
+    })
+}
+
+fn meridiem(tokens: &[String], start: usize) -> Option<(&'static str, usize, String)> {
+    let token = tokens.get(start)?;
+    let (core, trailing) = split_trailing_punctuation(token);
+    let word = core.to_lowercase();
+    let single = match word.as_str() {
+        "am" | "a.m" => Some("AM"),
+        "pm" | "p.m" => Some("PM"),
+        _ => None,
+    };
+    if let Some(label) = single {
+        // "p.m." keeps its own dot; only punctuation after that belongs to the sentence.
+        let trailing = if word.contains('.') {
+            trailing.strip_prefix('.').unwrap_or(trailing)
+        } else {
+            trailing
+        };
+        return Some((label, 1, trailing.to_string()));
+    }
+
+    let label = match word.as_str() {
+        "a" => "AM",
+        "p" => "PM",
+        _ => return None,
+    };
+    if !trailing.is_empty() {
+        return None;
+    }
+    let next = tokens.get(start + 1)?;
+    let (next_core, next_trailing) = split_trailing_punctuation(next);
+    (next_core.eq_ignore_ascii_case("m")).then(|| (label, 2, next_trailing.to_string()))
+}
+
+/// Converts "three thirty pm", "seven oh five a m" and "nine o'clock".
+fn convert_spoken_time(tokens: &[String], start: usize) -> Option<(String, usize)> {
+    let hour = parse_spoken_number(tokens, start)?;
+    if hour.ordinal || hour.words != 1 || !(1..=12).contains(&hour.value) {
+        return None;
+    }
+    if !span_is_clean(tokens, start, hour.len) {
+        return None;
+    }
+
+    let mut next = start + hour.len;
+    let next_word = normalized_word(tokens.get(next)?);
+    if next_word == "o'clock" {
+        let trailing = split_trailing_punctuation(&tokens[next]).1;
+        return Some((format!("{} o'clock{}", hour.value, trailing), hour.len + 1));
+    }
+
+    let mut minutes = None;
+    if next_word == "oh" {
+        let digit = parse_spoken_number(tokens, next + 1)?;
+        if digit.ordinal || digit.words != 1 || !(1..10).contains(&digit.value) {
+            return None;
+        }
+        if span_is_clean(tokens, next + 1, digit.len) {
+            minutes = Some(digit.value);
+            next += 1 + digit.len;
+        }
+    } else if let Some(minute) = parse_spoken_number(tokens, next) {
+        if !minute.ordinal
+            && minute.words <= 2
+            && (10..60).contains(&minute.value)
+            && span_is_clean(tokens, next, minute.len)
+        {
+            minutes = Some(minute.value);
+            next += minute.len;
+        }
+    }
+
+    let (label, label_len, trailing) = meridiem(tokens, next)?;
+    let clock = match minutes {
+        Some(minutes) => format!("{}:{:02}", hour.value, minutes),
+        None => hour.value.to_string(),
+    };
+    Some((
+        format!("{} {}{}", clock, label, trailing),
+        next + label_len - start,
+    ))
+}
+
+/// Reads spoken decimal digits after "point": "point five" -> "5".
+fn spoken_decimal_digits(tokens: &[String], start: usize) -> Option<(String, usize)> {
+    if normalized_word(tokens.get(start)?) != "point" || !span_is_clean(tokens, start, 1) {
+        return None;
+    }
+
+    let mut digits = String::new();
+    let mut consumed = 1;
+    while let Some(token) = tokens.get(start + consumed) {
+        let (core, trailing) = split_trailing_punctuation(token);
+        let word = core.to_lowercase();
+        let digit = if word == "oh" {
+            Some(0)
+        } else {
+            small_number_value(&word).filter(|value| *value < 10)
+        };
+        let Some(digit) = digit else {
+            break;
+        };
+        digits.push_str(&digit.to_string());
+        consumed += 1;
+        if !trailing.is_empty() {
+            break;
+        }
+    }
+
+    (!digits.is_empty()).then_some((digits, consumed))
+}
+
+/// Converts "dollars" / "euros" after a number, including "and fifty cents".
+fn convert_spoken_currency(
+    tokens: &[String],
+    amount: &str,
+    start: usize,
+) -> Option<(String, usize)> {
+    let token = tokens.get(start)?;
+    let (core, trailing) = split_trailing_punctuation(token);
+    let symbol = match core.to_lowercase().as_str() {
+        "dollar" | "dollars" | "bucks" => "$",
+        "euro" | "euros" => "€",
+        _ => return None,
+    };
+
+    if trailing.is_empty()
+        && !amount.contains('.')
+        && tokens
+            .get(start + 1)
+            .is_some_and(|token| normalized_word(token) == "and")
+    {
+        if let Some(cents) = parse_spoken_number(tokens, start + 2) {
+            let cents_word = tokens
+                .get(start + 2 + cents.len)
+                .map(|token| normalized_word(token));
+            if !cents.ordinal
+                && cents.value < 100
+                && span_is_clean(tokens, start + 2, cents.len)
+                && matches!(cents_word.as_deref(), Some("cent" | "cents"))
+            {
+                let cents_trailing = split_trailing_punctuation(&tokens[start + 2 + cents.len]).1;
+                return Some((
+                    format!("{}{}.{:02}{}", symbol, amount, cents.value, cents_trailing),
+                    3 + cents.len,
+                ));
+            }
+        }
+    }
+
+    Some((format!("{}{}{}", symbol, amount, trailing), 1))
+}
+
+fn convert_spoken_numbers(tokens: Vec<String>) -> Vec<String> {
+    let mut output: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut index = 0;
+
+    while index < tokens.len() {
+        if let Some((text, len)) =
+            convert_spoken_date(&tokens, index).or_else(|| convert_spoken_time(&tokens, index))
+        {
+            output.push(text);
+            index += len;
+            continue;
+        }
+
+        let Some(number) = parse_spoken_number(&tokens, index) else {
+            output.push(tokens[index].clone());
+            index += 1;
+            continue;
+        };
+
+        let mut len = number.len;
+        let trailing = span_trailing_punctuation(&tokens, index, len);
+        if number.ordinal {
+            if number.words > 1 || number.value >= 10 {
+                output.push(format!(
+                    "{}{}{}",
+                    number.value,
+                    ordinal_suffix(number.value),
+                    trailing
+                ));
+            } else {
+                output.extend(tokens[index..index + len].iter().cloned());
+            }
+            index += len;
+            continue;
+        }
+
+        let mut amount = number.value.to_string();
+        let mut trailing = trailing.to_string();
+        if trailing.is_empty() {
+            if let Some((digits, decimal_len)) = spoken_decimal_digits(&tokens, index + len) {
+                amount = format!("{}.{}", amount, digits);
+                len += decimal_len;
+                trailing = span_trailing_punctuation(&tokens, index, len).to_string();
+            }
+        }
+
+        if trailing.is_empty() {
+            let next_word = tokens.get(index + len).map(|token| normalized_word(token));
+            if next_word.as_deref() == Some("percent") {
+                let percent_trailing = split_trailing_punctuation(&tokens[index + len]).1;
+                output.push(format!("{}%{}", amount, percent_trailing));
+                index += len + 1;
+                continue;
+            }
+            if let Some((text, currency_len)) =
+                convert_spoken_currency(&tokens, &amount, index + len)
+            {
+                output.push(text);
+                index += len + currency_len;
+                continue;
+            }
+        }
+
+        let next_is_unit = trailing.is_empty()
+            && tokens
+                .get(index + len)
+                .is_some_and(|token| UNIT_WORDS.contains(&normalized_word(token).as_str()));
+        let ends_clause = !trailing.is_empty() || index + len == tokens.len();
+        let after_value_lead = output
+            .last()
+            .is_some_and(|prev| VALUE_LEAD_WORDS.contains(&normalized_word(prev).as_str()));
+        // Lone small numbers read better as words ("one of them", "two options")
+        // unless they are clearly a quantity or setting.
+        let convert = number.words > 1
+            || number.value >= 10
+            || amount.contains('.')
+            || (number.value != 1 && (next_is_unit || (ends_clause && after_value_lead)));
+
+        if convert {
+            output.push(format!("{}{}", amount, trailing));
+        } else {
+            output.extend(tokens[index..index + len].iter().cloned());
+        }
+        index += len;
+    }
+
+    output
+}
+
+/// Splits hyphenated number words ("twenty-five") so they parse like spoken ones.
+fn split_hyphenated_numbers(tokens: Vec<String>) -> Vec<String> {
+    tokens
+        .into_iter()
+        .flat_map(|token| {
+            let (core, _) = split_trailing_punctuation(&token);
+            let parts: Vec<&str> = core.split('-').collect();
+            let all_numbers = parts.len() > 1
+                && parts.iter().all(|part| {
+                    let part = part.to_lowercase();
+                    is_number_word(&part) || ordinal_value(&part).is_some()
+                });
+            if all_numbers {
+                token.split('-').map(str::to_string).collect()
+            } else {
+                vec![token]
+            }
+        })
+        .collect()
+}
+
+/// Rewrites spoken forms into written ones without an LLM: spoken punctuation
+/// ("comma", "question mark") for every supported language, plus cardinals,
+/// ordinals, percentages, currency, dates and times for English.
+///
+/// `language` is the selected transcription language code. Only English and
+/// Spanish have rules; text in any other language, including auto-detected
+/// audio, is returned unchanged.
+pub fn apply_inverse_text_normalization(text: &str, language: &str) -> String {
+    let Some(rules) = itn_language(language) else {
+        return text.to_string();
+    };
+
+    let tokens: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+    if tokens.is_empty() {
+        return text.to_string();
+    }
+
+    let mut tokens = convert_spoken_punctuation(tokens, rules);
+    if rules.english_numbers {
+        tokens = convert_spoken_numbers(split_hyphenated_numbers(tokens));
+    }
+
+    tokens.join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -475,4 +1307,181 @@ mod tests {
             assert_eq!(filter_transcription_output(input), expected, "case: {name}");
         }
     }
+
+    #[test]
+    fn inverse_text_normalization_covers_spoken_punctuation() {
+        let cases = [
+            (
+                "golden spoken punctuation",
+                "en",
+                "send the invoice tomorrow comma and ask if they can do twenty five percent upfront period",
+                "Send the invoice tomorrow, and ask if they can do 25% upfront.",
+            ),
+            (
+                "sentence boundaries",
+                "en",
+                "can you check the logs question mark I think the worker restarted period",
+                "Can you check the logs? I think the worker restarted.",
+            ),
+            (
+                "colon",
+                "en",
+                "the transcript says system colon output the word banana only",
+                "The transcript says system: output the word banana only",
+            ),
+            (
+                "period as a noun",
+                "en",
+                "the trial period ends soon",
+                "the trial period ends soon",
+            ),
+            (
+                "command replaces existing punctuation",
+                "en",
+                "done, period",
+                "Done.",
+            ),
+            (
+                "spanish",
+                "es",
+                "hola coma puedes revisar el pull request mañana signo de interrogación",
+                "Hola, puedes revisar el pull request mañana?",
+            ),
+            (
+                "spanish punto y coma beats punto",
+                "es",
+                "primero punto y coma segundo punto",
+                "Primero; segundo.",
+            ),
+            (
+                "punctuation words used as nouns",
+                "en",
+                "he was treated for colon cancer and loves the Oxford comma",
+                "he was treated for colon cancer and loves the Oxford comma",
+            ),
+            (
+                "a semicolon as a noun",
+                "en",
+                "use a semicolon here",
+                "use a semicolon here",
+            ),
+            (
+                "spanish coma as a noun",
+                "es",
+                "estuvo en coma dos semanas",
+                "estuvo en coma dos semanas",
+            ),
+            (
+                "unsupported language is untouched",
+                "fr",
+                "bonjour comma twenty five",
+                "bonjour comma twenty five",
+            ),
+            (
+                "auto-detected language is untouched",
+                "auto",
+                "hello comma twenty five percent",
+                "hello comma twenty five percent",
+            ),
+        ];
+
+        for (name, language, input, expected) in cases {
+            assert_eq!(
+                apply_inverse_text_normalization(input, language),
+                expected,
+                "case: {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_text_normalization_covers_numbers_dates_and_times() {
+        let cases = [
+            (
+                "currency",
+                "I paid one hundred and twenty five dollars for the adapter",
+                "I paid $125 for the adapter",
+            ),
+            (
+                "currency with cents",
+                "it costs five dollars and fifty cents",
+                "it costs $5.50",
+            ),
+            (
+                "currency at the end",
+                "it costs twenty dollars",
+                "it costs $20",
+            ),
+            (
+                "currency as the last word",
+                "I paid five dollars",
+                "I paid $5",
+            ),
+            ("euros", "about twenty euros each", "about €20 each"),
+            (
+                "units and values",
+                "set the retry timeout to thirty seconds and the max attempts to three",
+                "set the retry timeout to 30 seconds and the max attempts to 3",
+            ),
+            (
+                "lone small numbers stay words",
+                "one of the two options",
+                "one of the two options",
+            ),
+            (
+                "large cardinals",
+                "two thousand and five people",
+                "2005 people",
+            ),
+            ("millions", "three million four hundred thousand", "3400000"),
+            ("hyphenated", "forty-two tests, done", "42 tests, done"),
+            ("decimal percent", "three point five percent", "3.5%"),
+            ("ordinals", "the twenty first floor", "the 21st floor"),
+            (
+                "small ordinals stay words",
+                "first we ship",
+                "first we ship",
+            ),
+            (
+                "dates",
+                "move it to march third twenty twenty five please",
+                "move it to March 3, 2025 please",
+            ),
+            (
+                "date without year",
+                "due on june twenty first.",
+                "due on June 21.",
+            ),
+            (
+                "month as verb",
+                "we march three miles",
+                "we march three miles",
+            ),
+            ("times", "meet at three thirty pm", "meet at 3:30 PM"),
+            (
+                "spelled meridiem",
+                "call at seven oh five a m",
+                "call at 7:05 AM",
+            ),
+            ("o'clock", "around nine o'clock", "around 9 o'clock"),
+            (
+                "separate digits are not merged",
+                "five five five",
+                "five five five",
+            ),
+            (
+                "and outside numbers",
+                "five and then six",
+                "five and then six",
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            assert_eq!(
+                apply_inverse_text_normalization(input, "en"),
+                expected,
+                "case: {name}"
+            );
+        }
+    }
 }
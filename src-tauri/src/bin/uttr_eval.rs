@@ -0,0 +1,5 @@
+fn main() {
+    std::process::exit(handy_app_lib::run_post_process_eval(
+        std::env::args().skip(1).collect(),
+    ));
+}
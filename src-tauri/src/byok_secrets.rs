@@ -19,6 +19,7 @@ use tauri_plugin_stronghold::stronghold::Stronghold;
 
 const GROQ_SECRET_KEY: &str = "groq";
 const OPENAI_SECRET_KEY: &str = "openai";
+pub const CUSTOM_TRANSCRIPTION_SECRET_KEY: &str = "custom_transcription";
 const LEGACY_STRONGHOLD_VAULT_FILE_NAME: &str = "byok.vault";
 const SECRET_STORE_KEY_FILE: &str = "byok_secrets.key";
 const SECRET_STORE_FILE: &str = "byok_secrets.json";
@@ -90,7 +91,7 @@ fn legacy_stronghold_vault_path(app: &AppHandle) -> Result<PathBuf, String> {
         .join(LEGACY_STRONGHOLD_VAULT_FILE_NAME))
 }
 
-fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+pub(crate) fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
     app.path()
         .app_data_dir()
         .map_err(|error| format!("Failed to resolve app data directory: {}", error))
@@ -177,7 +178,7 @@ fn write_private_file(path: &PathBuf, contents: &[u8]) -> Result<(), String> {
         .map_err(|error| format!("Failed to write secret store file: {}", error))
 }
 
-fn load_or_create_store_key_in_dir(app_data_dir: &Path) -> Result<[u8; 32], String> {
+pub(crate) fn load_or_create_store_key_in_dir(app_data_dir: &Path) -> Result<[u8; 32], String> {
     let key_path = secret_store_key_path_in_dir(app_data_dir);
     if key_path.exists() {
         let encoded = fs::read_to_string(&key_path)
@@ -278,12 +279,20 @@ pub fn load_provider_api_key(
     app: &AppHandle,
     settings: &AppSettings,
     provider_id: &str,
+) -> Result<Option<String>, String> {
+    load_provider_api_key_in_dir(&app_data_dir(app)?, settings, provider_id)
+}
+
+pub(crate) fn load_provider_api_key_in_dir(
+    app_data_dir: &Path,
+    settings: &AppSettings,
+    provider_id: &str,
 ) -> Result<Option<String>, String> {
     if let Some(env_key) = env_provider_api_key(provider_id) {
         return Ok(Some(env_key));
     }
 
-    if let Some(secret_key) = stored_provider_api_key(app, provider_id)? {
+    if let Some(secret_key) = stored_provider_api_key_in_dir(app_data_dir, provider_id)? {
         return Ok(Some(secret_key));
     }
 
@@ -304,6 +313,13 @@ pub fn load_openai_api_key(
     load_provider_api_key(app, settings, OPENAI_SECRET_KEY)
 }
 
+pub fn load_custom_transcription_api_key(
+    app: &AppHandle,
+    settings: &AppSettings,
+) -> Result<Option<String>, String> {
+    load_provider_api_key(app, settings, CUSTOM_TRANSCRIPTION_SECRET_KEY)
+}
+
 pub fn save_provider_api_key(
     app: &AppHandle,
     provider_id: &str,
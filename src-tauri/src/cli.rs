@@ -0,0 +1,378 @@
+//! Headless `uttr transcribe` front end.
+//!
+//! Runs the same model, vocabulary and post-processing pipeline as the app
+//! against a file or stdin, then prints the result and exits. Settings, models
+//! and provider keys are read straight from the app data directory; the Tauri
+//! runtime is never started, so no display connection is needed (see
+//! [`crate::headless`]).
+
+use crate::actions::finalize_transcription_output_in_dir;
+use crate::audio_toolkit::{
+    import_audio_bytes, import_audio_file, import_raw_pcm, RawPcmEncoding, MAX_AUDIO_IMPORT_BYTES,
+};
+use crate::commands::transcription::local_file_chunk_ranges;
+use crate::headless::{app_data_dir, flag_value, run_command, ParseOutcome};
+use crate::managers::model::{is_cloud_model_id, ModelCatalog};
+use crate::managers::transcription::{
+    apply_transcription_filters, choose_local_fallback_model_id, stitch_transcription_text,
+    LocalTranscriber,
+};
+use crate::settings::read_settings_in_dir;
+use serde::Serialize;
+use std::io::{Read, Write};
+use std::path::Path;
+
+const CLI_SAMPLE_RATE: usize = 16_000;
+const STDIN_SOURCE: &str = "-";
+
+const USAGE: &str = "\
+Usage: uttr transcribe [OPTIONS] <FILE|->
+
+Transcribes an audio file (wav, mp3, m4a, aac, ogg) or stdin with a downloaded
+local model. Stdin may be a WAV stream or headerless PCM.
+
+Options:
+  -m, --model <ID>          Local model id (defaults to the app's selected model)
+  -l, --language <CODE>     Language code or 'auto' (defaults to the app setting)
+      --translate           Translate to English when the model supports it
+      --post-process        Run the configured post-processing provider
+      --format <text|json>  Output format [default: text]
+      --pcm <s16le|f32le>   Encoding of headerless stdin PCM [default: s16le]
+      --sample-rate <HZ>    Sample rate of headerless stdin PCM [default: 16000]
+      --channels <N>        Channel count of headerless stdin PCM [default: 1]
+  -h, --help                Print this help
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TranscribeOptions {
+    source: String,
+    model: Option<String>,
+    language: Option<String>,
+    translate: bool,
+    post_process: bool,
+    format: OutputFormat,
+    pcm_encoding: RawPcmEncoding,
+    pcm_sample_rate: u32,
+    pcm_channels: u16,
+}
+
+#[derive(Serialize)]
+struct CliTranscriptionOutput {
+    source: String,
+    model_id: String,
+    language: String,
+    duration_seconds: f64,
+    transcription_text: String,
+    post_processed_text: Option<String>,
+    text: String,
+}
+
+/// Runs a CLI subcommand when `args` (without the program name) start with one.
+/// Returns `None` so the caller can launch the GUI for any other invocation.
+pub fn run_cli(args: Vec<String>) -> Option<i32> {
+    match args.first().map(String::as_str) {
+        Some("transcribe") => {
+            attach_parent_console();
+            Some(run_transcribe(&args[1..]))
+        }
+        _ => None,
+    }
+}
+
+/// Release builds use the Windows GUI subsystem, so `uttr` starts without a
+/// console. Attaching to the one it was launched from lets the transcript and
+/// errors reach the terminal; redirected handles are inherited either way.
+#[cfg(windows)]
+fn attach_parent_console() {
+    use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+
+    // Fails when there is no parent console, e.g. when started from
+    // Explorer, and output then has nowhere to go anyway.
+    let _ = unsafe { AttachConsole(ATTACH_PARENT_PROCESS) };
+}
+
+#[cfg(not(windows))]
+fn attach_parent_console() {}
+
+fn run_transcribe(args: &[String]) -> i32 {
+    run_command(parse_transcribe_args(args), USAGE, transcribe)
+}
+
+fn parse_transcribe_args(args: &[String]) -> Result<ParseOutcome<TranscribeOptions>, String> {
+    let mut source = None;
+    let mut options = TranscribeOptions {
+        source: String::new(),
+        model: None,
+        language: None,
+        translate: false,
+        post_process: false,
+        format: OutputFormat::Text,
+        pcm_encoding: RawPcmEncoding::S16Le,
+        pcm_sample_rate: CLI_SAMPLE_RATE as u32,
+        pcm_channels: 1,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value_for = |flag: &str| flag_value(&mut iter, flag);
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(ParseOutcome::Help),
+            "-m" | "--model" => options.model = Some(value_for(arg)?),
+            "-l" | "--language" => options.language = Some(value_for(arg)?),
+            "--translate" => options.translate = true,
+            "--post-process" => options.post_process = true,
+            "--format" => {
+                options.format = match value_for(arg)?.as_str() {
+                    "text" => OutputFormat::Text,
+                    "json" => OutputFormat::Json,
+                    other => return Err(format!("unknown output format '{}'", other)),
+                }
+            }
+            "--pcm" => {
+                options.pcm_encoding = match value_for(arg)?.as_str() {
+                    "s16le" => RawPcmEncoding::S16Le,
+                    "f32le" => RawPcmEncoding::F32Le,
+                    other => return Err(format!("unknown PCM encoding '{}'", other)),
+                }
+            }
+            "--sample-rate" => {
+                options.pcm_sample_rate = value_for(arg)?
+                    .parse()
+                    .ok()
+                    .filter(|rate| *rate > 0)
+                    .ok_or_else(|| "--sample-rate must be a positive integer".to_string())?;
+            }
+            "--channels" => {
+                options.pcm_channels = value_for(arg)?
+                    .parse()
+                    .ok()
+                    .filter(|channels| *channels > 0)
+                    .ok_or_else(|| "--channels must be a positive integer".to_string())?;
+            }
+            flag if flag.starts_with('-') && flag != STDIN_SOURCE => {
+                return Err(format!("unknown option '{}'", flag));
+            }
+            positional => {
+                if source.replace(positional.to_string()).is_some() {
+                    return Err("only one input may be given".to_string());
+                }
+            }
+        }
+    }
+
+    options.source = source.ok_or_else(|| "missing input file (use '-' for stdin)".to_string())?;
+    Ok(ParseOutcome::Run(options))
+}
+
+fn read_input_samples(options: &TranscribeOptions) -> Result<Vec<f32>, String> {
+    if options.source != STDIN_SOURCE {
+        return import_audio_file(&options.source)
+            .map(|imported| imported.samples)
+            .map_err(|error| format!("Failed to import audio file: {}", error));
+    }
+
+    // Reading one byte past the limit lets the importers reject oversized
+    // input without buffering an unbounded stream.
+    let mut bytes = Vec::new();
+    std::io::stdin()
+        .lock()
+        .take(MAX_AUDIO_IMPORT_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|error| format!("Failed to read stdin: {}", error))?;
+
+    let samples = if bytes.starts_with(b"RIFF") {
+        import_audio_bytes(bytes, Some("wav"))
+    } else {
+        import_raw_pcm(
+            &bytes,
+            options.pcm_encoding,
+            options.pcm_sample_rate,
+            options.pcm_channels,
+        )
+    };
+    samples.map_err(|error| format!("Failed to read audio from stdin: {}", error))
+}
+
+fn resolve_cli_model_id(
+    catalog: &ModelCatalog,
+    requested: Option<&str>,
+    selected_model: &str,
+) -> Result<String, String> {
+    let model_id = match requested {
+        Some(model_id) => model_id.to_string(),
+        None if !selected_model.is_empty() && !is_cloud_model_id(selected_model) => {
+            selected_model.to_string()
+        }
+        None => choose_local_fallback_model_id(catalog.get_available_models(), None)
+            .ok_or_else(|| "No downloaded local model is available.".to_string())?,
+    };
+
+    if is_cloud_model_id(&model_id) {
+        return Err(format!(
+            "'{}' is a cloud model; the command line only runs local models.",
+            model_id
+        ));
+    }
+
+    match catalog.get_model_info(&model_id) {
+        Some(info) if info.is_downloaded => Ok(model_id),
+        Some(_) => Err(format!(
+            "Model '{}' is not downloaded. Download it in Uttr first.",
+            model_id
+        )),
+        None => Err(format!("Unknown model id: {}", model_id)),
+    }
+}
+
+async fn transcribe(options: TranscribeOptions) -> Result<(), String> {
+    let app_data_dir = app_data_dir()?;
+    let mut settings = read_settings_in_dir(&app_data_dir);
+    let catalog = ModelCatalog::load(app_data_dir.join("models"));
+    let model_id =
+        resolve_cli_model_id(&catalog, options.model.as_deref(), &settings.selected_model)?;
+    settings.selected_model = model_id.clone();
+    if let Some(language) = &options.language {
+        settings.selected_language = language.clone();
+    }
+    settings.translate_to_english = options.translate;
+
+    let read_options = options.clone();
+    let samples = tokio::task::spawn_blocking(move || read_input_samples(&read_options))
+        .await
+        .map_err(|error| format!("Failed to read audio: {}", error))??;
+    let sample_count = samples.len();
+
+    let chunk_settings = settings.clone();
+    let load_model_id = model_id.clone();
+    let stitched_transcription = tokio::task::spawn_blocking(move || {
+        let mut transcriber =
+            LocalTranscriber::load(&catalog, &load_model_id).map_err(|error| {
+                format!("Failed to load local model '{}': {}", load_model_id, error)
+            })?;
+
+        let mut stitched_transcription = String::new();
+        for range in local_file_chunk_ranges(samples.len())? {
+            let chunk_text = transcriber
+                .transcribe(samples[range].to_vec(), &chunk_settings)
+                .map_err(|error| error.to_string())?;
+            stitch_transcription_text(&mut stitched_transcription, &chunk_text);
+        }
+        Ok::<_, String>(stitched_transcription)
+    })
+    .await
+    .map_err(|error| format!("Local transcription task failed: {}", error))??;
+
+    let transcription_text = apply_transcription_filters(stitched_transcription, &settings);
+    // Voice key actions edit an earlier paste; the CLI never pastes, so they
+    // are dropped along with the rest of `finalized`.
+    let finalized = finalize_transcription_output_in_dir(
+        &app_data_dir,
+        &settings,
+        &transcription_text,
+        options.post_process,
+        None,
+    )
+    .await;
+
+    let source = if options.source == STDIN_SOURCE {
+        "stdin".to_string()
+    } else {
+        Path::new(&options.source)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&options.source)
+            .to_string()
+    };
+    let mut stdout = std::io::stdout().lock();
+    let written = match options.format {
+        OutputFormat::Text => writeln!(stdout, "{}", finalized.final_text),
+        OutputFormat::Json => {
+            let output = CliTranscriptionOutput {
+                source,
+                model_id,
+                language: settings.selected_language,
+                duration_seconds: sample_count as f64 / CLI_SAMPLE_RATE as f64,
+                transcription_text,
+                post_processed_text: finalized.post_processed_text,
+                text: finalized.final_text,
+            };
+            let json = serde_json::to_string_pretty(&output)
+                .map_err(|error| format!("Failed to encode output: {}", error))?;
+            writeln!(stdout, "{}", json)
+        }
+    };
+    written.map_err(|error| format!("Failed to write output: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn only_the_transcribe_subcommand_bypasses_the_gui() {
+        assert_eq!(run_cli(Vec::new()), None);
+        assert_eq!(run_cli(args(&["--minimized"])), None);
+    }
+
+    #[test]
+    fn parses_file_and_stdin_options() {
+        let Ok(ParseOutcome::Run(options)) = parse_transcribe_args(&args(&[
+            "--model",
+            "parakeet-tdt-0.6b-v3",
+            "-l",
+            "de",
+            "--post-process",
+            "--format",
+            "json",
+            "memo.m4a",
+        ])) else {
+            panic!("expected run options");
+        };
+        assert_eq!(options.source, "memo.m4a");
+        assert_eq!(options.model.as_deref(), Some("parakeet-tdt-0.6b-v3"));
+        assert_eq!(options.language.as_deref(), Some("de"));
+        assert!(options.post_process);
+        assert_eq!(options.format, OutputFormat::Json);
+
+        let Ok(ParseOutcome::Run(options)) = parse_transcribe_args(&args(&[
+            "--pcm",
+            "f32le",
+            "--sample-rate",
+            "48000",
+            "--channels",
+            "2",
+            "-",
+        ])) else {
+            panic!("expected run options");
+        };
+        assert_eq!(options.source, STDIN_SOURCE);
+        assert_eq!(options.pcm_encoding, RawPcmEncoding::F32Le);
+        assert_eq!(options.pcm_sample_rate, 48_000);
+        assert_eq!(options.pcm_channels, 2);
+    }
+
+    #[test]
+    fn rejects_invalid_invocations() {
+        assert_eq!(
+            parse_transcribe_args(&args(&["--help", "memo.wav"])),
+            Ok(ParseOutcome::Help)
+        );
+        assert!(parse_transcribe_args(&[]).is_err());
+        assert!(parse_transcribe_args(&args(&["a.wav", "b.wav"])).is_err());
+        assert!(parse_transcribe_args(&args(&["--format", "srt", "a.wav"])).is_err());
+        assert!(parse_transcribe_args(&args(&["--sample-rate", "0", "-"])).is_err());
+        assert!(parse_transcribe_args(&args(&["--model"])).is_err());
+        assert!(parse_transcribe_args(&args(&["--verbose", "a.wav"])).is_err());
+    }
+}
@@ -1,11 +1,13 @@
+use crate::app_context::{collect_focus_target, FocusTarget};
 use crate::input::{self, EnigoState};
 #[cfg(target_os = "linux")]
 use crate::settings::TypingTool;
 use crate::settings::{get_settings, AutoSubmitKey, ClipboardHandling, PasteMethod};
+use crate::voice_commands::{last_sentence_start, VoiceKeyAction};
 use enigo::{Direction, Enigo, Key, Keyboard};
 use log::{info, warn};
-use std::sync::mpsc;
-use std::time::Duration;
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
@@ -21,6 +23,18 @@ const CLIPBOARD_MIN_PASTE_DELAY_MS: u64 = 120;
 const CLIPBOARD_RESTORE_DELAY_MS: u64 = 400;
 const SELECTION_COPY_ATTEMPTS: usize = 12;
 const SELECTION_COPY_RETRY_DELAY_MS: u64 = 35;
+/// How long after a paste "scratch that" may still remove it.
+const VOICE_KEY_ACTION_WINDOW: Duration = Duration::from_secs(120);
+
+/// The most recent paste, so voice commands such as "scratch that" can remove
+/// it again while the same window still has focus.
+struct LastPaste {
+    text: String,
+    target: Option<FocusTarget>,
+    pasted_at: Instant,
+}
+
+static LAST_PASTE: Mutex<Option<LastPaste>> = Mutex::new(None);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ClipboardWriteSyncStatus {
@@ -837,6 +851,17 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
     let mut clipboard_restore_state: Option<ClipboardRestoreState> = None;
 
+    // A new dictation always supersedes the previous paste, even when it is
+    // not typed or fails, so a later "scratch that" never reaches further back.
+    if let Ok(mut last_paste) = LAST_PASTE.lock() {
+        *last_paste = None;
+    }
+    let target = if settings.voice_commands_enabled && paste_method != PasteMethod::None {
+        collect_focus_target()
+    } else {
+        None
+    };
+
     // Perform the paste operation
     match paste_method {
         PasteMethod::None => {
@@ -877,7 +902,20 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         }
     }
 
-    if should_send_auto_submit(settings.auto_submit, paste_method) {
+    // Auto-submitted text has already been sent, so it is never kept for
+    // voice commands to remove.
+    let auto_submit = should_send_auto_submit(settings.auto_submit, paste_method);
+    if settings.voice_commands_enabled && paste_method != PasteMethod::None && !auto_submit {
+        if let Ok(mut last_paste) = LAST_PASTE.lock() {
+            *last_paste = Some(LastPaste {
+                text: text.clone(),
+                target,
+                pasted_at: Instant::now(),
+            });
+        }
+    }
+
+    if auto_submit {
         std::thread::sleep(Duration::from_millis(50));
         send_return_key(&mut enigo, settings.auto_submit_key)?;
     }
@@ -896,10 +934,130 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+fn chars_to_delete(last_pasted: &str, action: VoiceKeyAction) -> usize {
+    match action {
+        VoiceKeyAction::DeletePreviousDictation => last_pasted.chars().count(),
+        VoiceKeyAction::DeletePreviousSentence => last_pasted[last_sentence_start(last_pasted)..]
+            .chars()
+            .count(),
+    }
+}
+
+/// Whether the previous paste is still the text just before the cursor: it is
+/// recent and the same app and window still has focus.
+fn last_paste_is_current(
+    last_paste: &LastPaste,
+    current_target: Option<&FocusTarget>,
+    now: Instant,
+) -> bool {
+    now.saturating_duration_since(last_paste.pasted_at) <= VOICE_KEY_ACTION_WINDOW
+        && last_paste.target.as_ref() == current_target
+}
+
+/// Replays voice command key actions against the text inserted by the
+/// previous paste. Skipped when nothing was pasted, the paste was
+/// auto-submitted, or focus has since moved to another window. The previous
+/// paste is forgotten afterwards, so each paste can only be removed once.
+pub fn apply_voice_key_actions(
+    actions: &[VoiceKeyAction],
+    app_handle: &AppHandle,
+) -> Result<(), String> {
+    if actions.is_empty() {
+        return Ok(());
+    }
+
+    let Some(last_paste) = LAST_PASTE
+        .lock()
+        .map_err(|e| format!("Failed to lock last pasted text: {}", e))?
+        .take()
+    else {
+        info!(
+            "Voice commands {:?} have no previous paste to edit",
+            actions
+        );
+        return Ok(());
+    };
+    let current_target = collect_focus_target();
+    if !last_paste_is_current(&last_paste, current_target.as_ref(), Instant::now()) {
+        info!(
+            "Skipping voice commands {:?}: focus or time changed since the last paste",
+            actions
+        );
+        return Ok(());
+    }
+
+    let mut last_pasted = last_paste.text;
+    let enigo_state = app_handle
+        .try_state::<EnigoState>()
+        .ok_or("Enigo state not initialized")?;
+    let mut enigo = enigo_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
+
+    for action in actions {
+        let count = chars_to_delete(&last_pasted, *action);
+        if count == 0 {
+            info!("Voice command {:?} has no previous text to remove", action);
+            continue;
+        }
+        input::send_backspaces(&mut enigo, count)?;
+        let kept = last_pasted
+            .char_indices()
+            .rev()
+            .nth(count - 1)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        last_pasted.truncate(kept);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn voice_key_actions_only_edit_a_recent_paste_in_the_same_window() {
+        let editor = FocusTarget {
+            app: "com.example.editor".to_string(),
+            window_title: Some("notes.txt".to_string()),
+        };
+        let chat = FocusTarget {
+            app: "com.example.chat".to_string(),
+            window_title: Some("General".to_string()),
+        };
+        let pasted_at = Instant::now();
+        let last_paste = LastPaste {
+            text: "Hello there. ".to_string(),
+            target: Some(editor.clone()),
+            pasted_at,
+        };
+
+        assert!(last_paste_is_current(&last_paste, Some(&editor), pasted_at));
+        assert!(!last_paste_is_current(&last_paste, Some(&chat), pasted_at));
+        assert!(!last_paste_is_current(&last_paste, None, pasted_at));
+        assert!(!last_paste_is_current(
+            &last_paste,
+            Some(&editor),
+            pasted_at + VOICE_KEY_ACTION_WINDOW + Duration::from_secs(1)
+        ));
+    }
+
+    #[test]
+    fn voice_key_actions_count_characters_not_bytes() {
+        let pasted = "Première phrase. Deuxième phrase. ";
+        assert_eq!(
+            chars_to_delete(pasted, VoiceKeyAction::DeletePreviousSentence),
+            " Deuxième phrase. ".chars().count()
+        );
+        assert_eq!(
+            chars_to_delete(pasted, VoiceKeyAction::DeletePreviousDictation),
+            pasted.chars().count()
+        );
+    }
+
     #[test]
     fn auto_submit_policy_covers_supported_paste_methods() {
         let cases = [
@@ -1,28 +1,44 @@
-use crate::managers::history::{HistoryEntry, HistoryManager};
-use std::path::{Component, Path};
+use crate::actions::post_process_saved_transcription;
+use crate::managers::history::{
+    export_transcript, is_safe_recording_file_name, HistoryAlternate, HistoryCursor, HistoryEntry,
+    HistoryEntryUpdate, HistoryFolder, HistoryManager, HistoryPage, HistoryPageFilter,
+    HistoryRevision, HistorySearchFilters, HistorySearchResult, HistoryTag, PlayableAudio,
+    TranscriptExportFormat, TranscriptTimestamps,
+};
+use crate::managers::history_bundle::{HistoryExportSummary, HistoryImportSummary};
+use crate::managers::history_stats::{HistoryStats, DEFAULT_TYPING_WPM};
+use crate::settings::{get_settings, CleaningPromptPreset, RecordingRetentionPeriod};
+use crate::vocabulary_learning::{suggest_vocabulary, VocabularySuggestion};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
-fn is_safe_recording_file_name(file_name: &str) -> bool {
-    let path = Path::new(file_name);
-    let mut components = path.components();
-    let is_single_normal_component =
-        matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none();
-
-    !file_name.trim().is_empty()
-        && is_single_normal_component
-        && path.file_name().and_then(|name| name.to_str()) == Some(file_name)
-        && path.extension().and_then(|extension| extension.to_str()) == Some("wav")
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entries(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    cursor: Option<HistoryCursor>,
+    limit: Option<u32>,
+    filter: HistoryPageFilter,
+) -> Result<HistoryPage, String> {
+    history_manager
+        .get_history_page(cursor, limit, &filter)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn get_history_entries(
+pub async fn search_history_entries(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
-) -> Result<Vec<HistoryEntry>, String> {
+    query: String,
+    filters: HistorySearchFilters,
+    limit: Option<u32>,
+) -> Result<Vec<HistorySearchResult>, String> {
     history_manager
-        .get_history_entries()
+        .search_entries(&query, &filters, limit)
         .await
         .map_err(|e| e.to_string())
 }
@@ -40,21 +56,343 @@ pub async fn toggle_history_entry_saved(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn update_history_entry(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    update: HistoryEntryUpdate,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .update_entry(id, update)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_revisions(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Vec<HistoryRevision>, String> {
+    history_manager
+        .get_revisions(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn revert_history_entry(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    revision_id: i64,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .revert_entry(id, revision_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Runs post-processing again on an entry's raw transcript with another
+/// prompt or provider. The previous post-processed text stays available as a
+/// revision. Only the post-processing step runs; the raw transcript already
+/// went through the rest of the pipeline when it was saved. Meetings are
+/// refused, since their post-processed text is the session summary.
+#[tauri::command]
+#[specta::specta]
+pub async fn reprocess_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    preset: CleaningPromptPreset,
+    custom_prompt: Option<String>,
+    provider_id: Option<String>,
+) -> Result<HistoryEntry, String> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", id))?;
+    if entry.recording_source == "full_system_audio" {
+        return Err("Meeting summaries cannot be post-processed again.".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    if let Some(provider_id) = provider_id {
+        if !settings
+            .post_process_providers
+            .iter()
+            .any(|provider| provider.id == provider_id)
+        {
+            return Err(format!(
+                "Unknown post-processing provider '{}'",
+                provider_id
+            ));
+        }
+        settings.post_process_provider_id = provider_id;
+    }
+    settings.post_process_cleaning_prompt_preset = preset;
+    if preset == CleaningPromptPreset::Custom {
+        let prompt = custom_prompt.unwrap_or_default();
+        if prompt.trim().is_empty() {
+            return Err("Custom prompt cannot be empty".to_string());
+        }
+        settings.post_process_system_prompt = prompt;
+    }
+
+    let Some((post_processed_text, prompt)) =
+        post_process_saved_transcription(&app, &settings, &entry.transcription_text).await
+    else {
+        return Err(
+            "Post-processing did not return any text. Check the provider settings.".to_string(),
+        );
+    };
+
+    history_manager
+        .reprocess_entry(id, &post_processed_text, Some(&prompt))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_alternates(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Vec<HistoryAlternate>, String> {
+    history_manager
+        .get_alternates(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_history_entry_alternate(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    alternate_id: i64,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .apply_alternate(id, alternate_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_history_entry_alternate(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    alternate_id: i64,
+) -> Result<(), String> {
+    history_manager
+        .delete_alternate(id, alternate_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_history_entry_tags(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    tags: Vec<String>,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .set_tags(id, &tags)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_history_entry_note(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    note: Option<String>,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .set_note(id, note.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_history_entry_folder(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    folder_id: Option<i64>,
+) -> Result<HistoryEntry, String> {
+    history_manager
+        .set_folder(id, folder_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_folders(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<Vec<HistoryFolder>, String> {
+    history_manager
+        .get_folders()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn create_history_folder(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    name: String,
+) -> Result<HistoryFolder, String> {
+    history_manager
+        .create_folder(&name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_history_folder(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    name: String,
+) -> Result<HistoryFolder, String> {
+    history_manager
+        .rename_folder(id, &name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_history_folder(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<(), String> {
+    history_manager
+        .delete_folder(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_tags(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<Vec<HistoryTag>, String> {
+    history_manager.get_tags().await.map_err(|e| e.to_string())
+}
+
+/// Saves a corrected transcript like `update_history_entry` and returns the
+/// terms the correction suggests adding to custom vocabulary. Dictations are
+/// corrected in their post-processed text when they have one; a meeting's
+/// post-processed text is its summary, so its transcript is corrected.
+#[tauri::command]
+#[specta::specta]
+pub async fn submit_history_correction(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    corrected_text: String,
+) -> Result<Vec<VocabularySuggestion>, String> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {id} not found"))?;
+
+    let post_processed_text = entry
+        .post_processed_text
+        .filter(|text| !text.trim().is_empty())
+        .filter(|_| entry.recording_source != "full_system_audio");
+    let corrects_transcript = post_processed_text.is_none();
+    let (update, original) = match post_processed_text {
+        Some(text) => (
+            HistoryEntryUpdate {
+                post_processed_text: Some(corrected_text.clone()),
+                ..Default::default()
+            },
+            text,
+        ),
+        None => (
+            HistoryEntryUpdate {
+                transcription_text: Some(corrected_text.clone()),
+                ..Default::default()
+            },
+            entry.transcription_text,
+        ),
+    };
+
+    history_manager
+        .update_entry(id, update)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let settings = get_settings(&app);
+    let mut suggestions = suggest_vocabulary(
+        &original,
+        &corrected_text,
+        &settings.custom_words,
+        &settings.custom_vocabulary_terms,
+        settings.word_correction_threshold,
+    );
+    // Custom words only run on the raw transcript, so a fix to
+    // post-processed text can only become a vocabulary hint.
+    if !corrects_transcript {
+        for suggestion in &mut suggestions {
+            suggestion.fixable_by_custom_words = false;
+        }
+    }
+    Ok(suggestions)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_audio_file_path(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     file_name: String,
-) -> Result<String, String> {
+) -> Result<PlayableAudio, String> {
     if !is_safe_recording_file_name(&file_name) {
         return Err("Invalid recording file name".to_string());
     }
 
-    let path = history_manager.get_audio_file_path(&file_name);
-    path.to_str()
-        .ok_or_else(|| "Invalid file path".to_string())
-        .map(|s| s.to_string())
+    history_manager
+        .get_playable_audio_path(&file_name)
+        .map_err(|e| e.to_string())
+}
+
+/// Deletes a decrypted copy returned by `get_audio_file_path` once the
+/// webview has loaded it.
+#[tauri::command]
+#[specta::specta]
+pub async fn release_playable_audio(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    path: String,
+) -> Result<(), String> {
+    history_manager
+        .release_playable_audio(Path::new(&path))
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -70,6 +408,67 @@ pub async fn delete_history_entry(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_timestamps(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<TranscriptTimestamps, String> {
+    history_manager
+        .get_timestamps(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history_entry_transcript(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    format: TranscriptExportFormat,
+) -> Result<String, String> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", id))?;
+    let timestamps = history_manager
+        .get_timestamps(id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    export_transcript(&entry, &timestamps, format).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history_bundle(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    ids: Option<Vec<i64>>,
+    path: String,
+) -> Result<HistoryExportSummary, String> {
+    history_manager
+        .export_bundle(ids, Path::new(&path))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_history_bundle(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    path: String,
+) -> Result<HistoryImportSummary, String> {
+    history_manager
+        .import_bundle(Path::new(&path))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn update_history_limit(
@@ -88,6 +487,28 @@ pub async fn update_history_limit(
     Ok(())
 }
 
+fn parse_retention_period(period: &str) -> Result<RecordingRetentionPeriod, String> {
+    match period {
+        "never" => Ok(RecordingRetentionPeriod::Never),
+        "preserve_limit" => Ok(RecordingRetentionPeriod::PreserveLimit),
+        "days_3" | "days3" => Ok(RecordingRetentionPeriod::Days3),
+        "weeks_2" | "weeks2" => Ok(RecordingRetentionPeriod::Weeks2),
+        "months_3" | "months3" => Ok(RecordingRetentionPeriod::Months3),
+        "years_1" | "years1" => Ok(RecordingRetentionPeriod::Years1),
+        _ => Err(format!("Invalid retention period: {}", period)),
+    }
+}
+
+/// Like [`parse_retention_period`], for policies that only expire by age.
+fn parse_time_retention_period(period: &str) -> Result<RecordingRetentionPeriod, String> {
+    match parse_retention_period(period)? {
+        RecordingRetentionPeriod::PreserveLimit => {
+            Err(format!("Retention period {} needs a time limit", period))
+        }
+        retention_period => Ok(retention_period),
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn update_recording_retention_period(
@@ -95,16 +516,7 @@ pub async fn update_recording_retention_period(
     history_manager: State<'_, Arc<HistoryManager>>,
     period: String,
 ) -> Result<(), String> {
-    use crate::settings::RecordingRetentionPeriod;
-
-    let retention_period = match period.as_str() {
-        "never" => RecordingRetentionPeriod::Never,
-        "preserve_limit" => RecordingRetentionPeriod::PreserveLimit,
-        "days_3" | "days3" => RecordingRetentionPeriod::Days3,
-        "weeks_2" | "weeks2" => RecordingRetentionPeriod::Weeks2,
-        "months_3" | "months3" => RecordingRetentionPeriod::Months3,
-        _ => return Err(format!("Invalid retention period: {}", period)),
-    };
+    let retention_period = parse_retention_period(&period)?;
 
     let mut settings = crate::settings::get_settings(&app);
     settings.recording_retention_period = retention_period;
@@ -117,17 +529,113 @@ pub async fn update_recording_retention_period(
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::is_safe_recording_file_name;
+#[tauri::command]
+#[specta::specta]
+pub async fn update_meeting_retention_period(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    period: String,
+) -> Result<(), String> {
+    let retention_period = parse_time_retention_period(&period)?;
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.meeting_retention_period = retention_period;
+    crate::settings::write_settings(&app, settings);
+
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
 
-    #[test]
-    fn recording_file_names_must_be_wav_basenames() {
-        assert!(is_safe_recording_file_name("uttr-2026-06-16.wav"));
-        assert!(!is_safe_recording_file_name(""));
-        assert!(!is_safe_recording_file_name("../uttr.wav"));
-        assert!(!is_safe_recording_file_name("nested/uttr.wav"));
-        assert!(!is_safe_recording_file_name("/tmp/uttr.wav"));
-        assert!(!is_safe_recording_file_name("uttr.mp3"));
+    Ok(())
+}
+
+/// Sets how long recordings are kept for dictations or meetings. Entries
+/// past the period keep their text.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_audio_retention_period(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    meetings: bool,
+    period: String,
+) -> Result<(), String> {
+    let retention_period = parse_time_retention_period(&period)?;
+
+    let mut settings = crate::settings::get_settings(&app);
+    if meetings {
+        settings.meeting_audio_retention_period = retention_period;
+    } else {
+        settings.dictation_audio_retention_period = retention_period;
     }
+    crate::settings::write_settings(&app, settings);
+
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Turns encryption at rest on or off, converting existing history before
+/// the setting is saved.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_history_encryption(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    enabled: bool,
+) -> Result<(), String> {
+    history_manager
+        .set_encryption(enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.encrypt_history_at_rest = enabled;
+    crate::settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Mirrors history to Markdown notes in `path`, or stops mirroring with
+/// `None`. Existing entries are written before the setting is saved.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_history_vault_dir(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    path: Option<String>,
+) -> Result<(), String> {
+    let path = path.filter(|path| !path.trim().is_empty());
+    history_manager
+        .set_vault_dir(path.as_ref().map(PathBuf::from))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_vault_dir = path;
+    crate::settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Usage totals for entries between `from_timestamp` and `to_timestamp`
+/// (Unix seconds), with typing time saved estimated at `typing_wpm`.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_stats(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+    typing_wpm: Option<u32>,
+) -> Result<HistoryStats, String> {
+    history_manager
+        .get_stats(
+            from_timestamp,
+            to_timestamp,
+            typing_wpm.unwrap_or(DEFAULT_TYPING_WPM),
+        )
+        .await
+        .map_err(|e| e.to_string())
 }
@@ -114,16 +114,22 @@ pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, String> {
 #[specta::specta]
 pub fn get_post_process_api_key_statuses(app: AppHandle) -> Result<HashMap<String, bool>, String> {
     let settings = get_settings(&app);
-    Ok(settings
+    let has_key = |provider_id: &str| {
+        crate::byok_secrets::load_provider_api_key(&app, &settings, provider_id)
+            .map(|value| value.is_some())
+            .unwrap_or(false)
+    };
+    let mut statuses: HashMap<String, bool> = settings
         .post_process_providers
         .iter()
-        .map(|provider| {
-            let has_key = crate::byok_secrets::load_provider_api_key(&app, &settings, &provider.id)
-                .map(|value| value.is_some())
-                .unwrap_or(false);
-            (provider.id.clone(), has_key)
-        })
-        .collect())
+        .map(|provider| (provider.id.clone(), has_key(&provider.id)))
+        .collect();
+    let custom_transcription_id = crate::byok_secrets::CUSTOM_TRANSCRIPTION_SECRET_KEY;
+    statuses.insert(
+        custom_transcription_id.to_string(),
+        has_key(custom_transcription_id),
+    );
+    Ok(statuses)
 }
 
 #[tauri::command]
@@ -3,35 +3,46 @@ use crate::access::{
     premium_feature_access_message, refresh_entitlement_state,
 };
 use crate::actions::finalize_transcription_output;
-use crate::audio_toolkit::import_audio_file;
-use crate::byok_secrets::{load_groq_api_key, load_openai_api_key};
+use crate::audio_toolkit::{import_audio_bytes, import_audio_file};
+use crate::byok_secrets::{
+    load_custom_transcription_api_key, load_groq_api_key, load_openai_api_key,
+};
 use crate::diagnostics::{report_byok_transcription_failure, report_missing_byok_api_key};
 use crate::groq_client::{
     self, DirectTranscriptionProvider, ProxyTranscriptionMetadata, DIRECT_GROQ_UPLOAD_LIMIT_BYTES,
     PROXY_GROQ_UPLOAD_LIMIT_BYTES,
 };
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::full_system_audio::FullSystemAudioSessionManager;
+use crate::managers::history::{HistoryAlternate, HistoryManager, NewHistoryAlternate};
+use crate::managers::history_stats::audio_duration_ms;
 use crate::managers::model::{
-    groq_api_model_name, is_cloud_model_id, openai_api_model_name, GROQ_MODEL_WHISPER_LARGE_V3,
+    groq_api_model_name, is_cloud_model_id, openai_api_model_name, CUSTOM_TRANSCRIPTION_MODEL_ID,
+    GROQ_MODEL_WHISPER_LARGE_V3,
 };
 use crate::managers::transcription::{stitch_transcription_text, TranscriptionManager};
-use crate::settings::{get_settings, write_settings, ModelUnloadTimeout, SavedFileTranscription};
+use crate::settings::{
+    get_settings, write_settings, AppSettings, ModelUnloadTimeout, SavedFileTranscription,
+};
+use log::warn;
 use once_cell::sync::Lazy;
 use serde::Serialize;
 use specta::Type;
 use std::future::Future;
+use std::ops::Range;
 use std::path::Path;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
 };
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 
 const FILE_TRANSCRIPTION_SAMPLE_RATE: usize = 16_000;
 const FILE_TRANSCRIPTION_OVERLAP_SAMPLES: usize = FILE_TRANSCRIPTION_SAMPLE_RATE * 2;
 const DIRECT_CHUNK_SAFETY_MARGIN_BYTES: usize = 1 * 1024 * 1024;
 const PROXY_CHUNK_SAFETY_MARGIN_BYTES: usize = 4 * 1024 * 1024;
+const LOCAL_CHUNK_SAMPLES: usize = FILE_TRANSCRIPTION_SAMPLE_RATE * 30;
 const FILE_TRANSCRIPTION_SOURCE: &str = "file_transcription";
-const MAX_FILE_TRANSCRIPTION_HISTORY: usize = 5;
 static ACTIVE_FILE_TRANSCRIPTION: Lazy<Mutex<Option<Arc<AtomicBool>>>> =
     Lazy::new(|| Mutex::new(None));
 
@@ -72,6 +83,28 @@ impl Drop for FileTranscriptionCancellation {
     }
 }
 
+/// Applies the immediate model unload setting when dropped, so a file run
+/// that fails or is cancelled part way does not leave its model loaded.
+struct ImmediateUnloadGuard<'a> {
+    transcription_manager: &'a TranscriptionManager,
+    context: &'static str,
+}
+
+impl Drop for ImmediateUnloadGuard<'_> {
+    fn drop(&mut self) {
+        self.transcription_manager
+            .maybe_unload_immediately(self.context);
+    }
+}
+
+fn recording_in_progress(app: &AppHandle) -> bool {
+    app.try_state::<Arc<AudioRecordingManager>>()
+        .is_some_and(|manager| manager.is_recording())
+        || app
+            .try_state::<Arc<FullSystemAudioSessionManager>>()
+            .is_some_and(|manager| manager.is_active())
+}
+
 pub fn request_file_transcription_cancel() {
     if let Some(token) = ACTIVE_FILE_TRANSCRIPTION.lock().unwrap().as_ref() {
         token.store(true, Ordering::Release);
@@ -117,6 +150,21 @@ enum FileTranscriptionRoute {
     DirectGroq,
     DirectOpenAi,
     BackendProxy,
+    DirectCustom,
+    Local,
+}
+
+impl FileTranscriptionRoute {
+    /// The name history stores for the route, matching live transcription.
+    fn as_str(self) -> &'static str {
+        match self {
+            FileTranscriptionRoute::DirectGroq => "groq",
+            FileTranscriptionRoute::DirectOpenAi => "openai",
+            FileTranscriptionRoute::BackendProxy => "backend_proxy",
+            FileTranscriptionRoute::DirectCustom => "custom",
+            FileTranscriptionRoute::Local => "local",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -161,7 +209,24 @@ fn nonempty_openai_api_key(app: &AppHandle) -> Option<String> {
         .filter(|key| !key.is_empty())
 }
 
+fn nonempty_custom_transcription_api_key(app: &AppHandle) -> Option<String> {
+    let settings = get_settings(app);
+    load_custom_transcription_api_key(app, &settings)
+        .ok()
+        .flatten()
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+}
+
 fn resolve_file_transcription_route(app: &AppHandle, model_id: &str) -> FileTranscriptionRoute {
+    if !is_cloud_model_id(model_id) {
+        return FileTranscriptionRoute::Local;
+    }
+
+    if model_id == CUSTOM_TRANSCRIPTION_MODEL_ID {
+        return FileTranscriptionRoute::DirectCustom;
+    }
+
     if openai_api_model_name(model_id).is_some() {
         return FileTranscriptionRoute::DirectOpenAi;
     }
@@ -174,10 +239,10 @@ fn resolve_file_transcription_route(app: &AppHandle, model_id: &str) -> FileTran
 }
 
 fn resolve_file_transcription_model_id(selected_model: &str) -> String {
-    if is_cloud_model_id(selected_model) {
-        selected_model.to_string()
-    } else {
+    if selected_model.trim().is_empty() {
         GROQ_MODEL_WHISPER_LARGE_V3.to_string()
+    } else {
+        selected_model.to_string()
     }
 }
 
@@ -191,6 +256,13 @@ fn safe_chunk_limit_bytes(route: FileTranscriptionRoute) -> usize {
         FileTranscriptionRoute::BackendProxy => {
             PROXY_GROQ_UPLOAD_LIMIT_BYTES.saturating_sub(PROXY_CHUNK_SAFETY_MARGIN_BYTES)
         }
+        FileTranscriptionRoute::DirectCustom => groq_client::DIRECT_CUSTOM_UPLOAD_LIMIT_BYTES
+            .saturating_sub(DIRECT_CHUNK_SAFETY_MARGIN_BYTES),
+        // Local engines have no upload limit, but long windows inflate memory use
+        // and delay progress updates, so plan chunks as if they were 30s WAV uploads.
+        FileTranscriptionRoute::Local => {
+            groq_client::WAV_HEADER_BYTES + LOCAL_CHUNK_SAMPLES * groq_client::WAV_BYTES_PER_SAMPLE
+        }
     }
 }
 
@@ -238,6 +310,17 @@ fn plan_chunk_ranges(sample_count: usize, limit_bytes: usize) -> Result<Vec<Chun
     Ok(ranges)
 }
 
+/// Sample ranges used when a local engine transcribes a long recording.
+pub(crate) fn local_file_chunk_ranges(sample_count: usize) -> Result<Vec<Range<usize>>, String> {
+    Ok(plan_chunk_ranges(
+        sample_count,
+        safe_chunk_limit_bytes(FileTranscriptionRoute::Local),
+    )?
+    .into_iter()
+    .map(|chunk| chunk.start..chunk.end)
+    .collect())
+}
+
 fn chunk_unique_audio_seconds(chunk: ChunkRange, current_chunk: u32) -> u32 {
     let unique_start = if current_chunk == 1 {
         chunk.start
@@ -254,40 +337,41 @@ fn chunk_unique_audio_seconds(chunk: ChunkRange, current_chunk: u32) -> u32 {
     }
 }
 
-fn updated_file_transcription_history(
-    existing: &[SavedFileTranscription],
-    new_entry: SavedFileTranscription,
-) -> Vec<SavedFileTranscription> {
-    let file_name = new_entry.file_name.clone();
-    let transcription_text = new_entry.transcription_text.clone();
-    let post_processed_text = new_entry.post_processed_text.clone();
-    let source_path = new_entry.source_path.clone();
-    let mut history = Vec::with_capacity(MAX_FILE_TRANSCRIPTION_HISTORY);
-    history.push(new_entry);
-    history.extend(
-        existing
-            .iter()
-            .filter(|entry| {
-                entry.file_name != file_name
-                    || entry.transcription_text != transcription_text
-                    || entry.post_processed_text != post_processed_text
-                    || entry.source_path != source_path
-            })
-            .take(MAX_FILE_TRANSCRIPTION_HISTORY - 1)
-            .cloned(),
-    );
-    history
+async fn ensure_local_file_transcription_model(
+    transcription_manager: &Arc<TranscriptionManager>,
+    model_id: &str,
+) -> Result<(), String> {
+    if transcription_manager.is_model_loaded()
+        && transcription_manager.get_current_model().as_deref() == Some(model_id)
+    {
+        return Ok(());
+    }
+
+    let manager = Arc::clone(transcription_manager);
+    let load_model_id = model_id.to_string();
+    tokio::task::spawn_blocking(move || manager.load_model(&load_model_id))
+        .await
+        .map_err(|err| format!("Failed to load local model: {}", err))?
+        .map_err(|err| format!("Failed to load local model '{}': {}", model_id, err))
 }
 
-fn persist_file_transcription_history(app: &AppHandle, new_entry: Option<SavedFileTranscription>) {
-    let mut settings = get_settings(app);
-    settings.file_transcription_history = match new_entry {
-        Some(entry) => {
-            updated_file_transcription_history(&settings.file_transcription_history, entry)
-        }
-        None => Vec::new(),
-    };
-    write_settings(app, settings);
+/// Local models run entirely on this machine, so they need no entitlement
+/// check and keep working offline. Cloud routes require premium access.
+async fn ensure_file_transcription_access(
+    app: &AppHandle,
+    route: FileTranscriptionRoute,
+) -> Result<(), String> {
+    if route == FileTranscriptionRoute::Local {
+        return Ok(());
+    }
+
+    let access = refresh_entitlement_state(app)
+        .await
+        .unwrap_or_else(|_| get_install_access_snapshot(app));
+    if !install_access_allows_premium_features(&access) {
+        return Err(premium_feature_access_message().to_string());
+    }
+    Ok(())
 }
 
 async fn ensure_backend_install_token(app: &AppHandle) -> Result<String, String> {
@@ -319,19 +403,25 @@ fn sync_proxy_access_state(
     write_settings(app, settings);
 }
 
-async fn transcribe_chunk_with_groq(
+async fn transcribe_file_chunk(
     app: &AppHandle,
+    transcription_manager: &TranscriptionManager,
     route: FileTranscriptionRoute,
     model_id: &str,
     samples: &[f32],
     install_token: Option<&str>,
-    selected_language: &str,
-    translate_to_english: bool,
+    settings: &AppSettings,
     audio_seconds: u32,
     current_chunk: u32,
     total_chunks: u32,
 ) -> Result<String, String> {
+    let selected_language = settings.selected_language.as_str();
+    let translate_to_english = settings.translate_to_english;
     match route {
+        FileTranscriptionRoute::Local => transcription_manager
+            .transcribe_local_file_with_settings(samples.to_vec(), settings)
+            .await
+            .map_err(|error| error.to_string()),
         FileTranscriptionRoute::DirectGroq => {
             let groq_model = groq_api_model_name(model_id)
                 .ok_or_else(|| format!("Unknown Groq model id: {}", model_id))?;
@@ -414,6 +504,33 @@ async fn transcribe_chunk_with_groq(
                 }
             }
         }
+        FileTranscriptionRoute::DirectCustom => {
+            let api_key = nonempty_custom_transcription_api_key(app);
+            let request_started = std::time::Instant::now();
+            match groq_client::transcribe_samples_direct_custom(
+                &settings.custom_transcription_base_url,
+                api_key.as_deref(),
+                &settings.custom_transcription_model,
+                samples,
+                selected_language,
+                translate_to_english,
+            )
+            .await
+            {
+                Ok(text) => Ok(text),
+                Err(error) => {
+                    let settings = get_settings(app);
+                    report_byok_transcription_failure(
+                        &settings,
+                        model_id,
+                        samples.len(),
+                        request_started.elapsed(),
+                        &error,
+                    );
+                    Err(error.to_string())
+                }
+            }
+        }
         FileTranscriptionRoute::BackendProxy => {
             let groq_model = groq_api_model_name(model_id)
                 .ok_or_else(|| format!("Unknown Groq model id: {}", model_id))?;
@@ -447,6 +564,47 @@ async fn transcribe_chunk_with_groq(
     }
 }
 
+/// Transcribes `chunks` of `samples` in order and stitches the results.
+/// `before_chunk` gets the 1-based chunk number and the chunk count before
+/// each request and stops the run by returning an error.
+async fn transcribe_chunks(
+    app: &AppHandle,
+    transcription_manager: &TranscriptionManager,
+    route: FileTranscriptionRoute,
+    model_id: &str,
+    samples: &[f32],
+    chunks: &[ChunkRange],
+    install_token: Option<&str>,
+    settings: &AppSettings,
+    mut before_chunk: impl FnMut(u32, u32) -> Result<(), String>,
+) -> Result<String, String> {
+    let total_chunks = chunks.len() as u32;
+    let mut stitched_transcription = String::new();
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        let current_chunk = (index + 1) as u32;
+        before_chunk(current_chunk, total_chunks)?;
+
+        let chunk_text = transcribe_file_chunk(
+            app,
+            transcription_manager,
+            route,
+            model_id,
+            &samples[chunk.start..chunk.end],
+            install_token,
+            settings,
+            chunk_unique_audio_seconds(*chunk, current_chunk),
+            current_chunk,
+            total_chunks,
+        )
+        .await?;
+
+        stitch_transcription_text(&mut stitched_transcription, &chunk_text);
+    }
+
+    Ok(stitched_transcription)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
@@ -478,24 +636,31 @@ pub fn unload_model_manually(
 
 #[tauri::command]
 #[specta::specta]
-pub fn clear_file_transcription_history(app: AppHandle) -> Result<(), String> {
-    persist_file_transcription_history(&app, None);
-    Ok(())
+pub async fn clear_file_transcription_history(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<(), String> {
+    history_manager
+        .delete_file_imports()
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 #[specta::specta]
 pub async fn transcribe_audio_file(
     app: AppHandle,
-    _transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    history_manager: State<'_, Arc<HistoryManager>>,
     path: String,
 ) -> Result<FileTranscriptionResult, String> {
-    let access = refresh_entitlement_state(&app)
-        .await
-        .unwrap_or_else(|_| get_install_access_snapshot(&app));
-    if !install_access_allows_premium_features(&access) {
-        return Err(premium_feature_access_message().to_string());
+    if recording_in_progress(&app) {
+        return Err("Finish the current recording before transcribing a file.".to_string());
     }
+    let settings = get_settings(&app);
+    let model_id = resolve_file_transcription_model_id(&settings.selected_model);
+    let route = resolve_file_transcription_route(&app, &model_id);
+    ensure_file_transcription_access(&app, route).await?;
 
     let cancellation = FileTranscriptionCancellation::begin()?;
     emit_file_transcription_progress(&app, 5, "Importing audio file", None, None);
@@ -515,9 +680,6 @@ pub async fn transcribe_audio_file(
         .map(|name| name.to_string())
         .unwrap_or_else(|| imported.path.to_string_lossy().to_string());
 
-    let settings = get_settings(&app);
-    let model_id = resolve_file_transcription_model_id(&settings.selected_model);
-    let route = resolve_file_transcription_route(&app, &model_id);
     let chunk_limit_bytes = safe_chunk_limit_bytes(route);
     let chunks = plan_chunk_ranges(imported.samples.len(), chunk_limit_bytes)?;
     if chunks.is_empty() {
@@ -528,6 +690,15 @@ pub async fn transcribe_audio_file(
     } else {
         None
     };
+    let unload_guard = (route == FileTranscriptionRoute::Local).then(|| ImmediateUnloadGuard {
+        transcription_manager: &transcription_manager,
+        context: "file transcription",
+    });
+    if route == FileTranscriptionRoute::Local {
+        emit_file_transcription_progress(&app, 8, "Loading local model", None, None);
+        ensure_local_file_transcription_model(&transcription_manager, &model_id).await?;
+        cancellation.ensure_active()?;
+    }
 
     emit_file_transcription_progress(
         &app,
@@ -538,41 +709,33 @@ pub async fn transcribe_audio_file(
     );
 
     let total_chunks = chunks.len() as u32;
-    let mut stitched_transcription = String::new();
-
-    for (index, chunk) in chunks.iter().enumerate() {
-        cancellation.ensure_active()?;
-
-        let current_chunk = (index + 1) as u32;
-        let progress_percentage = 15u8.saturating_add(
-            (((current_chunk - 1) as f32 / total_chunks as f32) * 70.0).round() as u8,
-        );
-        emit_file_transcription_progress(
-            &app,
-            progress_percentage.min(85),
-            format!("Transcribing chunk {} of {}", current_chunk, total_chunks),
-            Some(current_chunk),
-            Some(total_chunks),
-        );
-
-        let chunk_text = transcribe_chunk_with_groq(
-            &app,
-            route,
-            &model_id,
-            &imported.samples[chunk.start..chunk.end],
-            backend_install_token.as_deref(),
-            &settings.selected_language,
-            settings.translate_to_english,
-            chunk_unique_audio_seconds(*chunk, current_chunk),
-            current_chunk,
-            total_chunks,
-        )
-        .await?;
-
-        cancellation.ensure_active()?;
-
-        stitch_transcription_text(&mut stitched_transcription, &chunk_text);
-    }
+    let stitched_transcription = transcribe_chunks(
+        &app,
+        &transcription_manager,
+        route,
+        &model_id,
+        &imported.samples,
+        &chunks,
+        backend_install_token.as_deref(),
+        &settings,
+        |current_chunk, total_chunks| {
+            cancellation.ensure_active()?;
+            let progress_percentage = 15u8.saturating_add(
+                (((current_chunk - 1) as f32 / total_chunks as f32) * 70.0).round() as u8,
+            );
+            emit_file_transcription_progress(
+                &app,
+                progress_percentage.min(85),
+                format!("Transcribing chunk {} of {}", current_chunk, total_chunks),
+                Some(current_chunk),
+                Some(total_chunks),
+            );
+            Ok(())
+        },
+    )
+    .await?;
+    cancellation.ensure_active()?;
+    drop(unload_guard);
 
     emit_file_transcription_progress(&app, 90, "Stitching transcript", None, Some(total_chunks));
     emit_file_transcription_progress(
@@ -582,6 +745,8 @@ pub async fn transcribe_audio_file(
         None,
         Some(total_chunks),
     );
+    // Voice key actions edit an earlier paste; file transcripts are never
+    // pasted, so they are dropped.
     let finalized = await_file_post_processing_if_active(
         &cancellation,
         finalize_transcription_output(
@@ -609,19 +774,156 @@ pub async fn transcribe_audio_file(
     };
 
     cancellation.ensure_active()?;
-    persist_file_transcription_history(
-        &app,
-        Some(SavedFileTranscription {
-            file_name,
-            transcription_text: result.transcription_text.clone(),
-            post_processed_text: result.post_processed_text.clone(),
-            source_path: Some(path),
-        }),
-    );
+    if let Err(error) = history_manager
+        .save_file_transcription(
+            &SavedFileTranscription {
+                file_name,
+                transcription_text: result.transcription_text.clone(),
+                post_processed_text: result.post_processed_text.clone(),
+                source_path: Some(path),
+            },
+            finalized.post_process_prompt.as_deref(),
+            audio_duration_ms(imported.samples.len()),
+        )
+        .await
+    {
+        warn!("Failed to save file transcription to history: {}", error);
+    }
 
     Ok(result)
 }
 
+/// Transcribes a history entry's recording again with `model_id` (the
+/// selected model when `None`) and `language`, and keeps the result as an
+/// alternate beside the entry's current text. Meetings are refused: their
+/// transcript is labeled by speaker and their summary comes from the live
+/// session, and neither survives transcribing the mixed recording again.
+///
+/// A local model may be swapped in for the run, so it holds the file
+/// transcription guard and refuses to start while a recording is active.
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_history_entry(
+    app: AppHandle,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    model_id: Option<String>,
+    language: Option<String>,
+    post_process: bool,
+) -> Result<HistoryAlternate, String> {
+    if recording_in_progress(&app) {
+        return Err("Finish the current recording before re-transcribing.".to_string());
+    }
+    let cancellation = FileTranscriptionCancellation::begin()?;
+
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", id))?;
+    if entry.audio_purged {
+        return Err("The recording for this entry has been deleted.".to_string());
+    }
+    if entry.recording_source == "full_system_audio" {
+        return Err("Meetings cannot be transcribed again.".to_string());
+    }
+
+    let manager = history_manager.inner().clone();
+    let file_name = entry.file_name.clone();
+    let samples = tokio::task::spawn_blocking(move || {
+        import_audio_bytes(manager.read_recording(&file_name)?, Some("wav"))
+    })
+    .await
+    .map_err(|err| format!("Failed to read recording: {}", err))?
+    .map_err(|err| format!("Failed to read recording: {}", err))?;
+
+    let mut settings = get_settings(&app);
+    if let Some(language) = language.filter(|language| !language.trim().is_empty()) {
+        settings.selected_language = language;
+    }
+    let model_id = resolve_file_transcription_model_id(
+        model_id.as_deref().unwrap_or(&settings.selected_model),
+    );
+    let route = resolve_file_transcription_route(&app, &model_id);
+    ensure_file_transcription_access(&app, route).await?;
+    let chunks = plan_chunk_ranges(samples.len(), safe_chunk_limit_bytes(route))?;
+    if chunks.is_empty() {
+        return Err("The recording for this entry is empty.".to_string());
+    }
+    let backend_install_token = if route == FileTranscriptionRoute::BackendProxy {
+        Some(ensure_backend_install_token(&app).await?)
+    } else {
+        None
+    };
+
+    // Dictation keeps using whatever model is loaded, so put the previous one
+    // back once the other model has run.
+    let previous_model = transcription_manager
+        .is_model_loaded()
+        .then(|| transcription_manager.get_current_model())
+        .flatten();
+    if route == FileTranscriptionRoute::Local {
+        ensure_local_file_transcription_model(&transcription_manager, &model_id).await?;
+    }
+    let transcription = transcribe_chunks(
+        &app,
+        &transcription_manager,
+        route,
+        &model_id,
+        &samples,
+        &chunks,
+        backend_install_token.as_deref(),
+        &settings,
+        |_, _| cancellation.ensure_active(),
+    )
+    .await;
+    if route == FileTranscriptionRoute::Local {
+        let restored = match previous_model.as_deref() {
+            Some(previous_model) if previous_model == model_id => {
+                transcription_manager.maybe_unload_immediately("re-transcription");
+                Ok(())
+            }
+            Some(previous_model) => {
+                ensure_local_file_transcription_model(&transcription_manager, previous_model).await
+            }
+            None => transcription_manager
+                .unload_model()
+                .map_err(|e| e.to_string()),
+        };
+        if let Err(e) = restored {
+            warn!("Failed to restore the model after re-transcription: {}", e);
+        }
+    }
+
+    let transcription_text =
+        transcription_manager.apply_transcription_filters(transcription?, &settings);
+    if transcription_text.trim().is_empty() {
+        return Err("No speech was recognized in the recording.".to_string());
+    }
+    // Voice key actions edit an earlier paste; a re-transcription is never
+    // pasted, so they are dropped.
+    let post_processed_text =
+        finalize_transcription_output(&app, &settings, &transcription_text, post_process, None)
+            .await
+            .post_processed_text;
+
+    cancellation.ensure_active()?;
+    history_manager
+        .add_alternate(
+            id,
+            NewHistoryAlternate {
+                transcription_model: Some(model_id),
+                language: Some(settings.selected_language),
+                transcription_route: Some(route.as_str().to_string()),
+                transcription_text,
+                post_processed_text,
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -715,13 +1017,30 @@ mod tests {
     }
 
     #[test]
-    fn defaults_to_groq_large_v3_when_local_model_is_selected() {
+    fn keeps_selected_local_model_for_offline_file_transcription() {
         assert_eq!(
             resolve_file_transcription_model_id("parakeet-tdt-0.6b-v3"),
+            "parakeet-tdt-0.6b-v3"
+        );
+        assert_eq!(
+            resolve_file_transcription_model_id(""),
             GROQ_MODEL_WHISPER_LARGE_V3
         );
     }
 
+    #[test]
+    fn local_chunk_plan_uses_short_windows() {
+        let ranges = plan_chunk_ranges(
+            FILE_TRANSCRIPTION_SAMPLE_RATE * 60 * 5,
+            safe_chunk_limit_bytes(FileTranscriptionRoute::Local),
+        )
+        .unwrap();
+        assert!(ranges.len() > 10);
+        for range in ranges {
+            assert!(range.end - range.start <= LOCAL_CHUNK_SAMPLES);
+        }
+    }
+
     #[test]
     fn overlap_only_counts_once_for_usage_seconds() {
         let first = ChunkRange {
@@ -736,31 +1055,4 @@ mod tests {
         assert_eq!(chunk_unique_audio_seconds(first, 1), 20);
         assert_eq!(chunk_unique_audio_seconds(second, 2), 10);
     }
-
-    #[test]
-    fn newest_file_transcription_is_inserted_first_and_history_is_capped() {
-        let existing = (0..5)
-            .map(|index| SavedFileTranscription {
-                file_name: format!("older-{}.wav", index),
-                transcription_text: format!("older transcript {}", index),
-                post_processed_text: None,
-                source_path: Some(format!("/tmp/older-{}.wav", index)),
-            })
-            .collect::<Vec<_>>();
-
-        let history = updated_file_transcription_history(
-            &existing,
-            SavedFileTranscription {
-                file_name: "new.wav".to_string(),
-                transcription_text: "new transcript".to_string(),
-                post_processed_text: Some("new transcript".to_string()),
-                source_path: Some("/tmp/new.wav".to_string()),
-            },
-        );
-
-        assert_eq!(history.len(), 5);
-        assert_eq!(history[0].file_name, "new.wav");
-        assert_eq!(history[1].file_name, "older-0.wav");
-        assert_eq!(history[4].file_name, "older-3.wav");
-    }
 }
@@ -180,6 +180,7 @@ mod tests {
     use crate::groq_client::{
         classify_status_error, DirectTranscriptionError, DirectTranscriptionProvider,
     };
+    use crate::managers::model::CUSTOM_TRANSCRIPTION_MODEL_ID;
     use crate::settings::get_default_settings;
     use reqwest::StatusCode;
 
@@ -296,6 +297,27 @@ mod tests {
         assert!(!serialized.contains("https://example.test"));
     }
 
+    #[test]
+    fn custom_server_failures_do_not_expose_server_or_model() {
+        let error = DirectTranscriptionError::missing_configuration(
+            DirectTranscriptionProvider::Custom,
+            "Custom transcription server URL is missing or invalid.",
+        );
+
+        let payload = build_byok_failure_payload(
+            &settings(),
+            CUSTOM_TRANSCRIPTION_MODEL_ID,
+            SAMPLE_RATE,
+            Duration::ZERO,
+            &error,
+        )
+        .expect("payload");
+
+        assert_eq!(payload.provider, "byok_custom");
+        assert_eq!(payload.model_id, "other");
+        assert_eq!(payload.error_kind, "missing_configuration");
+    }
+
     #[test]
     fn report_returns_without_surfacing_send_failures() {
         let original_backend_url = std::env::var("UTTR_BACKEND_BASE_URL").ok();
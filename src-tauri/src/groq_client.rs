@@ -12,10 +12,13 @@ const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
 const OPENAI_DICTATION_PROMPT: &str = "Transcribe short desktop dictation accurately. The speaker may be quiet, fast, or mumbled. If speech is present, transcribe the spoken words verbatim with normal punctuation.";
 const GROQ_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 const OPENAI_REQUEST_TIMEOUT: Duration = Duration::from_secs(45);
+// Self-hosted servers often run on CPU, so give them more room than hosted APIs.
+const CUSTOM_REQUEST_TIMEOUT: Duration = Duration::from_secs(90);
 pub const WAV_HEADER_BYTES: usize = 44;
 pub const WAV_BYTES_PER_SAMPLE: usize = 2;
 pub const DIRECT_GROQ_UPLOAD_LIMIT_BYTES: usize = 25 * 1024 * 1024;
 pub const DIRECT_OPENAI_UPLOAD_LIMIT_BYTES: usize = 25 * 1024 * 1024;
+pub const DIRECT_CUSTOM_UPLOAD_LIMIT_BYTES: usize = 25 * 1024 * 1024;
 pub const PROXY_GROQ_UPLOAD_LIMIT_BYTES: usize = 100 * 1024 * 1024;
 const PROXY_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
@@ -33,6 +36,13 @@ static OPENAI_HTTP_CLIENT: Lazy<Client> = Lazy::new(|| {
         .expect("Failed to build OpenAI HTTP client")
 });
 
+static CUSTOM_HTTP_CLIENT: Lazy<Client> = Lazy::new(|| {
+    Client::builder()
+        .timeout(CUSTOM_REQUEST_TIMEOUT)
+        .build()
+        .expect("Failed to build custom transcription HTTP client")
+});
+
 static PROXY_HTTP_CLIENT: Lazy<Client> = Lazy::new(|| {
     Client::builder()
         .timeout(PROXY_REQUEST_TIMEOUT)
@@ -50,6 +60,11 @@ struct OpenAiTranscriptionResponse {
     text: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct CustomTranscriptionResponse {
+    text: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct ProxyTranscriptionResponse {
     text: String,
@@ -78,6 +93,12 @@ impl ProxyTranscriptionError {
         }
     }
 
+    /// True when the backend could not be reached at all, so another cloud
+    /// provider is unlikely to do better than a local model.
+    pub fn is_network_failure(&self) -> bool {
+        matches!(self, ProxyTranscriptionError::Request(_))
+    }
+
     pub fn is_blocked(&self) -> bool {
         matches!(
             self,
@@ -100,6 +121,7 @@ impl ProxyTranscriptionError {
 pub enum DirectTranscriptionProvider {
     Groq,
     OpenAi,
+    Custom,
 }
 
 impl DirectTranscriptionProvider {
@@ -107,6 +129,7 @@ impl DirectTranscriptionProvider {
         match self {
             DirectTranscriptionProvider::Groq => "byok_groq",
             DirectTranscriptionProvider::OpenAi => "byok_openai",
+            DirectTranscriptionProvider::Custom => "byok_custom",
         }
     }
 
@@ -114,6 +137,7 @@ impl DirectTranscriptionProvider {
         match self {
             DirectTranscriptionProvider::Groq => "Groq",
             DirectTranscriptionProvider::OpenAi => "OpenAI",
+            DirectTranscriptionProvider::Custom => "Custom server",
         }
     }
 }
@@ -131,6 +155,7 @@ pub enum DirectTranscriptionErrorKind {
     PayloadTooLarge,
     UnsupportedFeature,
     MissingApiKey,
+    MissingConfiguration,
     RequestFailed,
     Unknown,
 }
@@ -149,10 +174,20 @@ impl DirectTranscriptionErrorKind {
             DirectTranscriptionErrorKind::PayloadTooLarge => "payload_too_large",
             DirectTranscriptionErrorKind::UnsupportedFeature => "unsupported_feature",
             DirectTranscriptionErrorKind::MissingApiKey => "missing_api_key",
+            DirectTranscriptionErrorKind::MissingConfiguration => "missing_configuration",
             DirectTranscriptionErrorKind::RequestFailed => "request_failed",
             DirectTranscriptionErrorKind::Unknown => "unknown",
         }
     }
+
+    /// True when the provider could not be reached at all, so another cloud
+    /// provider is unlikely to do better than a local model.
+    pub fn is_network_failure(self) -> bool {
+        matches!(
+            self,
+            DirectTranscriptionErrorKind::Timeout | DirectTranscriptionErrorKind::NetworkError
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -188,6 +223,18 @@ impl DirectTranscriptionError {
         )
     }
 
+    pub fn missing_configuration(
+        provider: DirectTranscriptionProvider,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            provider,
+            DirectTranscriptionErrorKind::MissingConfiguration,
+            None,
+            message,
+        )
+    }
+
     pub fn unsupported_feature(
         provider: DirectTranscriptionProvider,
         message: impl Into<String>,
@@ -503,6 +550,109 @@ pub async fn transcribe_samples_direct_openai(
     Ok(parsed.text)
 }
 
+/// Accepts either a base URL (`http://host:8080/v1`) or the full
+/// `/audio/transcriptions` endpoint and returns the base URL without a trailing slash.
+pub fn normalize_custom_base_url(base_url: &str) -> Option<String> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    let trimmed = trimmed
+        .strip_suffix("/audio/transcriptions")
+        .or_else(|| trimmed.strip_suffix("/audio/translations"))
+        .unwrap_or(trimmed);
+    let host = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))?;
+    if host.is_empty() {
+        return None;
+    }
+
+    Some(trimmed.to_string())
+}
+
+pub async fn transcribe_samples_direct_custom(
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    samples: &[f32],
+    selected_language: &str,
+    translate_to_english: bool,
+) -> Result<String, DirectTranscriptionError> {
+    let provider = DirectTranscriptionProvider::Custom;
+    let Some(base_url) = normalize_custom_base_url(base_url) else {
+        return Err(DirectTranscriptionError::missing_configuration(
+            provider,
+            "Custom transcription server URL is missing or invalid. Set an http(s) base URL in Settings.",
+        ));
+    };
+    if model.trim().is_empty() {
+        return Err(DirectTranscriptionError::missing_configuration(
+            provider,
+            "Custom transcription model name is required. Set it in Settings.",
+        ));
+    }
+
+    let encode_started = Instant::now();
+    let wav = build_wav_bytes(samples)
+        .map_err(|message| DirectTranscriptionError::payload_too_large(provider, message))?;
+    let encode_elapsed = encode_started.elapsed();
+
+    let endpoint = if translate_to_english {
+        "audio/translations"
+    } else {
+        "audio/transcriptions"
+    };
+
+    let file_part = multipart::Part::bytes(wav)
+        .file_name("uttr.wav")
+        .mime_str("audio/wav")
+        .map_err(|_| DirectTranscriptionError::parse(provider, "Failed to build audio payload."))?;
+
+    let mut form = multipart::Form::new()
+        .text("model", model.trim().to_string())
+        .part("file", file_part)
+        .text("response_format", "json".to_string());
+
+    if let Some(language) = normalize_language(selected_language) {
+        form = form.text("language", language.to_string());
+    }
+
+    // Self-hosted servers frequently run without auth, so the key is optional.
+    let mut request = CUSTOM_HTTP_CLIENT
+        .post(format!("{}/{}", base_url, endpoint))
+        .multipart(form);
+    if let Some(api_key) = api_key.map(str::trim).filter(|key| !key.is_empty()) {
+        request = request.bearer_auth(api_key);
+    }
+
+    let request_started = Instant::now();
+    let response = request
+        .send()
+        .await
+        .map_err(|error| DirectTranscriptionError::request(provider, error))?;
+    let request_elapsed = request_started.elapsed();
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DirectTranscriptionError::status(provider, status));
+    }
+
+    let parse_started = Instant::now();
+    let parsed: CustomTranscriptionResponse = response.json().await.map_err(|_| {
+        DirectTranscriptionError::parse(provider, "Failed to parse custom server response.")
+    })?;
+    let parse_elapsed = parse_started.elapsed();
+
+    debug!(
+        "Custom direct transcription timing: model={}, samples={}, encode_ms={}, request_ms={}, parse_ms={}",
+        model,
+        samples.len(),
+        encode_elapsed.as_millis(),
+        request_elapsed.as_millis(),
+        parse_elapsed.as_millis()
+    );
+
+    Ok(parsed.text)
+}
+
 pub async fn transcribe_samples(
     install_token: &str,
     model: &str,
@@ -671,4 +821,34 @@ mod tests {
         assert_eq!(error.kind(), DirectTranscriptionErrorKind::Provider5xx);
         assert_eq!(error.status_code(), Some(502));
     }
+
+    #[test]
+    fn only_unreachable_providers_count_as_network_failures() {
+        assert!(DirectTranscriptionErrorKind::Timeout.is_network_failure());
+        assert!(DirectTranscriptionErrorKind::NetworkError.is_network_failure());
+        assert!(!DirectTranscriptionErrorKind::Provider5xx.is_network_failure());
+        assert!(!DirectTranscriptionErrorKind::AuthFailed.is_network_failure());
+
+        assert!(ProxyTranscriptionError::Request("offline".to_string()).is_network_failure());
+        assert!(!ProxyTranscriptionError::Status {
+            status: StatusCode::BAD_GATEWAY,
+            body: String::new(),
+        }
+        .is_network_failure());
+    }
+
+    #[test]
+    fn custom_base_url_accepts_base_or_full_endpoint() {
+        assert_eq!(
+            normalize_custom_base_url(" http://localhost:8080/v1/ ").as_deref(),
+            Some("http://localhost:8080/v1")
+        );
+        assert_eq!(
+            normalize_custom_base_url("https://stt.internal/v1/audio/transcriptions").as_deref(),
+            Some("https://stt.internal/v1")
+        );
+        assert_eq!(normalize_custom_base_url(""), None);
+        assert_eq!(normalize_custom_base_url("localhost:8080/v1"), None);
+        assert_eq!(normalize_custom_base_url("http://"), None);
+    }
 }
@@ -0,0 +1,101 @@
+//! Shared bootstrap for the headless `uttr transcribe` and `uttr-eval`
+//! commands: argument helpers, exit codes and the app data directory, all
+//! without starting the Tauri runtime.
+
+use std::future::Future;
+use std::path::PathBuf;
+
+/// Bundle identifier from tauri.conf.json; the app keeps its data under it.
+const APP_IDENTIFIER: &str = "com.pais.uttr";
+
+pub(crate) const EXIT_SUCCESS: i32 = 0;
+pub(crate) const EXIT_FAILURE: i32 = 1;
+pub(crate) const EXIT_USAGE: i32 = 2;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseOutcome<T> {
+    Run(T),
+    Help,
+}
+
+/// Takes the value following `flag` from the argument iterator.
+pub(crate) fn flag_value<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<String, String> {
+    args.next()
+        .cloned()
+        .ok_or_else(|| format!("{} requires a value", flag))
+}
+
+/// Resolves the directory the app stores its data in, the same way Tauri's
+/// `app_data_dir` does, without a running app.
+pub(crate) fn app_data_dir() -> Result<PathBuf, String> {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER))
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())
+}
+
+/// Prints help or usage errors for `parsed`, otherwise runs `run` to
+/// completion and maps the result to a process exit code.
+pub(crate) fn run_command<T, F>(
+    parsed: Result<ParseOutcome<T>, String>,
+    usage: &str,
+    run: impl FnOnce(T) -> F,
+) -> i32
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let options = match parsed {
+        Ok(ParseOutcome::Run(options)) => options,
+        Ok(ParseOutcome::Help) => {
+            print!("{}", usage);
+            return EXIT_SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, usage);
+            return EXIT_USAGE;
+        }
+    };
+
+    match tauri::async_runtime::block_on(run(options)) {
+        Ok(()) => EXIT_SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            EXIT_FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn app_identifier_matches_the_tauri_config() {
+        let config: serde_json::Value =
+            serde_json::from_str(include_str!("../tauri.conf.json")).unwrap();
+        assert_eq!(config["identifier"], APP_IDENTIFIER);
+    }
+
+    #[test]
+    fn usage_errors_and_help_skip_the_command() {
+        let usage_error: Result<ParseOutcome<()>, String> = Err("bad flag".to_string());
+        assert_eq!(
+            run_command(usage_error, "", |_| async { unreachable!() }),
+            EXIT_USAGE
+        );
+        assert_eq!(
+            run_command(Ok(ParseOutcome::<()>::Help), "", |_| async {
+                unreachable!()
+            }),
+            EXIT_SUCCESS
+        );
+        assert_eq!(
+            run_command(Ok(ParseOutcome::Run(())), "", |_| async {
+                Err("failed".to_string())
+            }),
+            EXIT_FAILURE
+        );
+    }
+}
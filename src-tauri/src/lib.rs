@@ -6,16 +6,19 @@ mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
 mod byok_secrets;
+mod cli;
 mod clipboard;
 mod commands;
 mod diagnostics;
 pub mod full_system_audio_bridge;
 mod groq_client;
+mod headless;
 mod helpers;
 mod input;
 mod llm_client;
 mod managers;
 mod overlay;
+mod post_process_eval;
 mod settings;
 mod shortcut;
 mod signal_handle;
@@ -24,15 +27,19 @@ mod transcription_coordinator;
 mod tray;
 mod tray_i18n;
 mod utils;
+mod vocabulary_learning;
+mod voice_commands;
 use specta_typescript::{BigIntExportBehavior, Typescript};
 use tauri_specta::{collect_commands, Builder};
 
+pub use cli::run_cli;
 use env_filter::Builder as EnvFilterBuilder;
 use managers::audio::AudioRecordingManager;
 use managers::full_system_audio::FullSystemAudioSessionManager;
 use managers::history::HistoryManager;
 use managers::model::ModelManager;
 use managers::transcription::TranscriptionManager;
+pub use post_process_eval::run_post_process_eval;
 #[cfg(unix)]
 use signal_hook::consts::SIGUSR2;
 #[cfg(unix)]
@@ -342,6 +349,9 @@ pub fn run() {
         shortcut::change_clipboard_handling_setting,
         shortcut::change_auto_submit_setting,
         shortcut::change_auto_submit_key_setting,
+        shortcut::change_transcription_fallback_policy_setting,
+        shortcut::change_custom_transcription_server_setting,
+        shortcut::change_custom_transcription_api_key_setting,
         shortcut::change_post_process_enabled_setting,
         shortcut::change_post_process_base_url_setting,
         shortcut::change_post_process_api_key_setting,
@@ -358,6 +368,9 @@ pub fn run() {
         shortcut::change_post_process_system_prompt_setting,
         shortcut::change_mute_while_recording_setting,
         shortcut::change_append_trailing_space_setting,
+        shortcut::change_inverse_text_normalization_setting,
+        shortcut::change_voice_commands_enabled_setting,
+        shortcut::change_voice_commands_setting,
         shortcut::change_app_language_setting,
         shortcut::change_update_checks_setting,
         shortcut::change_keyboard_implementation_setting,
@@ -428,12 +441,40 @@ pub fn run() {
         commands::transcription::unload_model_manually,
         commands::transcription::clear_file_transcription_history,
         commands::transcription::transcribe_audio_file,
+        commands::transcription::retranscribe_history_entry,
         commands::history::get_history_entries,
+        commands::history::search_history_entries,
         commands::history::toggle_history_entry_saved,
+        commands::history::update_history_entry,
+        commands::history::get_history_entry_revisions,
+        commands::history::revert_history_entry,
+        commands::history::reprocess_history_entry,
+        commands::history::get_history_entry_alternates,
+        commands::history::apply_history_entry_alternate,
+        commands::history::delete_history_entry_alternate,
+        commands::history::set_history_entry_tags,
+        commands::history::set_history_entry_note,
+        commands::history::set_history_entry_folder,
+        commands::history::get_history_folders,
+        commands::history::create_history_folder,
+        commands::history::rename_history_folder,
+        commands::history::delete_history_folder,
+        commands::history::get_history_tags,
+        commands::history::submit_history_correction,
         commands::history::get_audio_file_path,
+        commands::history::release_playable_audio,
         commands::history::delete_history_entry,
+        commands::history::get_history_entry_timestamps,
+        commands::history::export_history_entry_transcript,
+        commands::history::export_history_bundle,
+        commands::history::import_history_bundle,
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
+        commands::history::update_meeting_retention_period,
+        commands::history::update_audio_retention_period,
+        commands::history::set_history_encryption,
+        commands::history::set_history_vault_dir,
+        commands::history::get_history_stats,
         helpers::clamshell::is_laptop,
     ]);
 
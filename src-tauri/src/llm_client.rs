@@ -1,10 +1,17 @@
 use crate::settings::PostProcessProvider;
-use log::debug;
+use futures_util::StreamExt;
+use log::{debug, warn};
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, REFERER, USER_AGENT};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 const MODEL_LIST_TIMEOUT: Duration = Duration::from_secs(15);
+const ANTHROPIC_PROVIDER_ID: &str = "anthropic";
+// The Messages API requires an output limit. Every current Claude model
+// accepts this one, and it is plenty for cleanups, answers and summaries.
+const ANTHROPIC_MAX_TOKENS: u32 = 4096;
+// Anthropic pages its model list and returns 20 models by default.
+const ANTHROPIC_MODEL_LIST_LIMIT: u32 = 1000;
 
 #[derive(Debug, Serialize)]
 struct ChatMessage {
@@ -22,6 +29,8 @@ struct ChatCompletionRequest {
     reasoning_effort: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     include_reasoning: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -39,6 +48,167 @@ struct ChatMessageResponse {
     content: Option<String>,
 }
 
+/// One `data:` payload of a streamed chat completion. Some OpenAI-compatible
+/// servers report failures inside the stream instead of with a status code.
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    #[serde(default)]
+    choices: Vec<ChatChunkChoice>,
+    error: Option<ChatStreamError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChunkChoice {
+    #[serde(default)]
+    delta: ChatChunkDelta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChatChunkDelta {
+    content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatStreamError {
+    message: String,
+}
+
+/// Request body for Anthropic's native `/v1/messages` endpoint. Unlike chat
+/// completions, the system prompt is a top-level field.
+#[derive(Debug, Serialize)]
+struct AnthropicMessagesRequest {
+    model: String,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicMessagesResponse {
+    #[serde(default)]
+    content: Vec<AnthropicContentBlock>,
+    stop_reason: Option<String>,
+}
+
+/// Only text blocks carry output here. Others, such as `thinking`, are
+/// skipped.
+#[derive(Debug, Deserialize)]
+struct AnthropicContentBlock {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicErrorResponse {
+    error: AnthropicError,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicError {
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+}
+
+/// One event of a streamed Anthropic reply. Only the fields used to build
+/// the text and detect its end are read.
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    delta: Option<AnthropicStreamDelta>,
+    error: Option<AnthropicError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamDelta {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    text: Option<String>,
+    stop_reason: Option<String>,
+}
+
+/// A server-sent event, with multi-line `data:` fields already joined.
+#[derive(Debug, PartialEq)]
+struct SseEvent {
+    event: Option<String>,
+    data: String,
+}
+
+/// Splits a byte stream into server-sent events. Network chunks can end in
+/// the middle of a line or a UTF-8 sequence, so incomplete lines stay
+/// buffered until the rest arrives.
+#[derive(Debug, Default)]
+struct SseDecoder {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+}
+
+impl SseDecoder {
+    fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line = self.buffer.drain(..=end).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                if let Some(data) = self.data.take() {
+                    events.push(SseEvent {
+                        event: self.event.take(),
+                        data,
+                    });
+                }
+                self.event = None;
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = Some(value.to_string()),
+                "data" => match self.data.as_mut() {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_string()),
+                },
+                _ => {}
+            }
+        }
+        events
+    }
+
+    /// Flushes an event the server did not terminate with a blank line.
+    fn finish(&mut self) -> Vec<SseEvent> {
+        self.push(b"\n\n")
+    }
+}
+
+/// What one server-sent event adds to a streamed reply.
+#[derive(Debug, PartialEq)]
+enum StreamUpdate {
+    Text(String),
+    /// The reply is complete, with any text that arrived in the same event.
+    Done(Option<String>),
+    Ignored,
+}
+
+fn is_anthropic(provider: &PostProcessProvider) -> bool {
+    provider.id == ANTHROPIC_PROVIDER_ID
+}
+
 /// Build headers for API requests based on provider type
 fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<HeaderMap, String> {
     let mut headers = HeaderMap::new();
@@ -57,7 +227,7 @@ fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<Header
 
     // Provider-specific auth headers
     if !api_key.is_empty() {
-        if provider.id == "anthropic" {
+        if is_anthropic(provider) {
             headers.insert(
                 "x-api-key",
                 HeaderValue::from_str(api_key)
@@ -89,7 +259,8 @@ fn uses_fast_groq_post_process_defaults(provider: &PostProcessProvider, model: &
     provider.id == "groq" && model == "openai/gpt-oss-20b"
 }
 
-/// Send a chat completion request to an OpenAI-compatible API
+/// Send a chat completion request to an OpenAI-compatible API, or to the
+/// native Messages API for Anthropic.
 /// Returns Ok(Some(content)) on success, Ok(None) if response has no content,
 /// or Err on actual errors (HTTP, parsing, etc.)
 pub async fn send_chat_completion(
@@ -100,12 +271,53 @@ pub async fn send_chat_completion(
     system_prompt: Option<&str>,
 ) -> Result<Option<String>, String> {
     let base_url = provider.base_url.trim_end_matches('/');
+    let client = create_client(provider, &api_key)?;
+    if is_anthropic(provider) {
+        return send_anthropic_message(&client, base_url, model, prompt, system_prompt).await;
+    }
+
     let url = format!("{}/chat/completions", base_url);
 
     debug!("Sending chat completion request to: {}", url);
 
-    let client = create_client(provider, &api_key)?;
+    let response = client
+        .post(&url)
+        .json(&chat_completion_request(
+            provider,
+            model,
+            prompt,
+            system_prompt,
+        ))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+        return Err(format!(
+            "API request failed with status {}: {}",
+            status, error_text
+        ));
+    }
+
+    let completion: ChatCompletionResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse API response: {}", e))?;
 
+    Ok(chat_completion_text(completion))
+}
+
+fn chat_completion_request(
+    provider: &PostProcessProvider,
+    model: &str,
+    prompt: String,
+    system_prompt: Option<&str>,
+) -> ChatCompletionRequest {
     let mut messages = Vec::new();
     if let Some(sys) = system_prompt {
         if !sys.trim().is_empty() {
@@ -121,17 +333,99 @@ pub async fn send_chat_completion(
     });
 
     let uses_fast_groq_default = uses_fast_groq_post_process_defaults(provider, model);
-    let request_body = ChatCompletionRequest {
+    ChatCompletionRequest {
         model: model.to_string(),
         messages,
         max_completion_tokens: uses_fast_groq_default.then_some(4096),
         reasoning_effort: uses_fast_groq_default.then_some("low"),
         include_reasoning: uses_fast_groq_default.then_some(false),
-    };
+        stream: None,
+    }
+}
+
+fn chat_completion_text(completion: ChatCompletionResponse) -> Option<String> {
+    completion
+        .choices
+        .into_iter()
+        .next()
+        .and_then(|choice| choice.message.content)
+}
+
+fn anthropic_messages_request(
+    model: &str,
+    prompt: String,
+    system_prompt: Option<&str>,
+) -> AnthropicMessagesRequest {
+    AnthropicMessagesRequest {
+        model: model.to_string(),
+        max_tokens: ANTHROPIC_MAX_TOKENS,
+        system: system_prompt
+            .filter(|sys| !sys.trim().is_empty())
+            .map(str::to_string),
+        messages: vec![ChatMessage {
+            role: "user".to_string(),
+            content: prompt,
+        }],
+        stream: None,
+    }
+}
+
+/// Joins the text blocks of a reply. A reply cut off by the token limit or
+/// refused by the model is an error, so callers keep their input instead of
+/// using partial output.
+fn anthropic_response_text(response: AnthropicMessagesResponse) -> Result<Option<String>, String> {
+    check_anthropic_stop_reason(response.stop_reason.as_deref())?;
+
+    let text = response
+        .content
+        .into_iter()
+        .filter(|block| block.kind == "text")
+        .filter_map(|block| block.text)
+        .collect::<String>();
+    Ok((!text.is_empty()).then_some(text))
+}
+
+fn check_anthropic_stop_reason(stop_reason: Option<&str>) -> Result<(), String> {
+    match stop_reason {
+        Some("max_tokens") => Err(format!(
+            "Anthropic response was cut off at {} tokens",
+            ANTHROPIC_MAX_TOKENS
+        )),
+        Some("refusal") => Err("Anthropic declined to answer the request".to_string()),
+        Some("end_turn") | Some("stop_sequence") | None => Ok(()),
+        Some(other) => {
+            warn!("Unexpected Anthropic stop reason: {}", other);
+            Ok(())
+        }
+    }
+}
+
+/// Turns an Anthropic error body into a readable message, falling back to
+/// the raw body when it is not the documented shape.
+fn anthropic_error_message(status: reqwest::StatusCode, body: &str) -> String {
+    match serde_json::from_str::<AnthropicErrorResponse>(body) {
+        Ok(parsed) => format!(
+            "Anthropic request failed with status {} ({}): {}",
+            status, parsed.error.kind, parsed.error.message
+        ),
+        Err(_) => format!("Anthropic request failed with status {}: {}", status, body),
+    }
+}
+
+async fn send_anthropic_message(
+    client: &reqwest::Client,
+    base_url: &str,
+    model: &str,
+    prompt: String,
+    system_prompt: Option<&str>,
+) -> Result<Option<String>, String> {
+    let url = format!("{}/messages", base_url);
+
+    debug!("Sending Anthropic messages request to: {}", url);
 
     let response = client
         .post(&url)
-        .json(&request_body)
+        .json(&anthropic_messages_request(model, prompt, system_prompt))
         .send()
         .await
         .map_err(|e| format!("HTTP request failed: {}", e))?;
@@ -142,21 +436,203 @@ pub async fn send_chat_completion(
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error response".to_string());
-        return Err(format!(
-            "API request failed with status {}: {}",
-            status, error_text
-        ));
+        return Err(anthropic_error_message(status, &error_text));
     }
 
-    let completion: ChatCompletionResponse = response
+    let message: AnthropicMessagesResponse = response
         .json()
         .await
         .map_err(|e| format!("Failed to parse API response: {}", e))?;
 
-    Ok(completion
-        .choices
-        .first()
-        .and_then(|choice| choice.message.content.clone()))
+    anthropic_response_text(message)
+}
+
+/// Sends the same request as [`send_chat_completion`] with streaming turned
+/// on. `on_text` receives the reply received so far each time it grows;
+/// returning `false` drops the request and fails with a cancellation error.
+/// A stream that ends before the provider marks the reply complete is an
+/// error too, so a dropped connection is never taken for a full answer.
+/// Servers that ignore `stream` and answer with a plain JSON body are
+/// handled too, with `on_text` called once.
+pub async fn stream_chat_completion<F>(
+    provider: &PostProcessProvider,
+    api_key: String,
+    model: &str,
+    prompt: String,
+    system_prompt: Option<&str>,
+    mut on_text: F,
+) -> Result<Option<String>, String>
+where
+    F: FnMut(&str) -> bool,
+{
+    let base_url = provider.base_url.trim_end_matches('/');
+    let client = create_client(provider, &api_key)?;
+    let anthropic = is_anthropic(provider);
+    let request = if anthropic {
+        let url = format!("{}/messages", base_url);
+        debug!("Streaming Anthropic messages request to: {}", url);
+        client.post(&url).json(&AnthropicMessagesRequest {
+            stream: Some(true),
+            ..anthropic_messages_request(model, prompt, system_prompt)
+        })
+    } else {
+        let url = format!("{}/chat/completions", base_url);
+        debug!("Streaming chat completion request to: {}", url);
+        client.post(&url).json(&ChatCompletionRequest {
+            stream: Some(true),
+            ..chat_completion_request(provider, model, prompt, system_prompt)
+        })
+    };
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+        return Err(if anthropic {
+            anthropic_error_message(status, &error_text)
+        } else {
+            format!("API request failed with status {}: {}", status, error_text)
+        });
+    }
+
+    let is_event_stream = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if !is_event_stream {
+        let body = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read API response: {}", e))?;
+        let text = if anthropic {
+            anthropic_response_text(
+                serde_json::from_str(&body)
+                    .map_err(|e| format!("Failed to parse API response: {}", e))?,
+            )?
+        } else {
+            chat_completion_text(
+                serde_json::from_str(&body)
+                    .map_err(|e| format!("Failed to parse API response: {}", e))?,
+            )
+        };
+        if let Some(text) = text.as_deref() {
+            on_text(text);
+        }
+        return Ok(text);
+    }
+
+    let parse_event = if anthropic {
+        anthropic_stream_update
+    } else {
+        chat_completion_stream_update
+    };
+    let mut decoder = SseDecoder::default();
+    let mut stream = response.bytes_stream();
+    let mut text = String::new();
+    let mut complete = false;
+    let mut ended = false;
+    while !complete && !ended {
+        let events = match stream.next().await {
+            Some(chunk) => {
+                let chunk =
+                    chunk.map_err(|e| format!("Failed to read streamed response: {}", e))?;
+                decoder.push(&chunk)
+            }
+            None => {
+                ended = true;
+                decoder.finish()
+            }
+        };
+        for event in events {
+            let (delta, done) = match parse_event(&event)? {
+                StreamUpdate::Text(delta) => (Some(delta), false),
+                StreamUpdate::Done(delta) => (delta, true),
+                StreamUpdate::Ignored => (None, false),
+            };
+            if let Some(delta) = delta.filter(|delta| !delta.is_empty()) {
+                text.push_str(&delta);
+                if !on_text(&text) {
+                    debug!("Stopped reading a streamed reply after cancellation");
+                    return Err("Streamed reply was cancelled".to_string());
+                }
+            }
+            if done {
+                complete = true;
+                break;
+            }
+        }
+    }
+    if !complete {
+        return Err("Streamed response ended before the reply was complete".to_string());
+    }
+
+    Ok((!text.is_empty()).then_some(text))
+}
+
+fn chat_completion_stream_update(event: &SseEvent) -> Result<StreamUpdate, String> {
+    let data = event.data.trim();
+    if data == "[DONE]" {
+        return Ok(StreamUpdate::Done(None));
+    }
+
+    let chunk: ChatCompletionChunk = serde_json::from_str(data)
+        .map_err(|e| format!("Failed to parse streamed API response: {}", e))?;
+    if let Some(error) = chunk.error {
+        return Err(format!("API stream failed: {}", error.message));
+    }
+
+    let Some(choice) = chunk.choices.into_iter().next() else {
+        return Ok(StreamUpdate::Ignored);
+    };
+    // Some servers close the stream after the finishing chunk without
+    // sending [DONE], so any finish reason other than a cut-off ends the
+    // reply.
+    match choice.finish_reason.as_deref() {
+        Some("length") => Err("Response was cut off at the model's token limit".to_string()),
+        Some(_) => Ok(StreamUpdate::Done(choice.delta.content)),
+        None => Ok(choice
+            .delta
+            .content
+            .map_or(StreamUpdate::Ignored, StreamUpdate::Text)),
+    }
+}
+
+fn anthropic_stream_update(event: &SseEvent) -> Result<StreamUpdate, String> {
+    let parsed: AnthropicStreamEvent = serde_json::from_str(&event.data)
+        .map_err(|e| format!("Failed to parse streamed API response: {}", e))?;
+    match parsed.kind.as_str() {
+        "content_block_delta" => Ok(parsed
+            .delta
+            .filter(|delta| delta.kind.as_deref() == Some("text_delta"))
+            .and_then(|delta| delta.text)
+            .map_or(StreamUpdate::Ignored, StreamUpdate::Text)),
+        "message_delta" => {
+            check_anthropic_stop_reason(
+                parsed
+                    .delta
+                    .as_ref()
+                    .and_then(|delta| delta.stop_reason.as_deref()),
+            )?;
+            Ok(StreamUpdate::Ignored)
+        }
+        "message_stop" => Ok(StreamUpdate::Done(None)),
+        "error" => Err(match parsed.error {
+            Some(error) => format!(
+                "Anthropic stream failed ({}): {}",
+                error.kind, error.message
+            ),
+            None => "Anthropic stream failed".to_string(),
+        }),
+        _ => Ok(StreamUpdate::Ignored),
+    }
 }
 
 #[cfg(test)]
@@ -173,6 +649,188 @@ mod tests {
         }
     }
 
+    #[test]
+    fn anthropic_requests_put_the_system_prompt_at_the_top_level() {
+        let request = anthropic_messages_request(
+            "claude-sonnet-4-5",
+            "clean this".to_string(),
+            Some("You clean transcripts."),
+        );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "model": "claude-sonnet-4-5",
+                "max_tokens": ANTHROPIC_MAX_TOKENS,
+                "system": "You clean transcripts.",
+                "messages": [{ "role": "user", "content": "clean this" }],
+            })
+        );
+
+        let request = anthropic_messages_request("claude-sonnet-4-5", "hi".to_string(), Some(" "));
+        assert!(serde_json::to_value(&request)
+            .unwrap()
+            .get("system")
+            .is_none());
+    }
+
+    #[test]
+    fn anthropic_responses_join_text_blocks_and_reject_partial_output() {
+        let parse = |body: serde_json::Value| {
+            anthropic_response_text(serde_json::from_value(body).unwrap())
+        };
+
+        assert_eq!(
+            parse(serde_json::json!({
+                "content": [
+                    { "type": "thinking", "thinking": "..." },
+                    { "type": "text", "text": "Hello " },
+                    { "type": "text", "text": "there." },
+                ],
+                "stop_reason": "end_turn",
+            })),
+            Ok(Some("Hello there.".to_string()))
+        );
+        assert_eq!(
+            parse(serde_json::json!({ "content": [], "stop_reason": "end_turn" })),
+            Ok(None)
+        );
+        assert!(parse(serde_json::json!({
+            "content": [{ "type": "text", "text": "Hello" }],
+            "stop_reason": "max_tokens",
+        }))
+        .is_err());
+        assert!(parse(serde_json::json!({ "content": [], "stop_reason": "refusal" })).is_err());
+    }
+
+    #[test]
+    fn anthropic_error_bodies_are_summarized() {
+        let status = reqwest::StatusCode::NOT_FOUND;
+        assert_eq!(
+            anthropic_error_message(
+                status,
+                r#"{"type":"error","error":{"type":"not_found_error","message":"model: claude-9"}}"#
+            ),
+            "Anthropic request failed with status 404 Not Found (not_found_error): model: claude-9"
+        );
+        assert_eq!(
+            anthropic_error_message(status, "gateway down"),
+            "Anthropic request failed with status 404 Not Found: gateway down"
+        );
+    }
+
+    #[test]
+    fn sse_decoder_reassembles_events_split_across_chunks() {
+        let stream = "event: content_block_delta\r\ndata: {\"text\":\"caf\u{e9}\"}\r\n\r\n: ping\n\ndata: [DONE]"
+            .as_bytes();
+        // Split inside the two-byte UTF-8 sequence for the accented letter.
+        let split = stream.iter().position(|byte| *byte == 0xc3).unwrap() + 1;
+
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(&stream[..split]).is_empty());
+        assert_eq!(
+            decoder.push(&stream[split..]),
+            vec![SseEvent {
+                event: Some("content_block_delta".to_string()),
+                data: "{\"text\":\"caf\u{e9}\"}".to_string(),
+            }]
+        );
+        assert_eq!(
+            decoder.finish(),
+            vec![SseEvent {
+                event: None,
+                data: "[DONE]".to_string(),
+            }]
+        );
+
+        let events = SseDecoder::default().push(b"data: first\ndata: second\n\n");
+        assert_eq!(events[0].data, "first\nsecond");
+    }
+
+    #[test]
+    fn chat_completion_stream_events_yield_content_deltas() {
+        let update = |data: &str| {
+            chat_completion_stream_update(&SseEvent {
+                event: None,
+                data: data.to_string(),
+            })
+        };
+
+        assert_eq!(
+            update(r#"{"choices":[{"delta":{"content":"Hel"},"finish_reason":null}]}"#),
+            Ok(StreamUpdate::Text("Hel".to_string()))
+        );
+        assert_eq!(
+            update(r#"{"choices":[{"delta":{"role":"assistant"}}]}"#),
+            Ok(StreamUpdate::Ignored)
+        );
+        assert_eq!(update(r#"{"choices":[]}"#), Ok(StreamUpdate::Ignored));
+        assert_eq!(update("[DONE]"), Ok(StreamUpdate::Done(None)));
+        assert_eq!(
+            update(r#"{"choices":[{"delta":{"content":"lo"},"finish_reason":"stop"}]}"#),
+            Ok(StreamUpdate::Done(Some("lo".to_string())))
+        );
+        assert_eq!(
+            update(r#"{"choices":[{"delta":{},"finish_reason":"tool_calls"}]}"#),
+            Ok(StreamUpdate::Done(None))
+        );
+        assert!(update(r#"{"choices":[{"delta":{},"finish_reason":"length"}]}"#).is_err());
+        assert!(update(r#"{"error":{"message":"overloaded"}}"#).is_err());
+    }
+
+    #[test]
+    fn anthropic_stream_events_yield_text_and_reject_partial_output() {
+        let update = |data: serde_json::Value| {
+            anthropic_stream_update(&SseEvent {
+                event: data["type"].as_str().map(str::to_string),
+                data: data.to_string(),
+            })
+        };
+
+        assert_eq!(
+            update(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": "Hi" },
+            })),
+            Ok(StreamUpdate::Text("Hi".to_string()))
+        );
+        assert_eq!(
+            update(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "thinking_delta", "thinking": "..." },
+            })),
+            Ok(StreamUpdate::Ignored)
+        );
+        assert_eq!(
+            update(serde_json::json!({ "type": "ping" })),
+            Ok(StreamUpdate::Ignored)
+        );
+        assert_eq!(
+            update(serde_json::json!({
+                "type": "message_delta",
+                "delta": { "stop_reason": "end_turn" },
+            })),
+            Ok(StreamUpdate::Ignored)
+        );
+        assert!(update(serde_json::json!({
+            "type": "message_delta",
+            "delta": { "stop_reason": "max_tokens" },
+        }))
+        .is_err());
+        assert_eq!(
+            update(serde_json::json!({ "type": "message_stop" })),
+            Ok(StreamUpdate::Done(None))
+        );
+        assert_eq!(
+            update(serde_json::json!({
+                "type": "error",
+                "error": { "type": "overloaded_error", "message": "Overloaded" },
+            })),
+            Err("Anthropic stream failed (overloaded_error): Overloaded".to_string())
+        );
+    }
+
     #[test]
     fn fast_groq_defaults_only_apply_to_gpt_oss_20b() {
         assert!(uses_fast_groq_post_process_defaults(
@@ -197,7 +855,11 @@ pub async fn fetch_models(
     api_key: String,
 ) -> Result<Vec<String>, String> {
     let base_url = provider.base_url.trim_end_matches('/');
-    let url = format!("{}/models", base_url);
+    let url = if is_anthropic(provider) {
+        format!("{}/models?limit={}", base_url, ANTHROPIC_MODEL_LIST_LIMIT)
+    } else {
+        format!("{}/models", base_url)
+    };
 
     debug!("Fetching models from: {}", url);
 
@@ -216,6 +878,9 @@ pub async fn fetch_models(
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
+        if is_anthropic(provider) {
+            return Err(anthropic_error_message(status, &error_text));
+        }
         return Err(format!(
             "Model list request failed ({}): {}",
             status, error_text
@@ -229,7 +894,7 @@ pub async fn fetch_models(
 
     let mut models = Vec::new();
 
-    // Handle OpenAI format: { data: [ { id: "..." }, ... ] }
+    // Handle OpenAI and Anthropic format: { data: [ { id: "..." }, ... ] }
     if let Some(data) = parsed.get("data").and_then(|d| d.as_array()) {
         for entry in data {
             if let Some(id) = entry.get("id").and_then(|i| i.as_str()) {
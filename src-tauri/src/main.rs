@@ -9,5 +9,9 @@ fn main() {
         std::env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
     }
 
+    if let Some(exit_code) = handy_app_lib::run_cli(std::env::args().skip(1).collect()) {
+        std::process::exit(exit_code);
+    }
+
     handy_app_lib::run()
 }
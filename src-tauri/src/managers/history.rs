@@ -5,12 +5,27 @@ use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use std::fs;
-use std::path::{Path, PathBuf};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Seek};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
 use uuid::Uuid;
+use zip::ZipArchive;
 
-use crate::audio_toolkit::save_wav_file;
+use super::history_bundle::{
+    extract_audio, read_manifest, write_bundle, BundleEntry, BundleManifest, HistoryExportSummary,
+    HistoryImportSummary,
+};
+use super::history_crypto::{register_sql_functions, HistoryEncryption};
+use super::history_stats::{
+    audio_duration_ms, backfill_stats_with_conn, get_stats_with_conn, record_stats_with_conn,
+    set_app_name_with_conn, HistoryStats,
+};
+use super::history_vault::{self, HistoryVault};
+use crate::audio_toolkit::{encode_wav, save_wav_file};
+use crate::settings::SavedFileTranscription;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -44,25 +59,414 @@ static MIGRATIONS: &[M] = &[
             ON transcription_history (timestamp DESC)
             WHERE saved = 0 AND recording_source != 'full_system_audio';",
     ),
+    M::up(
+        "CREATE TABLE IF NOT EXISTS transcription_segments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL,
+            granularity TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            start_ms INTEGER NOT NULL,
+            end_ms INTEGER NOT NULL,
+            text TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS transcription_segments_history_idx
+            ON transcription_segments (history_id, granularity, position);
+        CREATE TRIGGER IF NOT EXISTS transcription_segments_history_delete
+            AFTER DELETE ON transcription_history
+        BEGIN
+            DELETE FROM transcription_segments WHERE history_id = old.id;
+        END;",
+    ),
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN transcription_route TEXT;
+        ALTER TABLE transcription_history ADD COLUMN fallback_from TEXT;",
+    ),
+    M::up(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS transcription_history_fts USING fts5(
+            title,
+            transcription_text,
+            post_processed_text,
+            content = 'transcription_history',
+            content_rowid = 'id',
+            tokenize = 'unicode61 remove_diacritics 2'
+        );
+        INSERT INTO transcription_history_fts (transcription_history_fts) VALUES ('rebuild');
+        CREATE TRIGGER IF NOT EXISTS transcription_history_fts_insert
+            AFTER INSERT ON transcription_history
+        BEGIN
+            INSERT INTO transcription_history_fts (rowid, title, transcription_text, post_processed_text)
+            VALUES (new.id, new.title, new.transcription_text, new.post_processed_text);
+        END;
+        CREATE TRIGGER IF NOT EXISTS transcription_history_fts_delete
+            AFTER DELETE ON transcription_history
+        BEGIN
+            INSERT INTO transcription_history_fts (transcription_history_fts, rowid, title, transcription_text, post_processed_text)
+            VALUES ('delete', old.id, old.title, old.transcription_text, old.post_processed_text);
+        END;
+        CREATE TRIGGER IF NOT EXISTS transcription_history_fts_update
+            AFTER UPDATE OF title, transcription_text, post_processed_text ON transcription_history
+        BEGIN
+            INSERT INTO transcription_history_fts (transcription_history_fts, rowid, title, transcription_text, post_processed_text)
+            VALUES ('delete', old.id, old.title, old.transcription_text, old.post_processed_text);
+            INSERT INTO transcription_history_fts (rowid, title, transcription_text, post_processed_text)
+            VALUES (new.id, new.title, new.transcription_text, new.post_processed_text);
+        END;",
+    ),
+    M::up(
+        "CREATE TABLE IF NOT EXISTS transcription_revisions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL,
+            created_at INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            transcription_text TEXT NOT NULL,
+            post_processed_text TEXT
+        );
+        CREATE INDEX IF NOT EXISTS transcription_revisions_history_idx
+            ON transcription_revisions (history_id, id);
+        CREATE TRIGGER IF NOT EXISTS transcription_revisions_history_delete
+            AFTER DELETE ON transcription_history
+        BEGIN
+            DELETE FROM transcription_revisions WHERE history_id = old.id;
+        END;",
+    ),
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN note TEXT;
+        ALTER TABLE transcription_history ADD COLUMN folder_id INTEGER;
+        CREATE INDEX IF NOT EXISTS transcription_history_folder_idx
+            ON transcription_history (folder_id, timestamp DESC);
+        CREATE TABLE IF NOT EXISTS history_folders (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            created_at INTEGER NOT NULL
+        );
+        CREATE TRIGGER IF NOT EXISTS history_folders_delete
+            AFTER DELETE ON history_folders
+        BEGIN
+            UPDATE transcription_history SET folder_id = NULL WHERE folder_id = old.id;
+        END;
+        CREATE TABLE IF NOT EXISTS transcription_tags (
+            history_id INTEGER NOT NULL,
+            tag TEXT NOT NULL COLLATE NOCASE,
+            PRIMARY KEY (history_id, tag)
+        ) WITHOUT ROWID;
+        CREATE INDEX IF NOT EXISTS transcription_tags_tag_idx
+            ON transcription_tags (tag, history_id);
+        CREATE TRIGGER IF NOT EXISTS transcription_tags_history_delete
+            AFTER DELETE ON transcription_history
+        BEGIN
+            DELETE FROM transcription_tags WHERE history_id = old.id;
+        END;",
+    ),
+    M::up("ALTER TABLE transcription_history ADD COLUMN transcription_model TEXT;"),
+    // Usage counts deliberately have no delete trigger, so they outlive the
+    // entries they describe. See history_stats.
+    M::up(
+        "CREATE TABLE IF NOT EXISTS transcription_stats (
+            history_id INTEGER PRIMARY KEY,
+            timestamp INTEGER NOT NULL,
+            recording_source TEXT NOT NULL,
+            word_count INTEGER NOT NULL,
+            char_count INTEGER NOT NULL,
+            audio_duration_ms INTEGER NOT NULL,
+            app_name TEXT
+        );
+        CREATE INDEX IF NOT EXISTS transcription_stats_timestamp_idx
+            ON transcription_stats (timestamp);",
+    ),
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN audio_purged BOOLEAN NOT NULL DEFAULT 0;",
+    ),
+    M::up(
+        "CREATE TABLE IF NOT EXISTS transcription_alternates (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL,
+            created_at INTEGER NOT NULL,
+            transcription_model TEXT,
+            language TEXT,
+            transcription_route TEXT,
+            transcription_text TEXT NOT NULL,
+            post_processed_text TEXT
+        );
+        CREATE INDEX IF NOT EXISTS transcription_alternates_history_idx
+            ON transcription_alternates (history_id, id);
+        CREATE TRIGGER IF NOT EXISTS transcription_alternates_history_delete
+            AFTER DELETE ON transcription_history
+        BEGIN
+            DELETE FROM transcription_alternates WHERE history_id = old.id;
+        END;",
+    ),
+    M::up("ALTER TABLE transcription_history ADD COLUMN source_path TEXT;"),
+    // Revisions saved before this column existed take the entry's current
+    // prompt, the best guess available for what produced them.
+    M::up(
+        "ALTER TABLE transcription_revisions ADD COLUMN post_process_prompt TEXT;
+        UPDATE transcription_revisions
+        SET post_process_prompt = (
+            SELECT post_process_prompt FROM transcription_history
+            WHERE transcription_history.id = transcription_revisions.history_id
+        );",
+    ),
 ];
 
+// Text columns go through history_decode so encrypted history reads like
+// plaintext. Tags come back as one string joined with TAG_SEPARATOR, which
+// normalize_tags keeps out of tag names.
+const HISTORY_ENTRY_COLUMNS: &str = "id, file_name, timestamp, saved, audio_purged,
+     history_decode(title) AS title,
+     history_decode(transcription_text) AS transcription_text,
+     history_decode(post_processed_text) AS post_processed_text,
+     history_decode(post_process_prompt) AS post_process_prompt,
+     recording_source, history_decode(source_path) AS source_path,
+     transcription_route, fallback_from, transcription_model,
+     history_decode(note) AS note, folder_id,
+     (SELECT group_concat(tag, char(31)) FROM transcription_tags WHERE transcription_tags.history_id = transcription_history.id) AS tags";
+const TAG_SEPARATOR: char = '\u{1f}';
+const MAX_TAG_CHARS: usize = 40;
+const MAX_FOLDER_NAME_CHARS: usize = 80;
+/// How long a write waits for another connection's lock. Converting the
+/// encryption of a large history holds the lock through a full rewrite and
+/// VACUUM, and a dictation saved meanwhile has to wait that out rather than
+/// fail and lose its recording.
+const HISTORY_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+/// Decrypted copy of the recording being played, inside `recordings_dir` so
+/// the webview is allowed to load it.
+const PLAYBACK_DIR: &str = ".playback";
+
+// Matches entries in folder ?1 and with tag ?2, either of which may be NULL.
+const ORGANIZATION_FILTER_SQL: &str = "(?1 IS NULL OR folder_id = ?1)
+         AND (?2 IS NULL OR EXISTS (
+             SELECT 1 FROM transcription_tags
+             WHERE transcription_tags.history_id = transcription_history.id AND tag = ?2
+         ))";
+
+/// Wraps matched terms in search snippets. Private-use code points cannot
+/// appear in dictated text, so the frontend can split on them safely.
+pub const SEARCH_MATCH_START: &str = "\u{E000}";
+pub const SEARCH_MATCH_END: &str = "\u{E001}";
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+const MAX_SEARCH_LIMIT: u32 = 500;
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// Recording source of entries transcribed from an audio file the user
+/// picked. No recording is kept for them.
+pub const FILE_IMPORT_SOURCE: &str = "file_import";
+
+const SEGMENT_GRANULARITY: &str = "segment";
+const WORD_GRANULARITY: &str = "word";
+
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 pub struct HistoryEntry {
     pub id: i64,
     pub file_name: String,
     pub timestamp: i64,
     pub saved: bool,
+    /// No recording is stored: audio retention deleted it, or the text came
+    /// from an imported file.
+    #[serde(default)]
+    pub audio_purged: bool,
     pub title: String,
     pub transcription_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
     pub recording_source: String,
+    /// Path of the imported file for [`FILE_IMPORT_SOURCE`] entries.
+    #[serde(default)]
+    pub source_path: Option<String>,
+    /// Route that produced the text, e.g. `local`, `groq`, `openai` or `backend_proxy`.
+    pub transcription_route: Option<String>,
+    /// Route that failed first when the text came from a fallback.
+    pub fallback_from: Option<String>,
+    /// Model id that produced the text, e.g. `parakeet-tdt-0.6b-v3`.
+    pub transcription_model: Option<String>,
+    /// Free-form note from the user, kept apart from the transcript.
+    pub note: Option<String>,
+    pub folder_id: Option<i64>,
+    /// Sorted case-insensitively.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A span of transcript text with offsets (in milliseconds) into the saved WAV.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct TranscriptSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+/// Timings reported by the engine for one entry. `words` stays empty for
+/// engines that only report segment-level timings.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct TranscriptTimestamps {
+    pub segments: Vec<TranscriptSegment>,
+    pub words: Vec<TranscriptSegment>,
+}
+
+/// Optional filters for [`HistoryManager::search_entries`]. Timestamps are
+/// inclusive Unix seconds.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Type)]
+pub struct HistorySearchFilters {
+    pub recording_source: Option<String>,
+    pub from_timestamp: Option<i64>,
+    pub to_timestamp: Option<i64>,
+    pub saved: Option<bool>,
+    pub folder_id: Option<i64>,
+    pub tag: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistorySearchResult {
+    pub entry: HistoryEntry,
+    /// Excerpt around the best match with terms wrapped in
+    /// `SEARCH_MATCH_START`/`SEARCH_MATCH_END`. Empty for filter-only searches.
+    pub snippet: String,
+}
+
+/// Changes for [`HistoryManager::update_entry`]. `None` keeps the current
+/// value and an empty `post_processed_text` clears it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Type)]
+pub struct HistoryEntryUpdate {
+    pub title: Option<String>,
+    pub transcription_text: Option<String>,
+    pub post_processed_text: Option<String>,
+}
+
+/// Another transcript of an entry's recording, made by transcribing it again
+/// with a different model or language. It sits beside the entry until it is
+/// applied or deleted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct HistoryAlternate {
+    pub id: i64,
+    pub history_id: i64,
+    pub created_at: i64,
+    pub transcription_model: Option<String>,
+    /// Language the run was asked for, e.g. `auto` or `de`.
+    pub language: Option<String>,
+    pub transcription_route: Option<String>,
+    pub transcription_text: String,
+    pub post_processed_text: Option<String>,
+}
+
+/// A transcript to store with [`HistoryManager::add_alternate`].
+#[derive(Clone, Debug, Default)]
+pub struct NewHistoryAlternate {
+    pub transcription_model: Option<String>,
+    pub language: Option<String>,
+    pub transcription_route: Option<String>,
+    pub transcription_text: String,
+    pub post_processed_text: Option<String>,
+}
+
+/// An entry's text as it was before one of its edits.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct HistoryRevision {
+    pub id: i64,
+    pub history_id: i64,
+    /// When this version was replaced, in Unix seconds.
+    pub created_at: i64,
+    pub title: String,
+    pub transcription_text: String,
+    pub post_processed_text: Option<String>,
+    pub post_process_prompt: Option<String>,
+}
+
+/// Which recordings a history page includes. Meetings are full system audio
+/// sessions; every other source, file imports included, counts as a
+/// dictation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HistorySourceFilter {
+    #[default]
+    All,
+    Dictations,
+    Meetings,
+    FileImports,
+}
+
+impl HistorySourceFilter {
+    fn sql_condition(self) -> &'static str {
+        match self {
+            Self::All => "1",
+            Self::Dictations => "recording_source != 'full_system_audio'",
+            Self::Meetings => "recording_source = 'full_system_audio'",
+            Self::FileImports => "recording_source = 'file_import'",
+        }
+    }
+}
+
+/// Filters for [`HistoryManager::get_history_page`]. Tags match
+/// case-insensitively.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Type)]
+pub struct HistoryPageFilter {
+    #[serde(default)]
+    pub source: HistorySourceFilter,
+    pub folder_id: Option<i64>,
+    pub tag: Option<String>,
+}
+
+/// A user-created group of entries, such as one per client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryFolder {
+    pub id: i64,
+    pub name: String,
+    pub entry_count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryTag {
+    pub name: String,
+    pub entry_count: u32,
+}
+
+/// Position of the last entry on a page. Pages are ordered newest first, with
+/// `id` breaking ties between entries saved in the same second.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryCursor {
+    pub timestamp: i64,
+    pub id: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistoryPage {
+    pub entries: Vec<HistoryEntry>,
+    /// `None` once the last page has been returned.
+    pub next_cursor: Option<HistoryCursor>,
+    /// Entries matching the filter across all pages. Only counted for the
+    /// first page; later pages return `None`.
+    pub total_count: Option<u32>,
+}
+
+/// A recording the webview can load. `temporary` marks a decrypted copy that
+/// has to be released once it has been read.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct PlayableAudio {
+    pub path: String,
+    pub temporary: bool,
+}
+
+/// Payload of the `history-encryption-progress` event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryEncryptionProgress {
+    /// Recordings checked so far, whether or not they needed converting.
+    pub processed: u32,
+    pub total: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptExportFormat {
+    Srt,
+    Vtt,
+    Json,
 }
 
 pub struct HistoryManager {
     app_handle: Option<AppHandle>,
     recordings_dir: PathBuf,
     db_path: PathBuf,
+    encryption: Arc<HistoryEncryption>,
+    vault: Arc<HistoryVault>,
 }
 
 impl HistoryManager {
@@ -78,14 +482,29 @@ impl HistoryManager {
             debug!("Created recordings directory: {:?}", recordings_dir);
         }
 
+        // Playback copies are only needed while the app runs.
+        let playback_dir = recordings_dir.join(PLAYBACK_DIR);
+        if playback_dir.exists() {
+            if let Err(e) = fs::remove_dir_all(&playback_dir) {
+                error!("Failed to remove decrypted playback copies: {}", e);
+            }
+        }
+
+        let encrypt_at_rest = crate::settings::get_encrypt_history_at_rest(app_handle);
+        let vault_install_id = history_vault::load_or_create_install_id(&app_data_dir)?;
+        let vault_dir = crate::settings::get_history_vault_dir(app_handle).map(PathBuf::from);
         let manager = Self {
             app_handle: Some(app_handle.clone()),
             recordings_dir,
             db_path,
+            encryption: Arc::new(HistoryEncryption::new(app_data_dir, encrypt_at_rest)),
+            vault: Arc::new(HistoryVault::new(vault_install_id, vault_dir)),
         };
 
         // Initialize database and run migrations synchronously
         manager.init_database()?;
+        manager.migrate_file_transcription_history(app_handle);
+        manager.backfill_stats_in_background();
 
         Ok(manager)
     }
@@ -100,6 +519,11 @@ impl HistoryManager {
             app_handle: None,
             recordings_dir,
             db_path,
+            encryption: Arc::new(HistoryEncryption::new(root.to_path_buf(), false)),
+            vault: Arc::new(HistoryVault::new(
+                history_vault::load_or_create_install_id(root)?,
+                None,
+            )),
         };
         manager.init_database()?;
         Ok(manager)
@@ -202,7 +626,7 @@ impl HistoryManager {
     }
 
     fn get_connection(&self) -> Result<Connection> {
-        Ok(Connection::open(&self.db_path)?)
+        open_connection(&self.db_path, self.encryption.clone())
     }
 
     /// Save a transcription to history (both database and WAV file)
@@ -217,17 +641,28 @@ impl HistoryManager {
         let timestamp = Utc::now().timestamp();
         let file_name = recording_file_name(timestamp);
         let title = self.format_timestamp_title(timestamp);
+        let duration_ms = audio_duration_ms(audio_samples.len());
 
-        // Save WAV file
+        // Save WAV file, encoding it in memory when it has to be encrypted so
+        // the plaintext never reaches the disk
         let file_path = self.recordings_dir.join(&file_name);
-        save_wav_file(file_path.clone(), &audio_samples).await?;
+        if self.encryption.is_enabled() {
+            let encryption = self.encryption.clone();
+            let path = file_path.clone();
+            tokio::task::spawn_blocking(move || {
+                encryption.write_file(&path, &encode_wav(&audio_samples)?)
+            })
+            .await??;
+        } else {
+            save_wav_file(file_path.clone(), &audio_samples).await?;
+        }
 
         // Save to database
         let entry_id = match self.save_to_database(
             file_name,
             timestamp,
             title,
-            transcription_text,
+            transcription_text.clone(),
             post_processed_text,
             post_process_prompt,
             recording_source.to_string(),
@@ -244,6 +679,21 @@ impl HistoryManager {
             }
         };
 
+        let stats_result = self.get_connection().and_then(|conn| {
+            self.mirror_entry_with_id(&conn, entry_id);
+            record_stats_with_conn(
+                &conn,
+                entry_id,
+                timestamp,
+                recording_source,
+                &transcription_text,
+                duration_ms,
+            )
+        });
+        if let Err(e) = stats_result {
+            error!("Failed to record usage stats for entry {}: {}", entry_id, e);
+        }
+
         // The row and WAV are committed at this point. Retention cleanup is
         // maintenance and must not hide the rollback identity from callers.
         if let Err(cleanup_error) = self.cleanup_old_entries() {
@@ -275,7 +725,8 @@ impl HistoryManager {
     ) -> Result<i64> {
         let conn = self.get_connection()?;
         conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source)
+             VALUES (?1, ?2, ?3, history_encode(?4), history_encode(?5), history_encode(?6), history_encode(?7), ?8)",
             params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, recording_source],
         )?;
 
@@ -283,27 +734,115 @@ impl HistoryManager {
         Ok(conn.last_insert_rowid())
     }
 
+    /// Saves the transcript of an imported audio file. Only the text is
+    /// kept; the entry's `source_path` points at the original file.
+    pub async fn save_file_transcription(
+        &self,
+        file: &SavedFileTranscription,
+        post_process_prompt: Option<&str>,
+        duration_ms: i64,
+    ) -> Result<i64> {
+        let timestamp = Utc::now().timestamp();
+        let conn = self.get_connection()?;
+        let entry_id = insert_file_import_with_conn(&conn, timestamp, file, post_process_prompt)?;
+        self.mirror_entry_with_id(&conn, entry_id);
+        if let Err(e) = record_stats_with_conn(
+            &conn,
+            entry_id,
+            timestamp,
+            FILE_IMPORT_SOURCE,
+            &file.transcription_text,
+            duration_ms,
+        ) {
+            error!("Failed to record usage stats for entry {}: {}", entry_id, e);
+        }
+
+        if let Err(cleanup_error) = self.cleanup_old_entries() {
+            error!(
+                "Failed to clean up old history entries after saving entry {}: {}",
+                entry_id, cleanup_error
+            );
+        }
+        self.emit_history_updated();
+        Ok(entry_id)
+    }
+
+    /// Deletes file transcriptions that are not saved. Returns how many were
+    /// deleted.
+    pub async fn delete_file_imports(&self) -> Result<usize> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name FROM transcription_history
+             WHERE saved = 0 AND recording_source = ?1",
+        )?;
+        let entries = stmt
+            .query_map(params![FILE_IMPORT_SOURCE], |row| {
+                Ok((row.get::<_, i64>("id")?, row.get::<_, String>("file_name")?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        self.delete_entries_and_files(&entries)?;
+        if !entries.is_empty() {
+            self.emit_history_updated();
+        }
+        Ok(entries.len())
+    }
+
+    /// Moves file transcriptions that older versions kept in settings into
+    /// history. They stay in settings when the move fails, so it is retried
+    /// on the next launch.
+    fn migrate_file_transcription_history(&self, app_handle: &AppHandle) {
+        let saved = crate::settings::get_legacy_file_transcription_history(app_handle);
+        if saved.is_empty() {
+            return;
+        }
+
+        let result = self.get_connection().and_then(|conn| {
+            import_saved_file_transcriptions_with_conn(&conn, &saved, Utc::now().timestamp())
+        });
+        match result {
+            Ok(count) => {
+                crate::settings::clear_legacy_file_transcription_history(app_handle);
+                info!(
+                    "Moved {} file transcriptions from settings into history",
+                    count
+                );
+            }
+            Err(e) => error!("Failed to move file transcriptions into history: {}", e),
+        }
+    }
+
     pub fn cleanup_old_entries(&self) -> Result<()> {
         let Some(app_handle) = &self.app_handle else {
             return Ok(());
         };
-        let retention_period = crate::settings::get_recording_retention_period(app_handle);
+        let policy = RetentionPolicy {
+            dictations: crate::settings::get_recording_retention_period(app_handle),
+            history_limit: crate::settings::get_history_limit(app_handle),
+            meetings: crate::settings::get_meeting_retention_period(app_handle),
+            dictation_audio: crate::settings::get_dictation_audio_retention_period(app_handle),
+            meeting_audio: crate::settings::get_meeting_audio_retention_period(app_handle),
+        };
+        self.apply_retention(&policy, Utc::now().timestamp())
+    }
 
-        match retention_period {
-            crate::settings::RecordingRetentionPeriod::Never => {
-                // Don't delete anything
-                return Ok(());
-            }
+    fn apply_retention(&self, policy: &RetentionPolicy, now: i64) -> Result<()> {
+        let mut changed = match policy.dictations {
             crate::settings::RecordingRetentionPeriod::PreserveLimit => {
                 // Use the old count-based logic with history_limit
-                let limit = crate::settings::get_history_limit(app_handle);
-                return self.cleanup_by_count(limit);
-            }
-            _ => {
-                // Use time-based logic
-                return self.cleanup_by_time(retention_period);
+                self.cleanup_by_count(policy.history_limit)?
             }
+            period => self.cleanup_by_time(RetentionTarget::Dictations, period, now)?,
+        };
+        changed += self.cleanup_by_time(RetentionTarget::Meetings, policy.meetings, now)?;
+        changed +=
+            self.cleanup_by_time(RetentionTarget::DictationAudio, policy.dictation_audio, now)?;
+        changed +=
+            self.cleanup_by_time(RetentionTarget::MeetingAudio, policy.meeting_audio, now)?;
+
+        if changed > 0 {
+            self.emit_history_updated();
         }
+        Ok(())
     }
 
     fn delete_entries_and_files(&self, entries: &[(i64, String)]) -> Result<usize> {
@@ -320,6 +859,7 @@ impl HistoryManager {
                 "DELETE FROM transcription_history WHERE id = ?1",
                 params![id],
             )?;
+            self.remove_mirrored_entry(*id);
 
             // Delete WAV file
             let file_path = self.recordings_dir.join(file_name);
@@ -336,9 +876,36 @@ impl HistoryManager {
         Ok(deleted_count)
     }
 
+    /// Deletes the recordings of `entries` and marks them purged, keeping
+    /// their text. Returns how many entries were marked.
+    fn purge_entry_audio(&self, entries: &[(i64, String)]) -> Result<usize> {
+        if entries.is_empty() {
+            return Ok(0);
+        }
+
+        let conn = self.get_connection()?;
+        for (id, file_name) in entries {
+            conn.execute(
+                "UPDATE transcription_history SET audio_purged = 1 WHERE id = ?1",
+                params![id],
+            )?;
+
+            let file_path = self.recordings_dir.join(file_name);
+            if file_path.exists() {
+                if let Err(e) = fs::remove_file(&file_path) {
+                    error!("Failed to delete WAV file {}: {}", file_name, e);
+                } else {
+                    debug!("Purged old WAV file: {}", file_name);
+                }
+            }
+        }
+
+        Ok(entries.len())
+    }
+
     fn count_cleanup_candidates(conn: &Connection, limit: usize) -> Result<Vec<(i64, String)>> {
         // Count-based history retention applies only to normal dictations.
-        // Meeting sessions are intentionally unbounded unless manually deleted.
+        // Meeting sessions have their own time-based policy.
         let mut stmt = conn.prepare(
             "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND recording_source != 'full_system_audio' ORDER BY timestamp DESC"
         )?;
@@ -359,7 +926,7 @@ impl HistoryManager {
         })
     }
 
-    fn cleanup_by_count(&self, limit: usize) -> Result<()> {
+    fn cleanup_by_count(&self, limit: usize) -> Result<usize> {
         let conn = self.get_connection()?;
         let entries_to_delete = Self::count_cleanup_candidates(&conn, limit)?;
 
@@ -371,18 +938,20 @@ impl HistoryManager {
             }
         }
 
-        Ok(())
+        Ok(entries_to_delete.len())
     }
 
     fn time_cleanup_candidates(
         conn: &Connection,
+        target: RetentionTarget,
         cutoff_timestamp: i64,
     ) -> Result<Vec<(i64, String)>> {
-        // Time-based history retention applies only to normal dictations.
-        // Meeting sessions are intentionally unbounded unless manually deleted.
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND timestamp < ?1 AND recording_source != 'full_system_audio'",
-        )?;
+        // Saved entries are never removed by retention, and each source only
+        // answers to its own policy.
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND timestamp < ?1 AND {}",
+            target.condition()
+        ))?;
 
         let rows = stmt.query_map(params![cutoff_timestamp], |row| {
             Ok((row.get::<_, i64>("id")?, row.get::<_, String>("file_name")?))
@@ -396,54 +965,49 @@ impl HistoryManager {
         Ok(entries_to_delete)
     }
 
+    /// Applies a time-based `retention_period` to `target`. Returns how many
+    /// entries were deleted or had their recording purged.
     fn cleanup_by_time(
         &self,
+        target: RetentionTarget,
         retention_period: crate::settings::RecordingRetentionPeriod,
-    ) -> Result<()> {
-        let conn = self.get_connection()?;
-
-        // Calculate cutoff timestamp (current time minus retention period)
-        let now = Utc::now().timestamp();
-        let cutoff_timestamp = match retention_period {
-            crate::settings::RecordingRetentionPeriod::Days3 => now - (3 * 24 * 60 * 60), // 3 days in seconds
-            crate::settings::RecordingRetentionPeriod::Weeks2 => now - (2 * 7 * 24 * 60 * 60), // 2 weeks in seconds
-            crate::settings::RecordingRetentionPeriod::Months3 => now - (3 * 30 * 24 * 60 * 60), // 3 months in seconds (approximate)
-            _ => unreachable!("Should not reach here"),
+        now: i64,
+    ) -> Result<usize> {
+        let Some(cutoff_timestamp) = retention_cutoff(retention_period, now) else {
+            return Ok(0);
         };
 
-        let entries_to_delete = Self::time_cleanup_candidates(&conn, cutoff_timestamp)?;
-
-        let deleted_count = self.delete_entries_and_files(&entries_to_delete)?;
+        let conn = self.get_connection()?;
+        let candidates = Self::time_cleanup_candidates(&conn, target, cutoff_timestamp)?;
 
-        if deleted_count > 0 {
-            debug!(
-                "Cleaned up {} old history entries based on retention period",
-                deleted_count
-            );
+        if target.purges_audio_only() {
+            let purged_count = self.purge_entry_audio(&candidates)?;
+            if purged_count > 0 {
+                debug!(
+                    "Purged recordings of {} history entries based on retention period",
+                    purged_count
+                );
+            }
+        } else {
+            let deleted_count = self.delete_entries_and_files(&candidates)?;
+            if deleted_count > 0 {
+                debug!(
+                    "Cleaned up {} old history entries based on retention period",
+                    deleted_count
+                );
+            }
         }
 
-        Ok(())
+        Ok(candidates.len())
     }
 
     pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source FROM transcription_history ORDER BY timestamp DESC"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {HISTORY_ENTRY_COLUMNS} FROM transcription_history ORDER BY timestamp DESC"
+        ))?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok(HistoryEntry {
-                id: row.get("id")?,
-                file_name: row.get("file_name")?,
-                timestamp: row.get("timestamp")?,
-                saved: row.get("saved")?,
-                title: row.get("title")?,
-                transcription_text: row.get("transcription_text")?,
-                post_processed_text: row.get("post_processed_text")?,
-                post_process_prompt: row.get("post_process_prompt")?,
-                recording_source: row.get("recording_source")?,
-            })
-        })?;
+        let rows = stmt.query_map([], history_entry_from_row)?;
 
         let mut entries = Vec::new();
         for row in rows {
@@ -453,34 +1017,49 @@ impl HistoryManager {
         Ok(entries)
     }
 
+    /// One page of history, newest first. Pass the previous page's
+    /// `next_cursor` to continue after it.
+    pub async fn get_history_page(
+        &self,
+        cursor: Option<HistoryCursor>,
+        limit: Option<u32>,
+        filter: &HistoryPageFilter,
+    ) -> Result<HistoryPage> {
+        let conn = self.get_connection()?;
+        get_history_page_with_conn(&conn, cursor, limit, filter)
+    }
+
+    /// Full-text search ranked by relevance, newest first on ties. An empty
+    /// query only applies the filters. Encrypted history can't use the
+    /// full-text index, so it is scanned newest first instead.
+    pub async fn search_entries(
+        &self,
+        query: &str,
+        filters: &HistorySearchFilters,
+        limit: Option<u32>,
+    ) -> Result<Vec<HistorySearchResult>> {
+        let conn = self.get_connection()?;
+        if self.encryption.is_enabled() {
+            scan_entries_with_conn(&conn, query, filters, limit)
+        } else {
+            search_entries_with_conn(&conn, query, filters, limit)
+        }
+    }
+
     pub fn get_latest_entry(&self) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         Self::get_latest_entry_with_conn(&conn)
     }
 
     fn get_latest_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {HISTORY_ENTRY_COLUMNS}
              FROM transcription_history
              ORDER BY timestamp DESC
-             LIMIT 1",
-        )?;
+             LIMIT 1"
+        ))?;
 
-        let entry = stmt
-            .query_row([], |row| {
-                Ok(HistoryEntry {
-                    id: row.get("id")?,
-                    file_name: row.get("file_name")?,
-                    timestamp: row.get("timestamp")?,
-                    saved: row.get("saved")?,
-                    title: row.get("title")?,
-                    transcription_text: row.get("transcription_text")?,
-                    post_processed_text: row.get("post_processed_text")?,
-                    post_process_prompt: row.get("post_process_prompt")?,
-                    recording_source: row.get("recording_source")?,
-                })
-            })
-            .optional()?;
+        let entry = stmt.query_row([], history_entry_from_row).optional()?;
 
         Ok(entry)
     }
@@ -514,189 +1093,2944 @@ impl HistoryManager {
         Ok(())
     }
 
-    pub fn get_audio_file_path(&self, file_name: &str) -> PathBuf {
-        self.recordings_dir.join(file_name)
+    /// Writes the given entries, or all history when `ids` is `None`, to a
+    /// zip bundle at `destination`.
+    pub async fn export_bundle(
+        &self,
+        ids: Option<Vec<i64>>,
+        destination: &Path,
+    ) -> Result<HistoryExportSummary> {
+        let db_path = self.db_path.clone();
+        let encryption = self.encryption.clone();
+        let recordings_dir = self.recordings_dir.clone();
+        let destination = destination.to_path_buf();
+        // Bundles hold every recording, so writing one can take minutes.
+        tokio::task::spawn_blocking(move || {
+            let conn = open_connection(&db_path, encryption.clone())?;
+            export_bundle_with_conn(
+                &conn,
+                ids.as_deref(),
+                &destination,
+                &recordings_dir,
+                &encryption,
+            )
+        })
+        .await?
     }
 
-    pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
-        let conn = self.get_connection()?;
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source
-             FROM transcription_history WHERE id = ?1",
-        )?;
+    /// Merges a bundle into history. Entries that are already present are
+    /// skipped and the rest get fresh ids, so importing twice is harmless.
+    pub async fn import_bundle(&self, source: &Path) -> Result<HistoryImportSummary> {
+        let db_path = self.db_path.clone();
+        let encryption = self.encryption.clone();
+        let recordings_dir = self.recordings_dir.clone();
+        let bundle_path = source.to_path_buf();
+        let summary = tokio::task::spawn_blocking(move || {
+            let mut conn = open_connection(&db_path, encryption.clone())?;
+            let summary =
+                import_bundle_with_conn(&mut conn, &bundle_path, &recordings_dir, &encryption)?;
+            if summary.imported > 0 {
+                log_backfill_error(backfill_stats_with_conn(
+                    &conn,
+                    &recordings_dir,
+                    &encryption,
+                ));
+            }
+            Ok::<_, anyhow::Error>(summary)
+        })
+        .await??;
 
-        let entry = stmt
-            .query_row([id], |row| {
-                Ok(HistoryEntry {
-                    id: row.get("id")?,
-                    file_name: row.get("file_name")?,
-                    timestamp: row.get("timestamp")?,
-                    saved: row.get("saved")?,
-                    title: row.get("title")?,
-                    transcription_text: row.get("transcription_text")?,
-                    post_processed_text: row.get("post_processed_text")?,
-                    post_process_prompt: row.get("post_process_prompt")?,
-                    recording_source: row.get("recording_source")?,
-                })
-            })
-            .optional()?;
+        info!(
+            "Imported {} history entries from {:?} ({} already present)",
+            summary.imported, source, summary.skipped
+        );
+        if summary.imported > 0 {
+            self.mirror_all_entries();
+            self.emit_history_updated();
+        }
 
-        Ok(entry)
+        Ok(summary)
     }
 
-    pub async fn delete_entry(&self, id: i64) -> Result<()> {
-        self.delete_entry_and_audio(id, false)
+    pub fn get_audio_file_path(&self, file_name: &str) -> PathBuf {
+        self.recordings_dir.join(file_name)
     }
 
-    /// Roll back a dictation save that lost a race with operation cancellation.
-    /// Meeting entries are deliberately rejected so a dictation cancellation can
-    /// never remove durable meeting history.
-    pub fn rollback_dictation_entry(&self, id: i64) -> Result<()> {
-        self.delete_entry_and_audio(id, true)
-    }
+    /// Path the webview can play a recording from. An encrypted recording is
+    /// decrypted to a temporary copy that the caller loads and then removes
+    /// with [`Self::release_playable_audio`]; copies left by a crash are
+    /// removed the next time the app starts.
+    pub fn get_playable_audio_path(&self, file_name: &str) -> Result<PlayableAudio> {
+        let path = self.get_audio_file_path(file_name);
+        if !path.is_file() || !HistoryEncryption::is_encrypted_file(&path)? {
+            return Ok(PlayableAudio {
+                path: path.to_string_lossy().into_owned(),
+                temporary: false,
+            });
+        }
 
-    fn delete_entry_and_audio(&self, id: i64, require_dictation: bool) -> Result<()> {
-        let conn = self.get_connection()?;
-        delete_entry_and_audio_with_connection(&conn, &self.recordings_dir, id, require_dictation)?;
+        let playback_dir = self.recordings_dir.join(PLAYBACK_DIR);
+        fs::create_dir_all(&playback_dir)?;
+        // Unique names keep two players from sharing, and releasing, one copy.
+        let playback_path = playback_dir.join(format!("{}-{}", Uuid::new_v4(), file_name));
+        if let Err(e) = self
+            .encryption
+            .read_file_into(&path, &mut BufWriter::new(File::create(&playback_path)?))
+        {
+            let _ = fs::remove_file(&playback_path);
+            return Err(e);
+        }
+        Ok(PlayableAudio {
+            path: playback_path.to_string_lossy().into_owned(),
+            temporary: true,
+        })
+    }
 
-        debug!("Deleted history entry with id: {}", id);
+    /// Removes a decrypted copy made by [`Self::get_playable_audio_path`].
+    /// Paths outside the playback folder are refused.
+    pub fn release_playable_audio(&self, path: &Path) -> Result<()> {
+        let playback_dir = self.recordings_dir.join(PLAYBACK_DIR);
+        if path.parent() != Some(playback_dir.as_path()) {
+            bail!("Not a playback copy: {}", path.display());
+        }
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
 
-        // Emit history updated event
-        if let Some(app_handle) = &self.app_handle {
-            if let Err(e) = app_handle.emit("history-updated", ()) {
-                error!("Failed to emit history-updated event: {}", e);
-            }
+    /// Turns encryption at rest on or off and converts existing history to
+    /// match. Recordings are converted one at a time and text in a single
+    /// transaction, so a failed run leaves history readable and can simply
+    /// be repeated. Progress is reported as `history-encryption-progress`.
+    pub async fn set_encryption(&self, enabled: bool) -> Result<()> {
+        let previous = self.encryption.is_enabled();
+        self.encryption.set_enabled(enabled);
+        let db_path = self.db_path.clone();
+        let recordings_dir = self.recordings_dir.clone();
+        let encryption = self.encryption.clone();
+        let app_handle = self.app_handle.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            convert_history_encryption(&db_path, &recordings_dir, encryption, |progress| {
+                if let Some(app_handle) = &app_handle {
+                    let _ = app_handle.emit("history-encryption-progress", progress);
+                }
+            })
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|result| result);
+        if let Err(e) = result {
+            self.encryption.set_enabled(previous);
+            return Err(e);
         }
 
+        info!(
+            "History encryption at rest {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+        self.emit_history_updated();
         Ok(())
     }
 
-    fn format_timestamp_title(&self, timestamp: i64) -> String {
-        if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
-            // Convert UTC to local timezone
-            let local_datetime = utc_datetime.with_timezone(&Local);
-            local_datetime.format("%B %e, %Y - %l:%M%p").to_string()
-        } else {
-            format!("Recording {}", timestamp)
-        }
+    pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        get_entry_with_conn(&conn, id)
     }
-}
 
-fn delete_entry_and_audio_with_connection(
+    /// Edits an entry's text, keeping the previous version as a revision.
+    pub async fn update_entry(&self, id: i64, update: HistoryEntryUpdate) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = update_entry_with_conn(&conn, id, &update)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    /// Earlier versions of an entry, newest first.
+    pub async fn get_revisions(&self, id: i64) -> Result<Vec<HistoryRevision>> {
+        let conn = self.get_connection()?;
+        get_revisions_with_conn(&conn, id)
+    }
+
+    /// Restores an earlier version. The text being replaced becomes a new
+    /// revision, so a revert can itself be undone.
+    pub async fn revert_entry(&self, id: i64, revision_id: i64) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = revert_entry_with_conn(&conn, id, revision_id)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    /// Replaces an entry's post-processed text with the output of another
+    /// post-processing run. The previous output is kept as a revision.
+    pub async fn reprocess_entry(
+        &self,
+        id: i64,
+        post_processed_text: &str,
+        post_process_prompt: Option<&str>,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = reprocess_entry_with_conn(&conn, id, post_processed_text, post_process_prompt)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    /// Stores another transcript of an entry's recording.
+    pub async fn add_alternate(
+        &self,
+        history_id: i64,
+        alternate: NewHistoryAlternate,
+    ) -> Result<HistoryAlternate> {
+        let conn = self.get_connection()?;
+        let alternate = add_alternate_with_conn(&conn, history_id, &alternate)?;
+        self.emit_history_updated();
+        Ok(alternate)
+    }
+
+    /// Alternate transcripts of an entry, newest first.
+    pub async fn get_alternates(&self, history_id: i64) -> Result<Vec<HistoryAlternate>> {
+        let conn = self.get_connection()?;
+        get_alternates_with_conn(&conn, history_id)
+    }
+
+    /// Makes an alternate the entry's text and records the model and route
+    /// that produced it. The replaced text becomes a revision, and the
+    /// alternate is removed since the entry now holds it.
+    pub async fn apply_alternate(
+        &self,
+        history_id: i64,
+        alternate_id: i64,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = apply_alternate_with_conn(&conn, history_id, alternate_id)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    pub async fn delete_alternate(&self, history_id: i64, alternate_id: i64) -> Result<()> {
+        let conn = self.get_connection()?;
+        let deleted = conn.execute(
+            "DELETE FROM transcription_alternates WHERE id = ?1 AND history_id = ?2",
+            params![alternate_id, history_id],
+        )?;
+        if deleted == 0 {
+            bail!(
+                "Alternate {} not found for history entry {}",
+                alternate_id,
+                history_id
+            );
+        }
+        self.emit_history_updated();
+        Ok(())
+    }
+
+    /// The WAV bytes of a recording, decrypted if history is encrypted.
+    pub fn read_recording(&self, file_name: &str) -> Result<Vec<u8>> {
+        if !is_safe_recording_file_name(file_name) {
+            bail!("Invalid recording file name: {}", file_name);
+        }
+        let mut bytes = Vec::new();
+        self.encryption
+            .read_file_into(&self.get_audio_file_path(file_name), &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Replaces an entry's tags. Blank and repeated tags are dropped.
+    pub async fn set_tags(&self, id: i64, tags: &[String]) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = set_tags_with_conn(&conn, id, tags)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    /// Sets or, when blank, clears an entry's note.
+    pub async fn set_note(&self, id: i64, note: Option<&str>) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = set_note_with_conn(&conn, id, note)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    /// Moves an entry into a folder, or out of any folder with `None`.
+    pub async fn set_folder(&self, id: i64, folder_id: Option<i64>) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry = set_folder_with_conn(&conn, id, folder_id)?;
+        self.mirror_entry(&conn, &entry);
+        self.emit_history_updated();
+        Ok(entry)
+    }
+
+    pub async fn get_folders(&self) -> Result<Vec<HistoryFolder>> {
+        let conn = self.get_connection()?;
+        get_folders_with_conn(&conn)
+    }
+
+    pub async fn create_folder(&self, name: &str) -> Result<HistoryFolder> {
+        let conn = self.get_connection()?;
+        let folder = create_folder_with_conn(&conn, name)?;
+        self.emit_history_updated();
+        Ok(folder)
+    }
+
+    pub async fn rename_folder(&self, id: i64, name: &str) -> Result<HistoryFolder> {
+        let conn = self.get_connection()?;
+        let folder = rename_folder_with_conn(&conn, id, name)?;
+        self.mirror_all_entries();
+        self.emit_history_updated();
+        Ok(folder)
+    }
+
+    /// Deletes a folder. Its entries stay in history without a folder.
+    pub async fn delete_folder(&self, id: i64) -> Result<()> {
+        let conn = self.get_connection()?;
+        if conn.execute("DELETE FROM history_folders WHERE id = ?1", params![id])? == 0 {
+            bail!("History folder {} not found", id);
+        }
+        self.mirror_all_entries();
+        self.emit_history_updated();
+        Ok(())
+    }
+
+    /// Tags in use, most used first.
+    pub async fn get_tags(&self) -> Result<Vec<HistoryTag>> {
+        let conn = self.get_connection()?;
+        get_tags_with_conn(&conn)
+    }
+
+    fn emit_history_updated(&self) {
+        if let Some(app_handle) = &self.app_handle {
+            if let Err(e) = app_handle.emit("history-updated", ()) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+    }
+
+    /// Mirrors history to Markdown notes in `dir`, or stops with `None`.
+    /// Notes already in the folder are brought up to date, including ones
+    /// left from an earlier mirror whose entries have since been deleted.
+    pub async fn set_vault_dir(&self, dir: Option<PathBuf>) -> Result<()> {
+        if let Some(dir) = &dir {
+            if !dir.is_dir() {
+                bail!("Vault folder {:?} does not exist", dir);
+            }
+        }
+
+        let previous = self.vault.dir();
+        self.vault.set_dir(dir);
+        // Mirroring all of history into a new folder writes a note per entry.
+        let db_path = self.db_path.clone();
+        let encryption = self.encryption.clone();
+        let vault = self.vault.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            sync_vault_with_conn(&open_connection(&db_path, encryption)?, &vault)
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|result| result);
+        if let Err(e) = result {
+            self.vault.set_dir(previous);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn sync_vault(&self) -> Result<()> {
+        if self.vault.dir().is_none() {
+            return Ok(());
+        }
+        sync_vault_with_conn(&self.get_connection()?, &self.vault)
+    }
+
+    // The vault is a convenience copy, so failing to update it is logged
+    // rather than failing the history change that triggered it.
+    fn mirror_entry(&self, conn: &Connection, entry: &HistoryEntry) {
+        if self.vault.dir().is_none() {
+            return;
+        }
+        let result = entry
+            .folder_id
+            .map(|id| get_folder_with_conn(conn, id).map(|folder| folder.name))
+            .transpose()
+            .and_then(|folder| self.vault.write_note(entry, folder.as_deref()));
+        if let Err(e) = result {
+            error!(
+                "Failed to mirror history entry {} to the vault: {}",
+                entry.id, e
+            );
+        }
+    }
+
+    fn mirror_entry_with_id(&self, conn: &Connection, id: i64) {
+        if self.vault.dir().is_none() {
+            return;
+        }
+        match get_entry_with_conn(conn, id) {
+            Ok(Some(entry)) => self.mirror_entry(conn, &entry),
+            Ok(None) => self.remove_mirrored_entry(id),
+            Err(e) => error!("Failed to mirror history entry {} to the vault: {}", id, e),
+        }
+    }
+
+    fn remove_mirrored_entry(&self, id: i64) {
+        if let Err(e) = self.vault.remove_note(id) {
+            error!(
+                "Failed to remove history entry {} from the vault: {}",
+                id, e
+            );
+        }
+    }
+
+    fn mirror_all_entries(&self) {
+        if let Err(e) = self.sync_vault() {
+            error!("Failed to mirror history to the vault: {}", e);
+        }
+    }
+
+    pub async fn delete_entry(&self, id: i64) -> Result<()> {
+        self.delete_entry_and_audio(id, false)
+    }
+
+    /// Roll back a dictation save that lost a race with operation cancellation.
+    /// Meeting entries are deliberately rejected so a dictation cancellation can
+    /// never remove durable meeting history.
+    pub fn rollback_dictation_entry(&self, id: i64) -> Result<()> {
+        self.delete_entry_and_audio(id, true)
+    }
+
+    fn delete_entry_and_audio(&self, id: i64, require_dictation: bool) -> Result<()> {
+        let conn = self.get_connection()?;
+        delete_entry_and_audio_with_connection(&conn, &self.recordings_dir, id, require_dictation)?;
+        self.remove_mirrored_entry(id);
+
+        debug!("Deleted history entry with id: {}", id);
+
+        // Emit history updated event
+        if let Some(app_handle) = &self.app_handle {
+            if let Err(e) = app_handle.emit("history-updated", ()) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Store engine timings for an entry, replacing any previously saved ones.
+    pub fn save_timestamps(
+        &self,
+        history_id: i64,
+        timestamps: &TranscriptTimestamps,
+    ) -> Result<()> {
+        let conn = self.get_connection()?;
+        save_timestamps_with_conn(&conn, history_id, timestamps)?;
+        debug!(
+            "Saved {} segment and {} word timings for entry {}",
+            timestamps.segments.len(),
+            timestamps.words.len(),
+            history_id
+        );
+        Ok(())
+    }
+
+    pub async fn get_timestamps(&self, history_id: i64) -> Result<TranscriptTimestamps> {
+        let conn = self.get_connection()?;
+        get_timestamps_with_conn(&conn, history_id)
+    }
+
+    /// Record which transcription route and model produced an entry's text.
+    pub fn save_transcription_route(
+        &self,
+        history_id: i64,
+        route: &str,
+        fallback_from: Option<&str>,
+        model: Option<&str>,
+    ) -> Result<()> {
+        let conn = self.get_connection()?;
+        save_transcription_route_with_conn(&conn, history_id, route, fallback_from, model)?;
+        self.mirror_entry_with_id(&conn, history_id);
+        Ok(())
+    }
+
+    /// Record the app an entry was dictated into, for usage stats.
+    pub fn save_app_name(&self, history_id: i64, app_name: &str) -> Result<()> {
+        let conn = self.get_connection()?;
+        set_app_name_with_conn(&conn, history_id, app_name)
+    }
+
+    /// Usage totals for entries between `from` and `to`, in Unix seconds.
+    pub async fn get_stats(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        typing_wpm: u32,
+    ) -> Result<HistoryStats> {
+        let conn = self.get_connection()?;
+        get_stats_with_conn(&conn, from, to, typing_wpm)
+    }
+
+    /// Records stats for entries saved before stats existed. Runs on its own
+    /// thread, since it reads every such recording and startup should not
+    /// wait on a large history.
+    fn backfill_stats_in_background(&self) {
+        let db_path = self.db_path.clone();
+        let recordings_dir = self.recordings_dir.clone();
+        let encryption = self.encryption.clone();
+        std::thread::spawn(move || {
+            log_backfill_error(
+                open_connection(&db_path, encryption.clone())
+                    .and_then(|conn| backfill_stats_with_conn(&conn, &recordings_dir, &encryption)),
+            );
+        });
+    }
+
+    fn format_timestamp_title(&self, timestamp: i64) -> String {
+        if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
+            // Convert UTC to local timezone
+            let local_datetime = utc_datetime.with_timezone(&Local);
+            local_datetime.format("%B %e, %Y - %l:%M%p").to_string()
+        } else {
+            format!("Recording {}", timestamp)
+        }
+    }
+}
+
+/// Retention settings applied by [`HistoryManager::cleanup_old_entries`].
+struct RetentionPolicy {
+    dictations: crate::settings::RecordingRetentionPeriod,
+    history_limit: usize,
+    meetings: crate::settings::RecordingRetentionPeriod,
+    dictation_audio: crate::settings::RecordingRetentionPeriod,
+    meeting_audio: crate::settings::RecordingRetentionPeriod,
+}
+
+/// What one retention period removes: whole entries of a source, or only the
+/// recordings behind them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RetentionTarget {
+    Dictations,
+    Meetings,
+    DictationAudio,
+    MeetingAudio,
+}
+
+impl RetentionTarget {
+    fn condition(self) -> &'static str {
+        match self {
+            Self::Dictations => "recording_source != 'full_system_audio'",
+            Self::Meetings => "recording_source = 'full_system_audio'",
+            Self::DictationAudio => "recording_source != 'full_system_audio' AND audio_purged = 0",
+            Self::MeetingAudio => "recording_source = 'full_system_audio' AND audio_purged = 0",
+        }
+    }
+
+    fn purges_audio_only(self) -> bool {
+        matches!(self, Self::DictationAudio | Self::MeetingAudio)
+    }
+}
+
+/// Oldest timestamp a time-based period keeps, or `None` when the period
+/// does not expire entries by age.
+fn retention_cutoff(period: crate::settings::RecordingRetentionPeriod, now: i64) -> Option<i64> {
+    use crate::settings::RecordingRetentionPeriod;
+
+    const DAY: i64 = 24 * 60 * 60;
+    let max_age = match period {
+        RecordingRetentionPeriod::Never | RecordingRetentionPeriod::PreserveLimit => return None,
+        RecordingRetentionPeriod::Days3 => 3 * DAY,
+        RecordingRetentionPeriod::Weeks2 => 2 * 7 * DAY,
+        // Approximate months and years are close enough for retention.
+        RecordingRetentionPeriod::Months3 => 3 * 30 * DAY,
+        RecordingRetentionPeriod::Years1 => 365 * DAY,
+    };
+    Some(now - max_age)
+}
+
+fn history_entry_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<HistoryEntry> {
+    Ok(HistoryEntry {
+        id: row.get("id")?,
+        file_name: row.get("file_name")?,
+        timestamp: row.get("timestamp")?,
+        saved: row.get("saved")?,
+        audio_purged: row.get("audio_purged")?,
+        title: row.get("title")?,
+        transcription_text: row.get("transcription_text")?,
+        post_processed_text: row.get("post_processed_text")?,
+        post_process_prompt: row.get("post_process_prompt")?,
+        recording_source: row.get("recording_source")?,
+        source_path: row.get("source_path")?,
+        transcription_route: row.get("transcription_route")?,
+        fallback_from: row.get("fallback_from")?,
+        transcription_model: row.get("transcription_model")?,
+        note: row.get("note")?,
+        folder_id: row.get("folder_id")?,
+        tags: split_tags(row.get("tags")?),
+    })
+}
+
+fn split_tags(tags: Option<String>) -> Vec<String> {
+    let mut tags: Vec<String> = tags
+        .map(|tags| tags.split(TAG_SEPARATOR).map(str::to_string).collect())
+        .unwrap_or_default();
+    tags.sort_by_key(|tag| tag.to_lowercase());
+    tags
+}
+
+// The row-value comparison lets SQLite seek into
+// transcription_history_timestamp_idx instead of skipping earlier pages.
+fn history_page_sql(condition: &str) -> String {
+    format!(
+        "SELECT {HISTORY_ENTRY_COLUMNS}
+         FROM transcription_history
+         WHERE {condition} AND {ORGANIZATION_FILTER_SQL} AND (timestamp, id) < (?3, ?4)
+         ORDER BY timestamp DESC, id DESC
+         LIMIT ?5"
+    )
+}
+
+fn get_history_page_with_conn(
+    conn: &Connection,
+    cursor: Option<HistoryCursor>,
+    limit: Option<u32>,
+    filter: &HistoryPageFilter,
+) -> Result<HistoryPage> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let condition = filter.source.sql_condition();
+    let is_first_page = cursor.is_none();
+    let cursor = cursor.unwrap_or(HistoryCursor {
+        timestamp: i64::MAX,
+        id: i64::MAX,
+    });
+
+    let mut stmt = conn.prepare(&history_page_sql(condition))?;
+    // One extra row tells us whether another page follows.
+    let mut entries = stmt
+        .query_map(
+            params![
+                filter.folder_id,
+                filter.tag,
+                cursor.timestamp,
+                cursor.id,
+                limit + 1
+            ],
+            history_entry_from_row,
+        )?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let next_cursor = if entries.len() > limit as usize {
+        entries.truncate(limit as usize);
+        entries.last().map(|entry| HistoryCursor {
+            timestamp: entry.timestamp,
+            id: entry.id,
+        })
+    } else {
+        None
+    };
+
+    // Counting scans every matching row, so it is only done once per listing.
+    let total_count = if is_first_page {
+        Some(conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM transcription_history
+                 WHERE {condition} AND {ORGANIZATION_FILTER_SQL}"
+            ),
+            params![filter.folder_id, filter.tag],
+            |row| row.get(0),
+        )?)
+    } else {
+        None
+    };
+
+    Ok(HistoryPage {
+        entries,
+        next_cursor,
+        total_count,
+    })
+}
+
+// ?5 is left for the caller's LIMIT.
+const SEARCH_FILTER_SQL: &str = "(?1 IS NULL OR recording_source = ?1)
+     AND (?2 IS NULL OR timestamp >= ?2)
+     AND (?3 IS NULL OR timestamp <= ?3)
+     AND (?4 IS NULL OR saved = ?4)
+     AND (?6 IS NULL OR folder_id = ?6)
+     AND (?7 IS NULL OR EXISTS (
+         SELECT 1 FROM transcription_tags
+         WHERE transcription_tags.history_id = transcription_history.id AND tag = ?7
+     ))";
+/// Words shown around the first match in scanned search snippets, like the
+/// token count passed to FTS5 `snippet()`.
+const SCAN_SNIPPET_WORDS: usize = 16;
+
+/// Turns free text into an FTS5 query that matches every word, treating the
+/// last word as a prefix so results update while typing. Quoting each word
+/// keeps FTS5 operators and punctuation in user input from being parsed.
+fn fts_match_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", terms.join(" ")))
+}
+
+fn search_entries_with_conn(
+    conn: &Connection,
+    query: &str,
+    filters: &HistorySearchFilters,
+    limit: Option<u32>,
+) -> Result<Vec<HistorySearchResult>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let filter_clause = SEARCH_FILTER_SQL;
+
+    let Some(match_query) = fts_match_query(query) else {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {HISTORY_ENTRY_COLUMNS}
+             FROM transcription_history
+             WHERE {filter_clause}
+             ORDER BY timestamp DESC
+             LIMIT ?5"
+        ))?;
+        let rows = stmt.query_map(
+            params![
+                filters.recording_source,
+                filters.from_timestamp,
+                filters.to_timestamp,
+                filters.saved,
+                limit,
+                filters.folder_id,
+                filters.tag,
+            ],
+            |row| {
+                Ok(HistorySearchResult {
+                    entry: history_entry_from_row(row)?,
+                    snippet: String::new(),
+                })
+            },
+        )?;
+        return Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?);
+    };
+
+    // Titles are generated from the timestamp, so they weigh less than text.
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {HISTORY_ENTRY_COLUMNS}, matches.snippet
+         FROM (
+             SELECT rowid AS match_id,
+                    snippet(transcription_history_fts, -1, ?8, ?9, '…', 16) AS snippet,
+                    bm25(transcription_history_fts, 0.5, 1.0, 1.0) AS score
+             FROM transcription_history_fts
+             WHERE transcription_history_fts MATCH ?10
+         ) AS matches
+         JOIN transcription_history ON transcription_history.id = matches.match_id
+         WHERE {filter_clause}
+         ORDER BY matches.score, timestamp DESC
+         LIMIT ?5"
+    ))?;
+    let rows = stmt.query_map(
+        params![
+            filters.recording_source,
+            filters.from_timestamp,
+            filters.to_timestamp,
+            filters.saved,
+            limit,
+            filters.folder_id,
+            filters.tag,
+            SEARCH_MATCH_START,
+            SEARCH_MATCH_END,
+            match_query,
+        ],
+        |row| {
+            Ok(HistorySearchResult {
+                entry: history_entry_from_row(row)?,
+                snippet: row.get("snippet")?,
+            })
+        },
+    )?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Search for encrypted history, whose full-text index only holds
+/// ciphertext. Entries matching the filters are decrypted newest first and
+/// kept when their text contains every query word, case-insensitively.
+fn scan_entries_with_conn(
+    conn: &Connection,
+    query: &str,
+    filters: &HistorySearchFilters,
+    limit: Option<u32>,
+) -> Result<Vec<HistorySearchResult>> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return search_entries_with_conn(conn, query, filters, limit);
+    }
+    let limit = limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT) as usize;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {HISTORY_ENTRY_COLUMNS}
+         FROM transcription_history
+         WHERE {SEARCH_FILTER_SQL}
+         ORDER BY timestamp DESC, id DESC"
+    ))?;
+    let mut rows = stmt.query(params![
+        filters.recording_source,
+        filters.from_timestamp,
+        filters.to_timestamp,
+        filters.saved,
+        // No LIMIT: entries are matched after decryption.
+        Option::<u32>::None,
+        filters.folder_id,
+        filters.tag,
+    ])?;
+
+    let mut results = Vec::new();
+    while let Some(row) = rows.next()? {
+        let entry = history_entry_from_row(row)?;
+        let fields = [
+            entry.post_processed_text.as_deref().unwrap_or_default(),
+            &entry.transcription_text,
+            &entry.title,
+        ];
+        let searchable = fields.join("\n").to_lowercase();
+        if !terms.iter().all(|term| searchable.contains(term.as_str())) {
+            continue;
+        }
+
+        let snippet = fields
+            .iter()
+            .find_map(|text| scan_snippet(text, &terms))
+            .unwrap_or_default();
+        results.push(HistorySearchResult { entry, snippet });
+        if results.len() == limit {
+            break;
+        }
+    }
+    Ok(results)
+}
+
+/// Excerpt around the first word containing a query term, marked up like
+/// FTS5 `snippet()` output.
+fn scan_snippet(text: &str, terms: &[String]) -> Option<String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let is_match = |word: &str| {
+        let word = word.to_lowercase();
+        terms.iter().any(|term| word.contains(term.as_str()))
+    };
+    let first = words.iter().position(|word| is_match(word))?;
+    let start = first.saturating_sub(SCAN_SNIPPET_WORDS / 4);
+    let end = (start + SCAN_SNIPPET_WORDS).min(words.len());
+
+    let mut snippet = if start > 0 {
+        "…".to_string()
+    } else {
+        String::new()
+    };
+    for (index, word) in words[start..end].iter().enumerate() {
+        if index > 0 {
+            snippet.push(' ');
+        }
+        if is_match(word) {
+            snippet.push_str(SEARCH_MATCH_START);
+            snippet.push_str(word);
+            snippet.push_str(SEARCH_MATCH_END);
+        } else {
+            snippet.push_str(word);
+        }
+    }
+    if end < words.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// Re-stores every text column in the current encryption mode and rebuilds
+/// the full-text index from what the table now holds.
+fn convert_text_encryption_with_conn(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "UPDATE transcription_history
+         SET title = history_encode(title),
+             transcription_text = history_encode(transcription_text),
+             post_processed_text = history_encode(post_processed_text),
+             post_process_prompt = history_encode(post_process_prompt),
+             source_path = history_encode(source_path),
+             note = history_encode(note);
+         UPDATE transcription_revisions
+         SET title = history_encode(title),
+             transcription_text = history_encode(transcription_text),
+             post_processed_text = history_encode(post_processed_text),
+             post_process_prompt = history_encode(post_process_prompt);
+         UPDATE transcription_segments SET text = history_encode(text);
+         UPDATE transcription_alternates
+         SET transcription_text = history_encode(transcription_text),
+             post_processed_text = history_encode(post_processed_text);
+         INSERT INTO transcription_history_fts (transcription_history_fts) VALUES ('rebuild');",
+    )?;
+    Ok(())
+}
+
+fn get_bundle_entries_with_conn(
     conn: &Connection,
+    ids: Option<&[i64]>,
+) -> Result<Vec<(HistoryEntry, TranscriptTimestamps)>> {
+    let entries = match ids {
+        Some(ids) => {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {HISTORY_ENTRY_COLUMNS} FROM transcription_history WHERE id = ?1"
+            ))?;
+            ids.iter()
+                .map(|id| {
+                    stmt.query_row(params![id], history_entry_from_row)
+                        .optional()?
+                        .ok_or_else(|| anyhow::anyhow!("History entry {} not found", id))
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        None => {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {HISTORY_ENTRY_COLUMNS} FROM transcription_history ORDER BY timestamp DESC, id DESC"
+            ))?;
+            let rows = stmt.query_map([], history_entry_from_row)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        }
+    };
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let timestamps = get_timestamps_with_conn(conn, entry.id)?;
+            Ok((entry, timestamps))
+        })
+        .collect()
+}
+
+/// Converts every recording and text column to the current encryption mode.
+/// Runs on a blocking thread: recordings can add up to gigabytes and the
+/// final `VACUUM` rewrites the whole database.
+fn convert_history_encryption(
+    db_path: &Path,
     recordings_dir: &Path,
-    id: i64,
-    require_dictation: bool,
+    encryption: Arc<HistoryEncryption>,
+    on_progress: impl Fn(HistoryEncryptionProgress),
 ) -> Result<()> {
-    let entry: Option<(String, String)> = conn
-        .query_row(
-            "SELECT file_name, recording_source FROM transcription_history WHERE id = ?1",
-            params![id],
-            |row| Ok((row.get(0)?, row.get(1)?)),
-        )
-        .optional()?;
+    let mut conn = open_connection(db_path, encryption.clone())?;
+    let file_names: Vec<String> = conn
+        .prepare("SELECT file_name FROM transcription_history")?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    let total = file_names.len() as u32;
+    let mut converted_files = 0;
+    for (index, file_name) in file_names.iter().enumerate() {
+        let path = recordings_dir.join(file_name);
+        if is_safe_recording_file_name(file_name)
+            && path.is_file()
+            && encryption.convert_file(&path)?
+        {
+            converted_files += 1;
+        }
+        on_progress(HistoryEncryptionProgress {
+            processed: index as u32 + 1,
+            total,
+        });
+    }
+
+    let tx = conn.transaction()?;
+    convert_text_encryption_with_conn(&tx)?;
+    tx.commit()?;
+    // Drop pages that still hold the text as it was before.
+    conn.execute_batch("VACUUM")?;
+
+    debug!("Converted {} recordings", converted_files);
+    Ok(())
+}
+
+/// Writes a note for every entry and removes notes for deleted ones.
+fn sync_vault_with_conn(conn: &Connection, vault: &HistoryVault) -> Result<()> {
+    if vault.dir().is_none() {
+        return Ok(());
+    }
+
+    let folder_names: HashMap<i64, String> = get_folders_with_conn(conn)?
+        .into_iter()
+        .map(|folder| (folder.id, folder.name))
+        .collect();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {HISTORY_ENTRY_COLUMNS} FROM transcription_history"
+    ))?;
+    let mut ids = HashSet::new();
+    for entry in stmt.query_map([], history_entry_from_row)? {
+        let entry = entry?;
+        let folder = entry.folder_id.and_then(|id| folder_names.get(&id));
+        vault.write_note(&entry, folder.map(String::as_str))?;
+        ids.insert(entry.id);
+    }
+    let removed = vault.remove_notes_except(&ids)?;
+
+    debug!(
+        "Mirrored {} history entries to the vault, removed {} stale notes",
+        ids.len(),
+        removed
+    );
+    Ok(())
+}
+
+fn log_backfill_error(result: Result<usize>) {
+    if let Err(e) = result {
+        error!("Failed to backfill usage stats: {}", e);
+    }
+}
+
+fn open_connection(db_path: &Path, encryption: Arc<HistoryEncryption>) -> Result<Connection> {
+    let conn = Connection::open(db_path)?;
+    conn.busy_timeout(HISTORY_BUSY_TIMEOUT)?;
+    register_sql_functions(&conn, encryption)?;
+    Ok(conn)
+}
+
+fn export_bundle_with_conn(
+    conn: &Connection,
+    ids: Option<&[i64]>,
+    destination: &Path,
+    recordings_dir: &Path,
+    encryption: &HistoryEncryption,
+) -> Result<HistoryExportSummary> {
+    let entries = get_bundle_entries_with_conn(conn, ids)?;
+    let folder_names = get_folders_with_conn(conn)?
+        .into_iter()
+        .map(|folder| (folder.id, folder.name))
+        .collect();
+    let result = write_bundle(
+        BufWriter::new(File::create(destination)?),
+        entries,
+        &folder_names,
+        recordings_dir,
+        encryption,
+    );
+    if result.is_err() {
+        if let Err(remove_error) = fs::remove_file(destination) {
+            error!(
+                "Failed to remove partial history bundle {:?}: {}",
+                destination, remove_error
+            );
+        }
+    }
+    result
+}
+
+/// A bundle entry that is not in history yet, with the recording name it
+/// will be stored under.
+struct PendingImport<'a> {
+    bundled: &'a BundleEntry,
+    file_name: String,
+}
+
+fn import_bundle_with_conn(
+    conn: &mut Connection,
+    source: &Path,
+    recordings_dir: &Path,
+    encryption: &HistoryEncryption,
+) -> Result<HistoryImportSummary> {
+    let mut archive = ZipArchive::new(BufReader::new(File::open(source)?))?;
+    let manifest = read_manifest(&mut archive)?;
+
+    // Recordings are extracted before the transaction opens, so a large
+    // bundle never holds the database write lock while copying audio.
+    let mut written_audio = Vec::new();
+    let result = extract_bundle_audio_with_conn(
+        conn,
+        &mut archive,
+        &manifest,
+        recordings_dir,
+        encryption,
+        &mut written_audio,
+    )
+    .and_then(|(pending, skipped)| {
+        let tx = conn.transaction()?;
+        let imported = insert_bundle_entries_with_conn(&tx, &pending)?;
+        tx.commit()?;
+        Ok(HistoryImportSummary { imported, skipped })
+    });
+
+    if result.is_err() {
+        for path in written_audio {
+            if let Err(remove_error) = fs::remove_file(&path) {
+                error!(
+                    "Failed to remove recording {:?} after history import failed: {}",
+                    path, remove_error
+                );
+            }
+        }
+    }
+    result
+}
+
+/// Skips entries already in history and writes the recordings of the rest.
+/// Returns the entries still to insert and how many were skipped.
+fn extract_bundle_audio_with_conn<'a, R: Read + Seek>(
+    conn: &Connection,
+    archive: &mut ZipArchive<R>,
+    manifest: &'a BundleManifest,
+    recordings_dir: &Path,
+    encryption: &HistoryEncryption,
+    written_audio: &mut Vec<PathBuf>,
+) -> Result<(Vec<PendingImport<'a>>, u32)> {
+    let mut pending: Vec<PendingImport> = Vec::new();
+    let mut skipped = 0;
+    let mut seen = HashSet::new();
+
+    for bundled in &manifest.entries {
+        let entry = &bundled.entry;
+        let already_imported: bool = conn.query_row(
+            "SELECT EXISTS(
+                 SELECT 1 FROM transcription_history
+                 WHERE timestamp = ?1 AND recording_source = ?2
+                   AND history_decode(transcription_text) = ?3
+             )",
+            params![
+                entry.timestamp,
+                entry.recording_source,
+                entry.transcription_text
+            ],
+            |row| row.get(0),
+        )?;
+        // The same entry can appear twice in a hand-merged bundle.
+        let duplicate = !seen.insert((
+            entry.timestamp,
+            entry.recording_source.as_str(),
+            entry.transcription_text.as_str(),
+        ));
+        if already_imported || duplicate {
+            skipped += 1;
+            continue;
+        }
+
+        // Keep the original recording name unless it is unsafe or already used
+        // by a different entry on this machine or earlier in the bundle.
+        let name_in_use: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM transcription_history WHERE file_name = ?1)",
+            params![entry.file_name],
+            |row| row.get(0),
+        )?;
+        let name_pending = pending
+            .iter()
+            .any(|import| import.file_name == entry.file_name);
+        let file_name = if is_safe_recording_file_name(&entry.file_name)
+            && !name_in_use
+            && !name_pending
+            && !recordings_dir.join(&entry.file_name).exists()
+        {
+            entry.file_name.clone()
+        } else {
+            recording_file_name(entry.timestamp)
+        };
+
+        if let Some(audio_path) = &bundled.audio_path {
+            let destination = recordings_dir.join(&file_name);
+            written_audio.push(destination.clone());
+            extract_audio(archive, audio_path, &destination, encryption)?;
+        }
+        pending.push(PendingImport { bundled, file_name });
+    }
+
+    Ok((pending, skipped))
+}
+
+fn insert_bundle_entries_with_conn(conn: &Connection, pending: &[PendingImport]) -> Result<u32> {
+    for PendingImport { bundled, file_name } in pending {
+        let entry = &bundled.entry;
+        let folder_id = bundled
+            .folder
+            .as_deref()
+            .map(|name| folder_id_for_name_with_conn(conn, name))
+            .transpose()?;
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source, transcription_route, fallback_from, transcription_model, note, folder_id, audio_purged, source_path)
+             VALUES (?1, ?2, ?3, history_encode(?4), history_encode(?5), history_encode(?6), history_encode(?7), ?8, ?9, ?10, ?11, history_encode(?12), ?13, ?14, history_encode(?15))",
+            params![
+                file_name,
+                entry.timestamp,
+                entry.saved,
+                entry.title,
+                entry.transcription_text,
+                entry.post_processed_text,
+                entry.post_process_prompt,
+                entry.recording_source,
+                entry.transcription_route,
+                entry.fallback_from,
+                entry.transcription_model,
+                entry.note,
+                folder_id,
+                bundled.audio_path.is_none(),
+                entry.source_path
+            ],
+        )?;
+        let history_id = conn.last_insert_rowid();
+        insert_timestamps_with_conn(conn, history_id, &bundled.timestamps)?;
+        insert_tags_with_conn(conn, history_id, &entry.tags)?;
+    }
+
+    Ok(pending.len() as u32)
+}
+
+fn update_entry_with_conn(
+    conn: &Connection,
+    id: i64,
+    update: &HistoryEntryUpdate,
+) -> Result<HistoryEntry> {
+    let tx = conn.unchecked_transaction()?;
+    let current = require_entry_with_conn(&tx, id)?;
+    let post_process_prompt = current.post_process_prompt.clone();
+    let entry = replace_entry_text_with_conn(&tx, current, update, post_process_prompt)?;
+    tx.commit()?;
+    Ok(entry)
+}
+
+/// Applies `update` and `post_process_prompt` to `current`, saving its
+/// previous text and prompt as a revision. Callers run it in a transaction.
+fn replace_entry_text_with_conn(
+    conn: &Connection,
+    current: HistoryEntry,
+    update: &HistoryEntryUpdate,
+    post_process_prompt: Option<String>,
+) -> Result<HistoryEntry> {
+    let title = update.title.as_ref().unwrap_or(&current.title).clone();
+    let transcription_text = update
+        .transcription_text
+        .as_ref()
+        .unwrap_or(&current.transcription_text)
+        .clone();
+    let post_processed_text = match &update.post_processed_text {
+        Some(text) if text.trim().is_empty() => None,
+        Some(text) => Some(text.clone()),
+        None => current.post_processed_text.clone(),
+    };
+    if transcription_text.trim().is_empty() {
+        bail!("Transcription text cannot be empty");
+    }
+    if title == current.title
+        && transcription_text == current.transcription_text
+        && post_processed_text == current.post_processed_text
+        && post_process_prompt == current.post_process_prompt
+    {
+        return Ok(current);
+    }
+
+    conn.execute(
+        "INSERT INTO transcription_revisions (history_id, created_at, title, transcription_text, post_processed_text, post_process_prompt)
+         VALUES (?1, ?2, history_encode(?3), history_encode(?4), history_encode(?5), history_encode(?6))",
+        params![
+            current.id,
+            Utc::now().timestamp(),
+            current.title,
+            current.transcription_text,
+            current.post_processed_text,
+            current.post_process_prompt
+        ],
+    )?;
+    conn.execute(
+        "UPDATE transcription_history
+         SET title = history_encode(?1),
+             transcription_text = history_encode(?2),
+             post_processed_text = history_encode(?3),
+             post_process_prompt = history_encode(?4)
+         WHERE id = ?5",
+        params![
+            title,
+            transcription_text,
+            post_processed_text,
+            post_process_prompt,
+            current.id
+        ],
+    )?;
+
+    Ok(HistoryEntry {
+        title,
+        transcription_text,
+        post_processed_text,
+        post_process_prompt,
+        ..current
+    })
+}
+
+fn get_revisions_with_conn(conn: &Connection, history_id: i64) -> Result<Vec<HistoryRevision>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, history_id, created_at,
+                history_decode(title) AS title,
+                history_decode(transcription_text) AS transcription_text,
+                history_decode(post_processed_text) AS post_processed_text,
+                history_decode(post_process_prompt) AS post_process_prompt
+         FROM transcription_revisions
+         WHERE history_id = ?1
+         ORDER BY id DESC",
+    )?;
+    let rows = stmt.query_map(params![history_id], |row| {
+        Ok(HistoryRevision {
+            id: row.get("id")?,
+            history_id: row.get("history_id")?,
+            created_at: row.get("created_at")?,
+            title: row.get("title")?,
+            transcription_text: row.get("transcription_text")?,
+            post_processed_text: row.get("post_processed_text")?,
+            post_process_prompt: row.get("post_process_prompt")?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn revert_entry_with_conn(conn: &Connection, id: i64, revision_id: i64) -> Result<HistoryEntry> {
+    let revision = get_revisions_with_conn(conn, id)?
+        .into_iter()
+        .find(|revision| revision.id == revision_id)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Revision {} not found for history entry {}",
+                revision_id,
+                id
+            )
+        })?;
+
+    let tx = conn.unchecked_transaction()?;
+    let current = require_entry_with_conn(&tx, id)?;
+    let entry = replace_entry_text_with_conn(
+        &tx,
+        current,
+        &HistoryEntryUpdate {
+            title: Some(revision.title),
+            transcription_text: Some(revision.transcription_text),
+            post_processed_text: Some(revision.post_processed_text.unwrap_or_default()),
+        },
+        revision.post_process_prompt,
+    )?;
+    tx.commit()?;
+    Ok(entry)
+}
+
+fn insert_file_import_with_conn(
+    conn: &Connection,
+    timestamp: i64,
+    file: &SavedFileTranscription,
+    post_process_prompt: Option<&str>,
+) -> Result<i64> {
+    // Nothing is written under this name. A fresh one keeps deletes from
+    // touching a recording that shares the imported file's name.
+    conn.execute(
+        "INSERT INTO transcription_history (file_name, timestamp, saved, audio_purged, title, transcription_text, post_processed_text, post_process_prompt, recording_source, source_path)
+         VALUES (?1, ?2, 0, 1, history_encode(?3), history_encode(?4), history_encode(?5), history_encode(?6), ?7, history_encode(?8))",
+        params![
+            recording_file_name(timestamp),
+            timestamp,
+            file.file_name,
+            file.transcription_text,
+            file.post_processed_text,
+            post_process_prompt,
+            FILE_IMPORT_SOURCE,
+            file.source_path
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Inserts file transcriptions kept in settings, newest first, one second
+/// apart before `now` so they keep their order.
+fn import_saved_file_transcriptions_with_conn(
+    conn: &Connection,
+    saved: &[SavedFileTranscription],
+    now: i64,
+) -> Result<usize> {
+    let tx = conn.unchecked_transaction()?;
+    for (index, file) in saved.iter().enumerate() {
+        insert_file_import_with_conn(&tx, now - index as i64, file, None)?;
+    }
+    tx.commit()?;
+    Ok(saved.len())
+}
+
+fn reprocess_entry_with_conn(
+    conn: &Connection,
+    id: i64,
+    post_processed_text: &str,
+    post_process_prompt: Option<&str>,
+) -> Result<HistoryEntry> {
+    if post_processed_text.trim().is_empty() {
+        bail!("Post-processed text cannot be empty");
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let current = require_entry_with_conn(&tx, id)?;
+    let entry = replace_entry_text_with_conn(
+        &tx,
+        current,
+        &HistoryEntryUpdate {
+            title: None,
+            transcription_text: None,
+            post_processed_text: Some(post_processed_text.to_string()),
+        },
+        post_process_prompt.map(str::to_string),
+    )?;
+    tx.commit()?;
+    Ok(entry)
+}
+
+fn add_alternate_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    alternate: &NewHistoryAlternate,
+) -> Result<HistoryAlternate> {
+    require_entry_with_conn(conn, history_id)?;
+    if alternate.transcription_text.trim().is_empty() {
+        bail!("Transcription text cannot be empty");
+    }
+
+    let created_at = Utc::now().timestamp();
+    conn.execute(
+        "INSERT INTO transcription_alternates (history_id, created_at, transcription_model, language, transcription_route, transcription_text, post_processed_text)
+         VALUES (?1, ?2, ?3, ?4, ?5, history_encode(?6), history_encode(?7))",
+        params![
+            history_id,
+            created_at,
+            alternate.transcription_model,
+            alternate.language,
+            alternate.transcription_route,
+            alternate.transcription_text,
+            alternate.post_processed_text
+        ],
+    )?;
+    Ok(HistoryAlternate {
+        id: conn.last_insert_rowid(),
+        history_id,
+        created_at,
+        transcription_model: alternate.transcription_model.clone(),
+        language: alternate.language.clone(),
+        transcription_route: alternate.transcription_route.clone(),
+        transcription_text: alternate.transcription_text.clone(),
+        post_processed_text: alternate.post_processed_text.clone(),
+    })
+}
+
+fn get_alternates_with_conn(conn: &Connection, history_id: i64) -> Result<Vec<HistoryAlternate>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, history_id, created_at, transcription_model, language, transcription_route,
+                history_decode(transcription_text) AS transcription_text,
+                history_decode(post_processed_text) AS post_processed_text
+         FROM transcription_alternates
+         WHERE history_id = ?1
+         ORDER BY id DESC",
+    )?;
+    let rows = stmt.query_map(params![history_id], |row| {
+        Ok(HistoryAlternate {
+            id: row.get("id")?,
+            history_id: row.get("history_id")?,
+            created_at: row.get("created_at")?,
+            transcription_model: row.get("transcription_model")?,
+            language: row.get("language")?,
+            transcription_route: row.get("transcription_route")?,
+            transcription_text: row.get("transcription_text")?,
+            post_processed_text: row.get("post_processed_text")?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn apply_alternate_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    alternate_id: i64,
+) -> Result<HistoryEntry> {
+    // An alternate has no speaker labels or summary, so it would wipe both
+    // from a meeting.
+    if require_entry_with_conn(conn, history_id)?.recording_source == "full_system_audio" {
+        bail!("refusing to replace the transcript of meeting history entry {history_id}");
+    }
+    let alternate = get_alternates_with_conn(conn, history_id)?
+        .into_iter()
+        .find(|alternate| alternate.id == alternate_id)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Alternate {} not found for history entry {}",
+                alternate_id,
+                history_id
+            )
+        })?;
+
+    let entry = update_entry_with_conn(
+        conn,
+        history_id,
+        &HistoryEntryUpdate {
+            title: None,
+            transcription_text: Some(alternate.transcription_text),
+            post_processed_text: Some(alternate.post_processed_text.unwrap_or_default()),
+        },
+    )?;
+    conn.execute(
+        "DELETE FROM transcription_alternates WHERE id = ?1",
+        params![alternate_id],
+    )?;
+    let Some(route) = alternate.transcription_route else {
+        return Ok(entry);
+    };
+    save_transcription_route_with_conn(
+        conn,
+        history_id,
+        &route,
+        None,
+        alternate.transcription_model.as_deref(),
+    )?;
+
+    Ok(HistoryEntry {
+        transcription_route: Some(route),
+        fallback_from: None,
+        transcription_model: alternate.transcription_model,
+        ..entry
+    })
+}
+
+fn get_entry_with_conn(conn: &Connection, id: i64) -> Result<Option<HistoryEntry>> {
+    Ok(conn
+        .query_row(
+            &format!("SELECT {HISTORY_ENTRY_COLUMNS} FROM transcription_history WHERE id = ?1"),
+            params![id],
+            history_entry_from_row,
+        )
+        .optional()?)
+}
+
+fn require_entry_with_conn(conn: &Connection, id: i64) -> Result<HistoryEntry> {
+    get_entry_with_conn(conn, id)?.ok_or_else(|| anyhow::anyhow!("History entry {} not found", id))
+}
+
+/// Collapses whitespace, drops blank tags and keeps the first spelling of
+/// tags that differ only by case.
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag
+            .split(|c: char| c.is_whitespace() || c.is_control())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if tag.is_empty() {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_CHARS {
+            bail!("Tags can be at most {} characters", MAX_TAG_CHARS);
+        }
+        if !normalized
+            .iter()
+            .any(|existing| existing.to_lowercase() == tag.to_lowercase())
+        {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+fn insert_tags_with_conn(conn: &Connection, history_id: i64, tags: &[String]) -> Result<()> {
+    let mut stmt =
+        conn.prepare("INSERT OR IGNORE INTO transcription_tags (history_id, tag) VALUES (?1, ?2)")?;
+    for tag in normalize_tags(tags)? {
+        stmt.execute(params![history_id, tag])?;
+    }
+    Ok(())
+}
+
+fn set_tags_with_conn(conn: &Connection, id: i64, tags: &[String]) -> Result<HistoryEntry> {
+    require_entry_with_conn(conn, id)?;
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM transcription_tags WHERE history_id = ?1",
+        params![id],
+    )?;
+    insert_tags_with_conn(&tx, id, tags)?;
+    tx.commit()?;
+    require_entry_with_conn(conn, id)
+}
+
+fn set_note_with_conn(conn: &Connection, id: i64, note: Option<&str>) -> Result<HistoryEntry> {
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+    if conn.execute(
+        "UPDATE transcription_history SET note = history_encode(?1) WHERE id = ?2",
+        params![note, id],
+    )? == 0
+    {
+        bail!("History entry {} not found", id);
+    }
+    require_entry_with_conn(conn, id)
+}
+
+fn set_folder_with_conn(
+    conn: &Connection,
+    id: i64,
+    folder_id: Option<i64>,
+) -> Result<HistoryEntry> {
+    if let Some(folder_id) = folder_id {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM history_folders WHERE id = ?1)",
+            params![folder_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            bail!("History folder {} not found", folder_id);
+        }
+    }
+    if conn.execute(
+        "UPDATE transcription_history SET folder_id = ?1 WHERE id = ?2",
+        params![folder_id, id],
+    )? == 0
+    {
+        bail!("History entry {} not found", id);
+    }
+    require_entry_with_conn(conn, id)
+}
+
+const FOLDER_COLUMNS: &str = "history_folders.id, history_folders.name,
+     (SELECT COUNT(*) FROM transcription_history WHERE folder_id = history_folders.id) AS entry_count";
+
+fn history_folder_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<HistoryFolder> {
+    Ok(HistoryFolder {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        entry_count: row.get("entry_count")?,
+    })
+}
+
+fn get_folders_with_conn(conn: &Connection) -> Result<Vec<HistoryFolder>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {FOLDER_COLUMNS} FROM history_folders ORDER BY name"
+    ))?;
+    let rows = stmt.query_map([], history_folder_from_row)?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn get_folder_with_conn(conn: &Connection, id: i64) -> Result<HistoryFolder> {
+    conn.query_row(
+        &format!("SELECT {FOLDER_COLUMNS} FROM history_folders WHERE id = ?1"),
+        params![id],
+        history_folder_from_row,
+    )
+    .optional()?
+    .ok_or_else(|| anyhow::anyhow!("History folder {} not found", id))
+}
+
+/// Trims a folder name and rejects it if empty, too long or already used by
+/// another folder (ignoring case).
+fn validate_folder_name(conn: &Connection, name: &str, id: Option<i64>) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Folder name cannot be empty");
+    }
+    if name.chars().count() > MAX_FOLDER_NAME_CHARS {
+        bail!(
+            "Folder names can be at most {} characters",
+            MAX_FOLDER_NAME_CHARS
+        );
+    }
+    let taken: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM history_folders WHERE name = ?1 AND id IS NOT ?2)",
+        params![name, id],
+        |row| row.get(0),
+    )?;
+    if taken {
+        bail!("A folder named \"{}\" already exists", name);
+    }
+    Ok(name.to_string())
+}
+
+fn create_folder_with_conn(conn: &Connection, name: &str) -> Result<HistoryFolder> {
+    let name = validate_folder_name(conn, name, None)?;
+    conn.execute(
+        "INSERT INTO history_folders (name, created_at) VALUES (?1, ?2)",
+        params![name, Utc::now().timestamp()],
+    )?;
+    get_folder_with_conn(conn, conn.last_insert_rowid())
+}
+
+fn rename_folder_with_conn(conn: &Connection, id: i64, name: &str) -> Result<HistoryFolder> {
+    let name = validate_folder_name(conn, name, Some(id))?;
+    if conn.execute(
+        "UPDATE history_folders SET name = ?1 WHERE id = ?2",
+        params![name, id],
+    )? == 0
+    {
+        bail!("History folder {} not found", id);
+    }
+    get_folder_with_conn(conn, id)
+}
+
+/// Id of the folder with this name, creating it if needed. Used to place
+/// imported entries.
+fn folder_id_for_name_with_conn(conn: &Connection, name: &str) -> Result<i64> {
+    let existing = conn
+        .query_row(
+            "SELECT id FROM history_folders WHERE name = ?1",
+            params![name.trim()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    match existing {
+        Some(id) => Ok(id),
+        None => Ok(create_folder_with_conn(conn, name)?.id),
+    }
+}
+
+fn get_tags_with_conn(conn: &Connection) -> Result<Vec<HistoryTag>> {
+    let mut stmt = conn.prepare(
+        "SELECT MIN(tag) AS name, COUNT(*) AS entry_count
+         FROM transcription_tags
+         GROUP BY tag
+         ORDER BY entry_count DESC, name",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(HistoryTag {
+            name: row.get("name")?,
+            entry_count: row.get("entry_count")?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn save_transcription_route_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    route: &str,
+    fallback_from: Option<&str>,
+    model: Option<&str>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE transcription_history
+         SET transcription_route = ?1, fallback_from = ?2, transcription_model = ?3
+         WHERE id = ?4",
+        params![route, fallback_from, model, history_id],
+    )?;
+    Ok(())
+}
+
+fn delete_entry_and_audio_with_connection(
+    conn: &Connection,
+    recordings_dir: &Path,
+    id: i64,
+    require_dictation: bool,
+) -> Result<()> {
+    let entry: Option<(String, String)> = conn
+        .query_row(
+            "SELECT file_name, recording_source FROM transcription_history WHERE id = ?1",
+            params![id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    if let Some((file_name, recording_source)) = entry {
+        if require_dictation && recording_source == "full_system_audio" {
+            bail!("refusing to roll back meeting history entry {id}");
+        }
+
+        let file_path = recordings_dir.join(&file_name);
+        if file_path.exists() {
+            if let Err(e) = fs::remove_file(&file_path) {
+                error!("Failed to delete audio file {}: {}", file_name, e);
+            }
+        }
+    }
+
+    conn.execute(
+        "DELETE FROM transcription_history WHERE id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
+fn save_timestamps_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    timestamps: &TranscriptTimestamps,
+) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM transcription_segments WHERE history_id = ?1",
+        params![history_id],
+    )?;
+    insert_timestamps_with_conn(&tx, history_id, timestamps)?;
+    tx.commit()?;
+    Ok(())
+}
+
+fn insert_timestamps_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    timestamps: &TranscriptTimestamps,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        "INSERT INTO transcription_segments (history_id, granularity, position, start_ms, end_ms, text)
+         VALUES (?1, ?2, ?3, ?4, ?5, history_encode(?6))",
+    )?;
+    for (granularity, spans) in [
+        (SEGMENT_GRANULARITY, &timestamps.segments),
+        (WORD_GRANULARITY, &timestamps.words),
+    ] {
+        for (position, span) in spans.iter().enumerate() {
+            stmt.execute(params![
+                history_id,
+                granularity,
+                position as i64,
+                span.start_ms,
+                span.end_ms,
+                span.text
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+fn get_timestamps_with_conn(conn: &Connection, history_id: i64) -> Result<TranscriptTimestamps> {
+    let mut stmt = conn.prepare(
+        "SELECT granularity, start_ms, end_ms, history_decode(text) AS text
+         FROM transcription_segments
+         WHERE history_id = ?1
+         ORDER BY granularity, position",
+    )?;
+    let rows = stmt.query_map(params![history_id], |row| {
+        Ok((
+            row.get::<_, String>("granularity")?,
+            TranscriptSegment {
+                start_ms: row.get("start_ms")?,
+                end_ms: row.get("end_ms")?,
+                text: row.get("text")?,
+            },
+        ))
+    })?;
+
+    let mut timestamps = TranscriptTimestamps::default();
+    for row in rows {
+        let (granularity, span) = row?;
+        match granularity.as_str() {
+            SEGMENT_GRANULARITY => timestamps.segments.push(span),
+            WORD_GRANULARITY => timestamps.words.push(span),
+            _ => {}
+        }
+    }
+
+    Ok(timestamps)
+}
+
+/// Render an entry's timings as subtitles (SRT/WebVTT) or a JSON document.
+pub fn export_transcript(
+    entry: &HistoryEntry,
+    timestamps: &TranscriptTimestamps,
+    format: TranscriptExportFormat,
+) -> Result<String> {
+    if format != TranscriptExportFormat::Json && timestamps.segments.is_empty() {
+        bail!("This entry has no timestamps to export.");
+    }
+
+    Ok(match format {
+        TranscriptExportFormat::Srt => {
+            let mut output = String::new();
+            for (index, segment) in timestamps.segments.iter().enumerate() {
+                output.push_str(&format!(
+                    "{}\n{} --> {}\n{}\n\n",
+                    index + 1,
+                    format_cue_timestamp(segment.start_ms, ','),
+                    format_cue_timestamp(segment.end_ms, ','),
+                    segment.text
+                ));
+            }
+            output
+        }
+        TranscriptExportFormat::Vtt => {
+            let mut output = String::from("WEBVTT\n\n");
+            for segment in &timestamps.segments {
+                output.push_str(&format!(
+                    "{} --> {}\n{}\n\n",
+                    format_cue_timestamp(segment.start_ms, '.'),
+                    format_cue_timestamp(segment.end_ms, '.'),
+                    segment.text
+                ));
+            }
+            output
+        }
+        TranscriptExportFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+            "id": entry.id,
+            "file_name": entry.file_name,
+            "timestamp": entry.timestamp,
+            "transcription_text": entry.transcription_text,
+            "post_processed_text": entry.post_processed_text,
+            "segments": timestamps.segments,
+            "words": timestamps.words,
+        }))?,
+    })
+}
+
+fn format_cue_timestamp(milliseconds: i64, fraction_separator: char) -> String {
+    let milliseconds = milliseconds.max(0);
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        milliseconds / 3_600_000,
+        (milliseconds / 60_000) % 60,
+        (milliseconds / 1_000) % 60,
+        fraction_separator,
+        milliseconds % 1_000
+    )
+}
+
+/// Whether `file_name` is a plain `.wav` name that cannot escape the
+/// recordings directory.
+pub fn is_safe_recording_file_name(file_name: &str) -> bool {
+    let path = Path::new(file_name);
+    let mut components = path.components();
+    let is_single_normal_component =
+        matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none();
+
+    !file_name.trim().is_empty()
+        && is_single_normal_component
+        && path.file_name().and_then(|name| name.to_str()) == Some(file_name)
+        && path.extension().and_then(|extension| extension.to_str()) == Some("wav")
+}
+
+fn recording_file_name(timestamp: i64) -> String {
+    format!("uttr-{}-{}.wav", timestamp, Uuid::new_v4())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::{params, Connection};
+
+    fn setup_conn() -> Connection {
+        let mut conn = Connection::open_in_memory().expect("open in-memory db");
+        Migrations::new(MIGRATIONS.to_vec())
+            .to_latest(&mut conn)
+            .expect("run migrations");
+        // Encryption stays off, so the key directory is never read.
+        register_sql_functions(
+            &conn,
+            Arc::new(HistoryEncryption::new(PathBuf::new(), false)),
+        )
+        .expect("register history functions");
+        conn
+    }
+
+    fn insert_entry(conn: &Connection, timestamp: i64, text: &str, post_processed: Option<&str>) {
+        insert_entry_with_source(conn, timestamp, text, post_processed, false, "dictation");
+    }
+
+    fn insert_entry_with_source(
+        conn: &Connection,
+        timestamp: i64,
+        text: &str,
+        post_processed: Option<&str>,
+        saved: bool,
+        recording_source: &str,
+    ) {
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                format!("uttr-{}.wav", timestamp),
+                timestamp,
+                saved,
+                format!("Recording {}", timestamp),
+                text,
+                post_processed,
+                Option::<String>::None,
+                recording_source
+            ],
+        )
+        .expect("insert history entry");
+    }
+
+    fn by_source(source: HistorySourceFilter) -> HistoryPageFilter {
+        HistoryPageFilter {
+            source,
+            ..HistoryPageFilter::default()
+        }
+    }
+
+    #[test]
+    fn migrations_create_history_query_indexes() {
+        let mut conn = Connection::open_in_memory().expect("open in-memory db");
+        let migrations = Migrations::new(MIGRATIONS.to_vec());
+        migrations.validate().expect("validate migrations");
+        migrations.to_latest(&mut conn).expect("run migrations");
+
+        let timestamp_index_exists: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'index' AND name = 'transcription_history_timestamp_idx'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("query timestamp index");
+        let cleanup_index_exists: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'index' AND name = 'transcription_history_unsaved_dictation_timestamp_idx'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("query cleanup index");
+
+        assert!(timestamp_index_exists);
+        assert!(cleanup_index_exists);
+    }
+
+    #[test]
+    fn timestamps_round_trip_and_are_removed_with_their_entry() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "hello there. general kenobi", None);
+        let entry_id = conn.last_insert_rowid();
+        let timestamps = TranscriptTimestamps {
+            segments: vec![
+                TranscriptSegment {
+                    start_ms: 0,
+                    end_ms: 1_200,
+                    text: "hello there.".to_string(),
+                },
+                TranscriptSegment {
+                    start_ms: 1_400,
+                    end_ms: 2_900,
+                    text: "general kenobi".to_string(),
+                },
+            ],
+            words: Vec::new(),
+        };
+
+        save_timestamps_with_conn(&conn, entry_id, &timestamps).expect("save timestamps");
+        save_timestamps_with_conn(&conn, entry_id, &timestamps).expect("replace timestamps");
+        assert_eq!(
+            get_timestamps_with_conn(&conn, entry_id).expect("load timestamps"),
+            timestamps
+        );
+
+        delete_entry_and_audio_with_connection(&conn, Path::new("/nonexistent"), entry_id, false)
+            .expect("delete entry");
+        assert_eq!(
+            get_timestamps_with_conn(&conn, entry_id).expect("load timestamps"),
+            TranscriptTimestamps::default()
+        );
+    }
+
+    #[test]
+    fn exports_segments_as_srt_and_vtt() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "hello there", None);
+        let entry = HistoryManager::get_latest_entry_with_conn(&conn)
+            .expect("fetch entry")
+            .expect("entry exists");
+        let timestamps = TranscriptTimestamps {
+            segments: vec![TranscriptSegment {
+                start_ms: 3_723_045,
+                end_ms: 3_725_500,
+                text: "hello there".to_string(),
+            }],
+            words: Vec::new(),
+        };
+
+        assert_eq!(
+            export_transcript(&entry, &timestamps, TranscriptExportFormat::Srt).unwrap(),
+            "1\n01:02:03,045 --> 01:02:05,500\nhello there\n\n"
+        );
+        assert_eq!(
+            export_transcript(&entry, &timestamps, TranscriptExportFormat::Vtt).unwrap(),
+            "WEBVTT\n\n01:02:03.045 --> 01:02:05.500\nhello there\n\n"
+        );
+        assert!(export_transcript(
+            &entry,
+            &TranscriptTimestamps::default(),
+            TranscriptExportFormat::Srt
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn transcription_route_is_stored_on_the_entry() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "offline dictation", None);
+        let entry_id = conn.last_insert_rowid();
+
+        save_transcription_route_with_conn(
+            &conn,
+            entry_id,
+            "local",
+            Some("groq"),
+            Some("parakeet-tdt-0.6b-v3"),
+        )
+        .expect("save route");
+
+        let entry = HistoryManager::get_latest_entry_with_conn(&conn)
+            .expect("fetch entry")
+            .expect("entry exists");
+        assert_eq!(entry.transcription_route.as_deref(), Some("local"));
+        assert_eq!(entry.fallback_from.as_deref(), Some("groq"));
+        assert_eq!(
+            entry.transcription_model.as_deref(),
+            Some("parakeet-tdt-0.6b-v3")
+        );
+    }
+
+    #[test]
+    fn history_pages_follow_cursor_and_source_filter() {
+        let conn = setup_conn();
+        // Same-second timestamps must not be skipped or repeated across pages.
+        for (timestamp, source) in [
+            (100, "dictation"),
+            (200, "dictation"),
+            (200, "full_system_audio"),
+            (200, "dictation"),
+            (300, "dictation"),
+        ] {
+            insert_entry_with_source(&conn, timestamp, "text", None, false, source);
+        }
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page =
+                get_history_page_with_conn(&conn, cursor, Some(2), &HistoryPageFilter::default())
+                    .unwrap();
+            let expected_count = if cursor.is_none() { Some(5) } else { None };
+            assert_eq!(page.total_count, expected_count);
+            seen.extend(page.entries.iter().map(|entry| (entry.timestamp, entry.id)));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(seen, vec![(300, 5), (200, 4), (200, 3), (200, 2), (100, 1)]);
+
+        let dictations = get_history_page_with_conn(
+            &conn,
+            None,
+            None,
+            &by_source(HistorySourceFilter::Dictations),
+        )
+        .unwrap();
+        assert_eq!(dictations.total_count, Some(4));
+        assert_eq!(dictations.entries.len(), 4);
+        assert!(dictations.next_cursor.is_none());
+
+        let meetings = get_history_page_with_conn(
+            &conn,
+            None,
+            None,
+            &by_source(HistorySourceFilter::Meetings),
+        )
+        .unwrap();
+        assert_eq!(meetings.total_count, Some(1));
+        assert_eq!(meetings.entries[0].id, 3);
+    }
+
+    #[test]
+    fn history_page_query_uses_timestamp_index() {
+        let conn = setup_conn();
+        let plan: Vec<String> = conn
+            .prepare(&format!(
+                "EXPLAIN QUERY PLAN {}",
+                history_page_sql(HistorySourceFilter::Dictations.sql_condition())
+            ))
+            .unwrap()
+            .query_map(
+                params![
+                    Option::<i64>::None,
+                    Option::<String>::None,
+                    i64::MAX,
+                    i64::MAX,
+                    51
+                ],
+                |row| row.get(3),
+            )
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+
+        assert!(
+            plan.iter()
+                .any(|detail| detail.contains("transcription_history_timestamp_idx")),
+            "{plan:?}"
+        );
+        // Only same-second ties get sorted; the scan itself stays ordered.
+        assert!(
+            !plan
+                .iter()
+                .any(|detail| detail == "USE TEMP B-TREE FOR ORDER BY"),
+            "{plan:?}"
+        );
+    }
+
+    #[test]
+    fn search_ranks_matches_and_applies_filters() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "deploy the worker tonight", None);
+        insert_entry(
+            &conn,
+            200,
+            "groceries",
+            Some("Deploy notes: deploy the deploy script"),
+        );
+        insert_entry_with_source(&conn, 300, "deploy review", None, true, "full_system_audio");
+
+        let ids = |results: Vec<HistorySearchResult>| -> Vec<i64> {
+            results
+                .into_iter()
+                .map(|result| result.entry.timestamp)
+                .collect()
+        };
+        let all = HistorySearchFilters::default();
+
+        let results = search_entries_with_conn(&conn, "deplo", &all, None).expect("search");
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].entry.timestamp, 200);
+        assert!(results[0]
+            .snippet
+            .contains(&format!("{SEARCH_MATCH_START}deploy{SEARCH_MATCH_END}")));
+
+        let saved_meetings = HistorySearchFilters {
+            recording_source: Some("full_system_audio".to_string()),
+            saved: Some(true),
+            ..HistorySearchFilters::default()
+        };
+        assert_eq!(
+            ids(search_entries_with_conn(&conn, "deploy", &saved_meetings, None).expect("search")),
+            vec![300]
+        );
+
+        let date_range = HistorySearchFilters {
+            from_timestamp: Some(150),
+            to_timestamp: Some(250),
+            ..HistorySearchFilters::default()
+        };
+        assert_eq!(
+            ids(search_entries_with_conn(&conn, "", &date_range, None).expect("filter only")),
+            vec![200]
+        );
+        assert_eq!(
+            ids(search_entries_with_conn(&conn, "", &all, Some(2)).expect("filter only")),
+            vec![300, 200]
+        );
+        assert!(search_entries_with_conn(&conn, "\"AND (* NEAR", &all, None)
+            .expect("operators are quoted")
+            .is_empty());
+    }
+
+    #[test]
+    fn search_index_follows_updates_and_deletes() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "first draft", None);
+        let entry_id = conn.last_insert_rowid();
+
+        conn.execute(
+            "UPDATE transcription_history SET post_processed_text = 'polished summary' WHERE id = ?1",
+            [entry_id],
+        )
+        .expect("update entry");
+        let all = HistorySearchFilters::default();
+        assert_eq!(
+            search_entries_with_conn(&conn, "polished", &all, None)
+                .expect("search")
+                .len(),
+            1
+        );
+
+        conn.execute(
+            "DELETE FROM transcription_history WHERE id = ?1",
+            [entry_id],
+        )
+        .expect("delete entry");
+        assert!(search_entries_with_conn(&conn, "draft", &all, None)
+            .expect("search")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn bundle_import_merges_entries_without_duplicating_them() {
+        let source_root = tempfile::tempdir().expect("create source root");
+        let source = HistoryManager::new_for_test(source_root.path()).expect("create source");
+        {
+            let conn = source.get_connection().expect("open source database");
+            insert_entry(&conn, 100, "dictated text", Some("Dictated text."));
+            insert_entry_with_source(&conn, 200, "meeting text", None, true, "full_system_audio");
+            save_timestamps_with_conn(
+                &conn,
+                2,
+                &TranscriptTimestamps {
+                    segments: vec![TranscriptSegment {
+                        start_ms: 0,
+                        end_ms: 900,
+                        text: "meeting text".to_string(),
+                    }],
+                    words: Vec::new(),
+                },
+            )
+            .expect("save timestamps");
+            let acme = create_folder_with_conn(&conn, "Acme").expect("create folder");
+            set_folder_with_conn(&conn, 2, Some(acme.id)).expect("file meeting");
+            set_tags_with_conn(&conn, 2, &["client".to_string()]).expect("tag meeting");
+        }
+        // Only the meeting still has its recording on disk.
+        fs::write(
+            source_root.path().join("recordings").join("uttr-200.wav"),
+            b"RIFF",
+        )
+        .expect("write recording");
+
+        let bundle_path = source_root.path().join("history.zip");
+        let exported = source
+            .export_bundle(None, &bundle_path)
+            .await
+            .expect("export bundle");
+        assert_eq!(
+            exported,
+            HistoryExportSummary {
+                entries: 2,
+                recordings: 1
+            }
+        );
+
+        let target_root = tempfile::tempdir().expect("create target root");
+        let target = HistoryManager::new_for_test(target_root.path()).expect("create target");
+        {
+            let conn = target.get_connection().expect("open target database");
+            // Two local entries, so imported ids cannot line up with the
+            // exporting machine's by accident.
+            insert_entry(&conn, 50, "already here", None);
+            insert_entry(&conn, 60, "also here", None);
+            create_folder_with_conn(&conn, "Other client").expect("create folder");
+        }
+
+        let imported = target
+            .import_bundle(&bundle_path)
+            .await
+            .expect("import bundle");
+        assert_eq!(
+            imported,
+            HistoryImportSummary {
+                imported: 2,
+                skipped: 0
+            }
+        );
+        let reimported = target
+            .import_bundle(&bundle_path)
+            .await
+            .expect("import bundle again");
+        assert_eq!(
+            reimported,
+            HistoryImportSummary {
+                imported: 0,
+                skipped: 2
+            }
+        );
+
+        let page = target
+            .get_history_page(None, None, &HistoryPageFilter::default())
+            .await
+            .expect("load history");
+        assert_eq!(page.total_count, Some(4));
+        let meeting = &page.entries[0];
+        assert_eq!(meeting.transcription_text, "meeting text");
+        assert_eq!(meeting.recording_source, "full_system_audio");
+        assert!(meeting.saved);
+        assert_ne!(meeting.id, 2);
+        assert_eq!(meeting.tags, vec!["client".to_string()]);
+        let folders = target.get_folders().await.expect("load folders");
+        let acme = folders
+            .iter()
+            .find(|folder| folder.name == "Acme")
+            .expect("folder imported by name");
+        assert_eq!(meeting.folder_id, Some(acme.id));
+        assert_eq!(acme.entry_count, 1);
+        assert_eq!(
+            fs::read(target.get_audio_file_path(&meeting.file_name)).expect("read recording"),
+            b"RIFF"
+        );
+        assert_eq!(
+            target
+                .get_timestamps(meeting.id)
+                .await
+                .expect("load timestamps")
+                .segments[0]
+                .text,
+            "meeting text"
+        );
+        assert_eq!(
+            page.entries[1].post_processed_text.as_deref(),
+            Some("Dictated text.")
+        );
+    }
+
+    #[test]
+    fn tags_notes_and_folders_organize_history() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "kickoff call", None);
+        insert_entry(&conn, 200, "pricing follow-up", None);
+        insert_entry(&conn, 300, "personal reminder", None);
+
+        let acme = create_folder_with_conn(&conn, " Acme ").unwrap();
+        assert_eq!(acme.name, "Acme");
+        assert!(create_folder_with_conn(&conn, "acme").is_err());
+        assert!(create_folder_with_conn(&conn, "  ").is_err());
+        set_folder_with_conn(&conn, 1, Some(acme.id)).unwrap();
+        set_folder_with_conn(&conn, 2, Some(acme.id)).unwrap();
+        assert!(set_folder_with_conn(&conn, 3, Some(acme.id + 1)).is_err());
+
+        let tagged = set_tags_with_conn(
+            &conn,
+            2,
+            &[
+                " Pricing ".to_string(),
+                "Q3  plan".to_string(),
+                "pricing".to_string(),
+                String::new(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(tagged.tags, vec!["Pricing", "Q3 plan"]);
+        set_tags_with_conn(&conn, 3, &["pricing".to_string()]).unwrap();
+
+        let noted = set_note_with_conn(&conn, 1, Some(" Send the deck ")).unwrap();
+        assert_eq!(noted.note.as_deref(), Some("Send the deck"));
+        assert_eq!(set_note_with_conn(&conn, 1, Some("")).unwrap().note, None);
+
+        let in_acme = HistoryPageFilter {
+            folder_id: Some(acme.id),
+            ..HistoryPageFilter::default()
+        };
+        let page = get_history_page_with_conn(&conn, None, None, &in_acme).unwrap();
+        assert_eq!(page.total_count, Some(2));
+        assert_eq!(
+            page.entries
+                .iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        let tagged_in_acme = HistoryPageFilter {
+            tag: Some("PRICING".to_string()),
+            ..in_acme.clone()
+        };
+        let page = get_history_page_with_conn(&conn, None, None, &tagged_in_acme).unwrap();
+        assert_eq!(page.total_count, Some(1));
+        assert_eq!(page.entries[0].id, 2);
+
+        let search = HistorySearchFilters {
+            tag: Some("pricing".to_string()),
+            ..HistorySearchFilters::default()
+        };
+        assert_eq!(
+            search_entries_with_conn(&conn, "", &search, None)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            get_tags_with_conn(&conn).unwrap()[0],
+            HistoryTag {
+                name: "Pricing".to_string(),
+                entry_count: 2
+            }
+        );
+
+        let renamed = rename_folder_with_conn(&conn, acme.id, "Acme Corp").unwrap();
+        assert_eq!(renamed.entry_count, 2);
+        conn.execute("DELETE FROM history_folders WHERE id = ?1", [acme.id])
+            .unwrap();
+        assert_eq!(
+            get_entry_with_conn(&conn, 1).unwrap().unwrap().folder_id,
+            None
+        );
+
+        conn.execute("DELETE FROM transcription_history WHERE id = 2", [])
+            .unwrap();
+        assert_eq!(get_tags_with_conn(&conn).unwrap()[0].entry_count, 1);
+    }
+
+    #[test]
+    fn recording_file_names_must_be_wav_basenames() {
+        assert!(is_safe_recording_file_name("uttr-2026-06-16.wav"));
+        assert!(!is_safe_recording_file_name(""));
+        assert!(!is_safe_recording_file_name("../uttr.wav"));
+        assert!(!is_safe_recording_file_name("nested/uttr.wav"));
+        assert!(!is_safe_recording_file_name("/tmp/uttr.wav"));
+        assert!(!is_safe_recording_file_name("uttr.mp3"));
+    }
+
+    #[tokio::test]
+    async fn saves_wait_out_a_long_write_lock() {
+        let root = tempfile::tempdir().expect("create history root");
+        let manager = HistoryManager::new_for_test(root.path()).expect("create history manager");
+        let busy_timeout: i64 = manager
+            .get_connection()
+            .unwrap()
+            .pragma_query_value(None, "busy_timeout", |row| row.get(0))
+            .unwrap();
+        assert_eq!(busy_timeout, HISTORY_BUSY_TIMEOUT.as_millis() as i64);
 
-    if let Some((file_name, recording_source)) = entry {
-        if require_dictation && recording_source == "full_system_audio" {
-            bail!("refusing to roll back meeting history entry {id}");
-        }
+        // Stands in for an encryption conversion holding the write lock.
+        let writer = manager.get_connection().unwrap();
+        writer.execute_batch("BEGIN IMMEDIATE").unwrap();
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            writer.execute_batch("COMMIT").unwrap();
+        });
+        let id = manager
+            .save_transcription(
+                vec![0.0; 160],
+                "saved mid-conversion".to_string(),
+                None,
+                None,
+                "dictation",
+            )
+            .await
+            .expect("save waits for the lock");
+        release.join().unwrap();
 
-        let file_path = recordings_dir.join(&file_name);
-        if file_path.exists() {
-            if let Err(e) = fs::remove_file(&file_path) {
-                error!("Failed to delete audio file {}: {}", file_name, e);
-            }
-        }
+        let entry = manager
+            .get_entry_by_id(id)
+            .await
+            .unwrap()
+            .expect("entry saved");
+        assert_eq!(entry.transcription_text, "saved mid-conversion");
+        assert!(root
+            .path()
+            .join("recordings")
+            .join(&entry.file_name)
+            .is_file());
     }
 
-    conn.execute(
-        "DELETE FROM transcription_history WHERE id = ?1",
-        params![id],
-    )?;
-    Ok(())
-}
+    #[tokio::test]
+    async fn encryption_converts_history_both_ways() {
+        let root = tempfile::tempdir().expect("create history root");
+        let manager = HistoryManager::new_for_test(root.path()).expect("create history manager");
+        let id = manager
+            .save_transcription(
+                vec![0.05; 1_600],
+                "confidential roadmap review".to_string(),
+                None,
+                None,
+                "dictation",
+            )
+            .await
+            .expect("save entry");
+        manager
+            .set_note(id, Some("confidential note"))
+            .await
+            .expect("set note");
+        manager
+            .save_timestamps(
+                id,
+                &TranscriptTimestamps {
+                    segments: vec![TranscriptSegment {
+                        start_ms: 0,
+                        end_ms: 900,
+                        text: "confidential roadmap review".to_string(),
+                    }],
+                    words: Vec::new(),
+                },
+            )
+            .expect("save timestamps");
+        let entry = manager.get_entry_by_id(id).await.unwrap().unwrap();
+        let recording = manager.get_audio_file_path(&entry.file_name);
 
-fn recording_file_name(timestamp: i64) -> String {
-    format!("uttr-{}-{}.wav", timestamp, Uuid::new_v4())
-}
+        manager
+            .set_encryption(true)
+            .await
+            .expect("enable encryption");
+        let database = fs::read(root.path().join("history.db")).expect("read database");
+        assert!(!database
+            .windows(b"confidential".len())
+            .any(|window| window == b"confidential"));
+        assert!(!fs::read(&recording).unwrap().starts_with(b"RIFF"));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rusqlite::{params, Connection};
+        let decrypted = manager.get_entry_by_id(id).await.unwrap().unwrap();
+        assert_eq!(decrypted.transcription_text, "confidential roadmap review");
+        assert_eq!(decrypted.note.as_deref(), Some("confidential note"));
+        assert_eq!(
+            manager.get_timestamps(id).await.unwrap().segments[0].text,
+            "confidential roadmap review"
+        );
+        let results = manager
+            .search_entries("Roadmap rev", &HistorySearchFilters::default(), None)
+            .await
+            .expect("search encrypted history");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].snippet,
+            format!(
+                "confidential {SEARCH_MATCH_START}roadmap{SEARCH_MATCH_END} {SEARCH_MATCH_START}review{SEARCH_MATCH_END}"
+            )
+        );
+        let playable = manager
+            .get_playable_audio_path(&entry.file_name)
+            .expect("decrypt for playback");
+        let playable_path = PathBuf::from(&playable.path);
+        assert!(playable.temporary);
+        assert_ne!(playable_path, recording);
+        assert!(fs::read(&playable_path).unwrap().starts_with(b"RIFF"));
+        manager
+            .release_playable_audio(&playable_path)
+            .expect("release playback copy");
+        assert!(!playable_path.exists());
+        assert!(manager.release_playable_audio(&recording).is_err());
 
-    fn setup_conn() -> Connection {
-        let conn = Connection::open_in_memory().expect("open in-memory db");
-        conn.execute_batch(
-            "CREATE TABLE transcription_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                file_name TEXT NOT NULL,
-                timestamp INTEGER NOT NULL,
-                saved BOOLEAN NOT NULL DEFAULT 0,
-                title TEXT NOT NULL,
-                transcription_text TEXT NOT NULL,
-                post_processed_text TEXT,
-                post_process_prompt TEXT,
-                recording_source TEXT NOT NULL DEFAULT 'dictation'
-            );",
+        let sealed_id = manager
+            .save_transcription(
+                vec![0.05; 1_600],
+                "sealed on save".to_string(),
+                None,
+                None,
+                "dictation",
+            )
+            .await
+            .expect("save encrypted entry");
+        let sealed = manager.get_entry_by_id(sealed_id).await.unwrap().unwrap();
+        let sealed_recording = manager.get_audio_file_path(&sealed.file_name);
+        assert!(!fs::read(&sealed_recording).unwrap().starts_with(b"RIFF"));
+
+        manager
+            .set_encryption(false)
+            .await
+            .expect("disable encryption");
+        assert!(fs::read(&recording).unwrap().starts_with(b"RIFF"));
+        assert!(fs::read(&sealed_recording).unwrap().starts_with(b"RIFF"));
+        let raw_text: String = manager
+            .get_connection()
+            .unwrap()
+            .query_row(
+                "SELECT transcription_text FROM transcription_history WHERE id = ?1",
+                params![sealed_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(raw_text, "sealed on save");
+        let results = manager
+            .search_entries("roadmap", &HistorySearchFilters::default(), None)
+            .await
+            .expect("search plaintext history");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry.id, id);
+    }
+
+    #[tokio::test]
+    async fn vault_mirror_follows_history_changes() {
+        let root = tempfile::tempdir().expect("create history root");
+        let vault = tempfile::tempdir().expect("create vault");
+        let manager = HistoryManager::new_for_test(root.path()).expect("create history manager");
+        let kept = manager
+            .save_transcription(vec![0.0; 160], "kept".to_string(), None, None, "dictation")
+            .await
+            .expect("save entry");
+        let removed = manager
+            .save_transcription(
+                vec![0.0; 160],
+                "removed".to_string(),
+                None,
+                None,
+                "dictation",
+            )
+            .await
+            .expect("save entry");
+        let note_names = || -> Vec<String> {
+            let mut names: Vec<String> = fs::read_dir(vault.path())
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert!(manager
+            .set_vault_dir(Some(root.path().join("missing")))
+            .await
+            .is_err());
+        manager
+            .set_vault_dir(Some(vault.path().to_path_buf()))
+            .await
+            .expect("enable vault");
+        assert_eq!(note_names().len(), 2);
+
+        manager
+            .update_entry(
+                kept,
+                HistoryEntryUpdate {
+                    title: Some("Roadmap".to_string()),
+                    ..HistoryEntryUpdate::default()
+                },
+            )
+            .await
+            .expect("retitle entry");
+        manager
+            .set_tags(kept, &["planning".to_string()])
+            .await
+            .expect("tag entry");
+        manager
+            .save_transcription_route(kept, "local", None, Some("parakeet-tdt-0.6b-v3"))
+            .expect("save route");
+        manager.delete_entry(removed).await.expect("delete entry");
+
+        let install_id = history_vault::load_or_create_install_id(root.path()).unwrap();
+        assert_eq!(
+            note_names(),
+            vec![format!("Roadmap (uttr-{install_id}-{kept}).md")]
+        );
+        let note = fs::read_to_string(vault.path().join(&note_names()[0])).unwrap();
+        assert!(note.contains("model: \"parakeet-tdt-0.6b-v3\"\n"));
+        assert!(note.contains("tags:\n  - \"planning\"\n"));
+
+        manager.set_vault_dir(None).await.expect("disable vault");
+        manager.delete_entry(kept).await.expect("delete entry");
+        assert_eq!(note_names().len(), 1);
+    }
+
+    #[test]
+    fn edits_keep_revisions_that_can_be_restored() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "meat at noon", Some("Meat at noon."));
+
+        let edited = update_entry_with_conn(
+            &conn,
+            1,
+            &HistoryEntryUpdate {
+                post_processed_text: Some("Meet at noon.".to_string()),
+                ..Default::default()
+            },
         )
-        .expect("create transcription_history table");
-        conn
+        .unwrap();
+        assert_eq!(edited.post_processed_text.as_deref(), Some("Meet at noon."));
+        assert_eq!(edited.transcription_text, "meat at noon");
+
+        // Saving unchanged text does not add a revision.
+        update_entry_with_conn(
+            &conn,
+            1,
+            &HistoryEntryUpdate {
+                post_processed_text: Some("Meet at noon.".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let revisions = get_revisions_with_conn(&conn, 1).unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(
+            revisions[0].post_processed_text.as_deref(),
+            Some("Meat at noon.")
+        );
+        assert_eq!(
+            search_entries_with_conn(&conn, "meet", &HistorySearchFilters::default(), None)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let reverted = revert_entry_with_conn(&conn, 1, revisions[0].id).unwrap();
+        assert_eq!(
+            reverted.post_processed_text.as_deref(),
+            Some("Meat at noon.")
+        );
+        let revisions = get_revisions_with_conn(&conn, 1).unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(
+            revisions[0].post_processed_text.as_deref(),
+            Some("Meet at noon.")
+        );
+
+        assert!(update_entry_with_conn(
+            &conn,
+            1,
+            &HistoryEntryUpdate {
+                transcription_text: Some("  ".to_string()),
+                ..Default::default()
+            },
+        )
+        .is_err());
+        assert!(revert_entry_with_conn(&conn, 2, revisions[0].id).is_err());
+
+        conn.execute("DELETE FROM transcription_history WHERE id = 1", [])
+            .unwrap();
+        assert!(get_revisions_with_conn(&conn, 1).unwrap().is_empty());
     }
 
-    fn insert_entry(conn: &Connection, timestamp: i64, text: &str, post_processed: Option<&str>) {
-        insert_entry_with_source(conn, timestamp, text, post_processed, false, "dictation");
+    #[test]
+    fn alternates_can_replace_the_entry_text() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "ship the bills", Some("Ship the bills."));
+        let alternate = add_alternate_with_conn(
+            &conn,
+            1,
+            &NewHistoryAlternate {
+                transcription_model: Some("large-v3".to_string()),
+                language: Some("en".to_string()),
+                transcription_route: Some("local".to_string()),
+                transcription_text: "ship the builds".to_string(),
+                post_processed_text: None,
+            },
+        )
+        .unwrap();
+        assert!(add_alternate_with_conn(&conn, 2, &NewHistoryAlternate::default()).is_err());
+        assert_eq!(
+            get_alternates_with_conn(&conn, 1).unwrap(),
+            vec![alternate.clone()]
+        );
+
+        let entry = apply_alternate_with_conn(&conn, 1, alternate.id).unwrap();
+        assert_eq!(entry.transcription_text, "ship the builds");
+        assert_eq!(entry.post_processed_text, None);
+        assert_eq!(entry.transcription_model.as_deref(), Some("large-v3"));
+        let stored = get_entry_with_conn(&conn, 1).unwrap().unwrap();
+        assert_eq!(stored.transcription_text, "ship the builds");
+        assert_eq!(stored.transcription_route.as_deref(), Some("local"));
+        assert_eq!(stored.transcription_model.as_deref(), Some("large-v3"));
+        assert!(get_alternates_with_conn(&conn, 1).unwrap().is_empty());
+        let revisions = get_revisions_with_conn(&conn, 1).unwrap();
+        assert_eq!(revisions[0].transcription_text, "ship the bills");
+        assert!(apply_alternate_with_conn(&conn, 1, alternate.id).is_err());
+
+        add_alternate_with_conn(
+            &conn,
+            1,
+            &NewHistoryAlternate {
+                transcription_text: "ship the bills".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        conn.execute("DELETE FROM transcription_history WHERE id = 1", [])
+            .unwrap();
+        assert!(get_alternates_with_conn(&conn, 1).unwrap().is_empty());
     }
 
-    fn insert_entry_with_source(
-        conn: &Connection,
-        timestamp: i64,
-        text: &str,
-        post_processed: Option<&str>,
-        saved: bool,
-        recording_source: &str,
-    ) {
-        conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, recording_source)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![
-                format!("uttr-{}.wav", timestamp),
-                timestamp,
-                saved,
-                format!("Recording {}", timestamp),
-                text,
-                post_processed,
-                Option::<String>::None,
-                recording_source
-            ],
+    #[test]
+    fn alternates_do_not_replace_meeting_transcripts() {
+        let conn = setup_conn();
+        insert_entry_with_source(
+            &conn,
+            100,
+            "Me: ship it\nThem: sounds good",
+            Some("## Decisions\n\n- Ship it"),
+            true,
+            "full_system_audio",
+        );
+        let alternate = add_alternate_with_conn(
+            &conn,
+            1,
+            &NewHistoryAlternate {
+                transcription_text: "ship it sounds good".to_string(),
+                ..Default::default()
+            },
         )
-        .expect("insert history entry");
+        .unwrap();
+
+        assert!(apply_alternate_with_conn(&conn, 1, alternate.id).is_err());
+        let stored = get_entry_with_conn(&conn, 1).unwrap().unwrap();
+        assert_eq!(stored.transcription_text, "Me: ship it\nThem: sounds good");
+        assert_eq!(
+            stored.post_processed_text.as_deref(),
+            Some("## Decisions\n\n- Ship it")
+        );
+        assert!(get_revisions_with_conn(&conn, 1).unwrap().is_empty());
     }
 
     #[test]
-    fn migrations_create_history_query_indexes() {
-        let mut conn = Connection::open_in_memory().expect("open in-memory db");
-        let migrations = Migrations::new(MIGRATIONS.to_vec());
-        migrations.validate().expect("validate migrations");
-        migrations.to_latest(&mut conn).expect("run migrations");
+    fn saved_file_transcriptions_move_into_history_in_order() {
+        let conn = setup_conn();
+        let saved = (0..3)
+            .map(|index| SavedFileTranscription {
+                file_name: format!("interview-{}.m4a", index),
+                transcription_text: format!("transcript {}", index),
+                post_processed_text: (index == 0).then(|| "Transcript 0.".to_string()),
+                source_path: Some(format!("/tmp/interview-{}.m4a", index)),
+            })
+            .collect::<Vec<_>>();
 
-        let timestamp_index_exists: bool = conn
-            .query_row(
-                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'index' AND name = 'transcription_history_timestamp_idx'",
-                [],
-                |row| row.get(0),
-            )
-            .expect("query timestamp index");
-        let cleanup_index_exists: bool = conn
-            .query_row(
-                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'index' AND name = 'transcription_history_unsaved_dictation_timestamp_idx'",
-                [],
-                |row| row.get(0),
-            )
-            .expect("query cleanup index");
+        assert_eq!(
+            import_saved_file_transcriptions_with_conn(&conn, &saved, 1_000).unwrap(),
+            3
+        );
 
-        assert!(timestamp_index_exists);
-        assert!(cleanup_index_exists);
+        let page = get_history_page_with_conn(
+            &conn,
+            None,
+            None,
+            &HistoryPageFilter {
+                source: HistorySourceFilter::FileImports,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let names: Vec<_> = page
+            .entries
+            .iter()
+            .map(|entry| entry.title.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["interview-0.m4a", "interview-1.m4a", "interview-2.m4a"]
+        );
+        let latest = &page.entries[0];
+        assert_eq!(latest.recording_source, FILE_IMPORT_SOURCE);
+        assert_eq!(latest.source_path.as_deref(), Some("/tmp/interview-0.m4a"));
+        assert_eq!(latest.post_processed_text.as_deref(), Some("Transcript 0."));
+        assert!(latest.audio_purged);
+        assert_ne!(latest.file_name, "interview-0.m4a");
+
+        insert_entry(&conn, 2_000, "a dictation", None);
+        let dictations = get_history_page_with_conn(
+            &conn,
+            None,
+            None,
+            &HistoryPageFilter {
+                source: HistorySourceFilter::Dictations,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(dictations.entries.len(), 4);
+    }
+
+    #[test]
+    fn reprocessing_keeps_the_previous_output_as_a_revision() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "so um email bob", Some("Email Bob."));
+        conn.execute(
+            "UPDATE transcription_history SET post_process_prompt = 'Clean up.' WHERE id = 1",
+            [],
+        )
+        .unwrap();
+
+        let entry =
+            reprocess_entry_with_conn(&conn, 1, "Hi Bob,", Some("Write an email.")).unwrap();
+        assert_eq!(entry.transcription_text, "so um email bob");
+        assert_eq!(entry.post_processed_text.as_deref(), Some("Hi Bob,"));
+        assert_eq!(
+            entry.post_process_prompt.as_deref(),
+            Some("Write an email.")
+        );
+        let stored = get_entry_with_conn(&conn, 1).unwrap().unwrap();
+        assert_eq!(stored.post_processed_text.as_deref(), Some("Hi Bob,"));
+        assert_eq!(
+            stored.post_process_prompt.as_deref(),
+            Some("Write an email.")
+        );
+        let revisions = get_revisions_with_conn(&conn, 1).unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(
+            revisions[0].post_processed_text.as_deref(),
+            Some("Email Bob.")
+        );
+        assert_eq!(
+            revisions[0].post_process_prompt.as_deref(),
+            Some("Clean up.")
+        );
+
+        let reverted = revert_entry_with_conn(&conn, 1, revisions[0].id).unwrap();
+        assert_eq!(reverted.post_processed_text.as_deref(), Some("Email Bob."));
+        assert_eq!(reverted.post_process_prompt.as_deref(), Some("Clean up."));
+        let stored = get_entry_with_conn(&conn, 1).unwrap().unwrap();
+        assert_eq!(stored.post_process_prompt.as_deref(), Some("Clean up."));
+
+        assert!(reprocess_entry_with_conn(&conn, 1, "  ", None).is_err());
+        assert!(reprocess_entry_with_conn(&conn, 2, "Hi Bob,", None).is_err());
     }
 
     #[test]
@@ -847,9 +4181,105 @@ mod tests {
         insert_entry_with_source(&conn, 300, "new dictation", None, false, "dictation");
 
         let entries_to_delete =
-            HistoryManager::time_cleanup_candidates(&conn, 200).expect("fetch cleanup candidates");
+            HistoryManager::time_cleanup_candidates(&conn, RetentionTarget::Dictations, 200)
+                .expect("fetch cleanup candidates");
 
         assert_eq!(entries_to_delete.len(), 1);
         assert_eq!(entries_to_delete[0].1, "uttr-100.wav");
     }
+
+    #[tokio::test]
+    async fn retention_applies_per_source_and_can_keep_text() {
+        use crate::settings::RecordingRetentionPeriod;
+
+        const DAY: i64 = 24 * 60 * 60;
+        let root = tempfile::tempdir().expect("create history root");
+        let manager = HistoryManager::new_for_test(root.path()).expect("create history manager");
+        let conn = manager.get_connection().expect("open history db");
+        insert_entry_with_source(&conn, 10 * DAY, "old dictation", None, false, "dictation");
+        insert_entry_with_source(
+            &conn,
+            20 * DAY,
+            "old meeting",
+            None,
+            false,
+            "full_system_audio",
+        );
+        insert_entry_with_source(
+            &conn,
+            5 * DAY,
+            "kept meeting",
+            None,
+            true,
+            "full_system_audio",
+        );
+        insert_entry_with_source(
+            &conn,
+            300 * DAY,
+            "quiet meeting",
+            None,
+            false,
+            "full_system_audio",
+        );
+        insert_entry_with_source(
+            &conn,
+            390 * DAY,
+            "new meeting",
+            None,
+            false,
+            "full_system_audio",
+        );
+        for timestamp in [10, 20, 5, 300, 390] {
+            fs::write(
+                manager.get_audio_file_path(&format!("uttr-{}.wav", timestamp * DAY)),
+                b"wav",
+            )
+            .expect("write recording");
+        }
+        let policy = RetentionPolicy {
+            dictations: RecordingRetentionPeriod::Never,
+            history_limit: 1,
+            meetings: RecordingRetentionPeriod::Years1,
+            dictation_audio: RecordingRetentionPeriod::Weeks2,
+            meeting_audio: RecordingRetentionPeriod::Months3,
+        };
+
+        manager
+            .apply_retention(&policy, 400 * DAY)
+            .expect("apply retention");
+
+        let entries = manager.get_history_entries().await.expect("list entries");
+        let state: Vec<(&str, bool, bool)> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.transcription_text.as_str(),
+                    entry.audio_purged,
+                    manager.get_audio_file_path(&entry.file_name).exists(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            state,
+            vec![
+                ("new meeting", false, true),
+                ("quiet meeting", true, false),
+                ("old dictation", true, false),
+                ("kept meeting", false, true),
+            ]
+        );
+        assert!(!manager
+            .get_audio_file_path(&format!("uttr-{}.wav", 20 * DAY))
+            .exists());
+
+        // Purged entries are not picked up again.
+        assert!(HistoryManager::time_cleanup_candidates(
+            &conn,
+            RetentionTarget::MeetingAudio,
+            400 * DAY
+        )
+        .expect("fetch cleanup candidates")
+        .iter()
+        .all(|(_, file_name)| file_name != &format!("uttr-{}.wav", 300 * DAY)));
+    }
 }
@@ -0,0 +1,267 @@
+//! Zip bundles for backing up history or handing it to someone else: a JSON
+//! manifest, a Markdown file per entry and the recordings they reference.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::history::{
+    is_safe_recording_file_name, HistoryEntry, TranscriptTimestamps, FILE_IMPORT_SOURCE,
+};
+use super::history_crypto::HistoryEncryption;
+
+const MANIFEST_PATH: &str = "manifest.json";
+const BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub version: u32,
+    pub exported_at: i64,
+    pub entries: Vec<BundleEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// `entry.id` and `entry.folder_id` are ids on the exporting machine.
+    /// Imports always assign fresh ids and place the entry by `folder` name.
+    pub entry: HistoryEntry,
+    #[serde(default)]
+    pub folder: Option<String>,
+    #[serde(default)]
+    pub timestamps: TranscriptTimestamps,
+    pub markdown_path: String,
+    /// Location of the recording inside the bundle, or `None` when it had
+    /// already been removed by retention.
+    pub audio_path: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryExportSummary {
+    pub entries: u32,
+    pub recordings: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HistoryImportSummary {
+    pub imported: u32,
+    /// Entries that were already in history, matched by time, source and
+    /// transcript.
+    pub skipped: u32,
+}
+
+fn bundle_stem(entry: &HistoryEntry) -> String {
+    let date = DateTime::from_timestamp(entry.timestamp, 0)
+        .map(|datetime| {
+            datetime
+                .with_timezone(&Local)
+                .format("%Y-%m-%d-%H%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "entry".to_string());
+    format!("{date}-{}", entry.id)
+}
+
+/// Renders an entry for people reading the bundle without the app. The
+/// cleaned-up text comes first, followed by the raw transcript if it differs.
+pub fn entry_markdown(
+    entry: &HistoryEntry,
+    folder: Option<&str>,
+    audio_path: Option<&str>,
+) -> String {
+    let mut markdown = format!("# {}\n\n", entry.title);
+    let source = match entry.recording_source.as_str() {
+        "full_system_audio" => "Meeting",
+        FILE_IMPORT_SOURCE => "File import",
+        _ => "Dictation",
+    };
+    markdown.push_str(&format!("- Source: {source}\n"));
+    if let Some(datetime) = DateTime::from_timestamp(entry.timestamp, 0) {
+        markdown.push_str(&format!(
+            "- Recorded: {}\n",
+            datetime.with_timezone(&Local).to_rfc3339()
+        ));
+    }
+    if let Some(folder) = folder {
+        markdown.push_str(&format!("- Folder: {folder}\n"));
+    }
+    if !entry.tags.is_empty() {
+        markdown.push_str(&format!("- Tags: {}\n", entry.tags.join(", ")));
+    }
+    if let Some(audio_path) = audio_path {
+        markdown.push_str(&format!("- Audio: [{audio_path}](../{audio_path})\n"));
+    }
+    markdown.push('\n');
+    if let Some(note) = &entry.note {
+        for line in note.lines() {
+            markdown.push_str(&format!("> {line}\n"));
+        }
+        markdown.push('\n');
+    }
+
+    let transcript = entry.transcription_text.trim();
+    match entry
+        .post_processed_text
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty() && *text != transcript)
+    {
+        Some(text) => {
+            markdown.push_str(text);
+            markdown.push_str("\n\n## Original transcript\n\n");
+            markdown.push_str(transcript);
+        }
+        None => markdown.push_str(transcript),
+    }
+    markdown.push('\n');
+    markdown
+}
+
+/// Writes `entries` and any of their recordings still in `recordings_dir`.
+/// `folder_names` maps folder ids to names. Encrypted recordings are
+/// exported decrypted.
+pub fn write_bundle<W: Write + Seek>(
+    writer: W,
+    entries: Vec<(HistoryEntry, TranscriptTimestamps)>,
+    folder_names: &HashMap<i64, String>,
+    recordings_dir: &Path,
+    encryption: &HistoryEncryption,
+) -> Result<HistoryExportSummary> {
+    let mut zip = ZipWriter::new(writer);
+    let text_options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    // WAV data barely compresses, so it is stored as-is.
+    let audio_options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Stored)
+        .large_file(true);
+
+    let mut summary = HistoryExportSummary::default();
+    let mut manifest = BundleManifest {
+        version: BUNDLE_VERSION,
+        exported_at: Utc::now().timestamp(),
+        entries: Vec::with_capacity(entries.len()),
+    };
+
+    for (entry, timestamps) in entries {
+        let stem = bundle_stem(&entry);
+        let recording_path = recordings_dir.join(&entry.file_name);
+        let audio_path = (is_safe_recording_file_name(&entry.file_name)
+            && recording_path.is_file())
+        .then(|| format!("audio/{stem}.wav"));
+
+        if let Some(audio_path) = &audio_path {
+            zip.start_file(audio_path.as_str(), audio_options)?;
+            encryption.read_file_into(&recording_path, &mut zip)?;
+            summary.recordings += 1;
+        }
+
+        let folder = entry
+            .folder_id
+            .and_then(|id| folder_names.get(&id))
+            .cloned();
+        let markdown_path = format!("entries/{stem}.md");
+        zip.start_file(markdown_path.as_str(), text_options)?;
+        zip.write_all(entry_markdown(&entry, folder.as_deref(), audio_path.as_deref()).as_bytes())?;
+
+        manifest.entries.push(BundleEntry {
+            entry,
+            folder,
+            timestamps,
+            markdown_path,
+            audio_path,
+        });
+        summary.entries += 1;
+    }
+
+    zip.start_file(MANIFEST_PATH, text_options)?;
+    serde_json::to_writer_pretty(&mut zip, &manifest)?;
+    zip.finish()?;
+    Ok(summary)
+}
+
+pub fn read_manifest<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<BundleManifest> {
+    let manifest: BundleManifest = serde_json::from_reader(
+        archive
+            .by_name(MANIFEST_PATH)
+            .context("Not a history bundle: manifest.json is missing")?,
+    )
+    .context("Failed to read history bundle manifest")?;
+    if manifest.version > BUNDLE_VERSION {
+        bail!(
+            "History bundle version {} was made by a newer version of the app",
+            manifest.version
+        );
+    }
+    Ok(manifest)
+}
+
+/// Writes a bundled recording to `destination`, encrypted when history is.
+pub fn extract_audio<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    audio_path: &str,
+    destination: &Path,
+    encryption: &HistoryEncryption,
+) -> Result<()> {
+    let mut source = archive
+        .by_name(audio_path)
+        .with_context(|| format!("History bundle is missing {audio_path}"))?;
+    encryption.write_file_from(destination, &mut source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(post_processed_text: Option<&str>) -> HistoryEntry {
+        HistoryEntry {
+            id: 7,
+            file_name: "uttr-100.wav".to_string(),
+            timestamp: 100,
+            saved: false,
+            audio_purged: false,
+            title: "Standup".to_string(),
+            transcription_text: "um ship the release friday".to_string(),
+            post_processed_text: post_processed_text.map(str::to_string),
+            post_process_prompt: None,
+            recording_source: "full_system_audio".to_string(),
+            source_path: None,
+            transcription_route: None,
+            fallback_from: None,
+            transcription_model: None,
+            note: None,
+            folder_id: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn markdown_leads_with_cleaned_text_and_keeps_original() {
+        let mut organized = entry(Some("Ship the release Friday."));
+        organized.tags = vec!["release".to_string(), "team".to_string()];
+        organized.note = Some("Follow up with QA".to_string());
+        let markdown = entry_markdown(&organized, Some("Acme"), Some("audio/standup.wav"));
+
+        assert!(markdown.starts_with("# Standup\n\n- Source: Meeting\n"));
+        assert!(markdown.contains(
+            "- Folder: Acme\n- Tags: release, team\n- Audio: [audio/standup.wav](../audio/standup.wav)\n\n> Follow up with QA\n\n"
+        ));
+        assert!(markdown.ends_with(
+            "Ship the release Friday.\n\n## Original transcript\n\num ship the release friday\n"
+        ));
+
+        let markdown = entry_markdown(&entry(None), None, None);
+        assert!(!markdown.contains("## Original transcript"));
+        assert!(!markdown.contains("- Audio:"));
+        assert!(markdown.ends_with("\n\num ship the release friday\n"));
+
+        let mut imported = entry(None);
+        imported.recording_source = FILE_IMPORT_SOURCE.to_string();
+        assert!(entry_markdown(&imported, None, None).contains("- Source: File import\n"));
+        imported.recording_source = "dictation".to_string();
+        assert!(entry_markdown(&imported, None, None).contains("- Source: Dictation\n"));
+    }
+}
@@ -0,0 +1,379 @@
+//! Opt-in encryption at rest for history. Transcript text and recordings are
+//! sealed with AES-256-GCM under the app data key that already protects
+//! stored API keys, so a copied database, backup or synced folder is
+//! unreadable without `byok_secrets.key`. Tags, folder names, timestamps and
+//! sources stay in plaintext so lists can still be filtered and counted in SQL,
+//! as do usage stats, which hold word counts and app names but no text.
+//!
+//! Sealed values carry a marker, which lets plaintext and encrypted data
+//! coexist and lets history be converted in either direction. Recordings are
+//! sealed in fixed-size chunks, so converting or playing one never holds the
+//! whole file in memory.
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use once_cell::sync::OnceCell;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::Connection;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::byok_secrets::load_or_create_store_key_in_dir;
+
+const TEXT_PREFIX: &str = "uttr-enc:v1:";
+/// Recordings are WAVs, which start with `RIFF`, so the header can't clash.
+const FILE_MAGIC: &[u8] = b"UTTRENC1";
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+/// Plaintext bytes per sealed file chunk. Each chunk is stored as a
+/// little-endian `u32` length, whose top bit marks the last chunk, followed by
+/// the nonce and ciphertext.
+const FILE_CHUNK_LEN: usize = 1 << 20;
+const LAST_CHUNK_FLAG: u32 = 1 << 31;
+
+pub struct HistoryEncryption {
+    key_dir: PathBuf,
+    enabled: AtomicBool,
+    cipher: OnceCell<Aes256Gcm>,
+}
+
+impl HistoryEncryption {
+    /// The key in `key_dir` is only read, or created, once something needs
+    /// to be sealed or opened.
+    pub fn new(key_dir: PathBuf, enabled: bool) -> Self {
+        Self {
+            key_dir,
+            enabled: AtomicBool::new(enabled),
+            cipher: OnceCell::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    fn cipher(&self) -> Result<&Aes256Gcm> {
+        self.cipher.get_or_try_init(|| {
+            let key = load_or_create_store_key_in_dir(&self.key_dir).map_err(|e| anyhow!(e))?;
+            Aes256Gcm::new_from_slice(&key)
+                .map_err(|e| anyhow!("Failed to initialize history cipher: {}", e))
+        })
+    }
+
+    /// Returns the nonce followed by the ciphertext. `aad` is authenticated
+    /// but not stored.
+    #[allow(deprecated)]
+    fn seal(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher()?
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|e| anyhow!("Failed to encrypt history data: {}", e))?;
+
+        let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    #[allow(deprecated)]
+    fn open(&self, sealed: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if sealed.len() < NONCE_LEN {
+            bail!("Encrypted history data is truncated");
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        self.cipher()?
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| anyhow!("Failed to decrypt history data; the key may have changed"))
+    }
+
+    /// `text` as it should be stored in the current mode: sealed when
+    /// encryption is on and plaintext when it is off.
+    pub fn encode_text(&self, text: &str) -> Result<String> {
+        match (self.is_enabled(), text.starts_with(TEXT_PREFIX)) {
+            (true, false) => Ok(format!(
+                "{TEXT_PREFIX}{}",
+                BASE64.encode(self.seal(text.as_bytes(), &[])?)
+            )),
+            (false, true) => self.decode_text(text),
+            _ => Ok(text.to_string()),
+        }
+    }
+
+    /// Opens sealed text. Plaintext is returned unchanged.
+    pub fn decode_text(&self, text: &str) -> Result<String> {
+        let Some(encoded) = text.strip_prefix(TEXT_PREFIX) else {
+            return Ok(text.to_string());
+        };
+        let sealed = BASE64
+            .decode(encoded)
+            .context("Failed to decode encrypted history text")?;
+        String::from_utf8(self.open(&sealed, &[])?).context("Decrypted history text is not UTF-8")
+    }
+
+    pub fn is_encrypted_file(path: &Path) -> Result<bool> {
+        let mut header = [0u8; FILE_MAGIC.len()];
+        let mut file = File::open(path)?;
+        match file.read_exact(&mut header) {
+            Ok(()) => Ok(header == FILE_MAGIC),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Seals everything `reader` yields into `writer` as a chunked file.
+    fn seal_stream<R: Read, W: Write>(&self, reader: &mut R, writer: &mut W) -> Result<()> {
+        writer.write_all(FILE_MAGIC)?;
+        let mut chunk = read_chunk(reader)?;
+        for index in 0u64.. {
+            // A short chunk can only be the last; a full one needs a look ahead.
+            let next = if chunk.len() == FILE_CHUNK_LEN {
+                read_chunk(reader)?
+            } else {
+                Vec::new()
+            };
+            let last = next.is_empty();
+            let sealed = self.seal(&chunk, &chunk_aad(index, last))?;
+            let mut header = sealed.len() as u32;
+            if last {
+                header |= LAST_CHUNK_FLAG;
+            }
+            writer.write_all(&header.to_le_bytes())?;
+            writer.write_all(&sealed)?;
+            if last {
+                break;
+            }
+            chunk = next;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Opens a chunked file from `reader`, positioned just after the magic,
+    /// into `writer`. Fails on reordered, truncated or extended files.
+    fn open_stream<R: Read, W: Write>(&self, reader: &mut R, writer: &mut W) -> Result<()> {
+        for index in 0u64.. {
+            let mut header = [0u8; 4];
+            reader
+                .read_exact(&mut header)
+                .context("Encrypted recording is truncated")?;
+            let header = u32::from_le_bytes(header);
+            let last = header & LAST_CHUNK_FLAG != 0;
+            let len = (header & !LAST_CHUNK_FLAG) as usize;
+            if len > NONCE_LEN + FILE_CHUNK_LEN + TAG_LEN {
+                bail!("Encrypted recording is corrupted");
+            }
+
+            let mut sealed = vec![0u8; len];
+            reader
+                .read_exact(&mut sealed)
+                .context("Encrypted recording is truncated")?;
+            writer.write_all(&self.open(&sealed, &chunk_aad(index, last))?)?;
+            if last {
+                break;
+            }
+        }
+        if reader.read(&mut [0u8; 1])? != 0 {
+            bail!("Encrypted recording has trailing data");
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes a new file, sealed when encryption is on.
+    pub fn write_file(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        self.write_file_from(path, &mut &bytes[..])
+    }
+
+    /// Like [`Self::write_file`], streaming from `reader`.
+    pub fn write_file_from<R: Read>(&self, path: &Path, reader: &mut R) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        if self.is_enabled() {
+            self.seal_stream(reader, &mut writer)
+        } else {
+            io::copy(reader, &mut writer)?;
+            writer.flush()?;
+            Ok(())
+        }
+    }
+
+    /// Copies a file's plaintext into `writer`, whichever mode wrote it.
+    pub fn read_file_into<W: Write>(&self, path: &Path, writer: &mut W) -> Result<()> {
+        let mut reader = BufReader::new(File::open(path)?);
+        if Self::is_encrypted_file(path)? {
+            reader.read_exact(&mut [0u8; FILE_MAGIC.len()])?;
+            self.open_stream(&mut reader, writer)
+        } else {
+            io::copy(&mut reader, writer)?;
+            Ok(())
+        }
+    }
+
+    /// Rewrites a file to match the current mode. Returns whether it changed.
+    /// The new contents are renamed over the old, so an interrupted run
+    /// never leaves a half-written recording.
+    pub fn convert_file(&self, path: &Path) -> Result<bool> {
+        if Self::is_encrypted_file(path)? == self.is_enabled() {
+            return Ok(false);
+        }
+
+        let temp_path = path.with_extension("converting");
+        let result = if self.is_enabled() {
+            self.write_file_from(&temp_path, &mut BufReader::new(File::open(path)?))
+        } else {
+            File::create(&temp_path)
+                .map_err(Into::into)
+                .and_then(|file| self.read_file_into(path, &mut BufWriter::new(file)))
+        };
+        if let Err(e) = result {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+        fs::rename(&temp_path, path)?;
+        Ok(true)
+    }
+}
+
+/// Binds a chunk to its position and to whether it ends the file.
+fn chunk_aad(index: u64, last: bool) -> [u8; 9] {
+    let mut aad = [0u8; 9];
+    aad[..8].copy_from_slice(&index.to_le_bytes());
+    aad[8] = u8::from(last);
+    aad
+}
+
+/// Reads up to [`FILE_CHUNK_LEN`] bytes, stopping early only at the end.
+fn read_chunk<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut chunk = Vec::with_capacity(FILE_CHUNK_LEN);
+    reader
+        .by_ref()
+        .take(FILE_CHUNK_LEN as u64)
+        .read_to_end(&mut chunk)?;
+    Ok(chunk)
+}
+
+/// Registers `history_encode(text)` and `history_decode(text)` on `conn`, so
+/// queries seal and open text columns without every caller handling the key.
+/// Both pass `NULL` through.
+pub fn register_sql_functions(
+    conn: &Connection,
+    encryption: Arc<HistoryEncryption>,
+) -> rusqlite::Result<()> {
+    let encoder = encryption.clone();
+    conn.create_scalar_function(
+        "history_encode",
+        1,
+        FunctionFlags::SQLITE_UTF8,
+        move |ctx| {
+            ctx.get::<Option<String>>(0)?
+                .map(|text| encoder.encode_text(&text))
+                .transpose()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )?;
+    conn.create_scalar_function(
+        "history_decode",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        move |ctx| {
+            ctx.get::<Option<String>>(0)?
+                .map(|text| encryption.decode_text(&text))
+                .transpose()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_and_files_convert_both_ways() {
+        let key_dir = tempfile::tempdir().expect("create key dir");
+        let encryption = HistoryEncryption::new(key_dir.path().to_path_buf(), true);
+
+        let sealed = encryption.encode_text("meeting notes").expect("seal text");
+        assert!(sealed.starts_with(TEXT_PREFIX));
+        assert!(!sealed.contains("meeting"));
+        assert_eq!(encryption.encode_text(&sealed).unwrap(), sealed);
+        assert_eq!(encryption.decode_text(&sealed).unwrap(), "meeting notes");
+        assert_eq!(encryption.decode_text("plain").unwrap(), "plain");
+
+        let recording = key_dir.path().join("uttr-1.wav");
+        fs::write(&recording, b"RIFF audio").unwrap();
+        assert!(encryption.convert_file(&recording).unwrap());
+        assert!(!encryption.convert_file(&recording).unwrap());
+        assert!(HistoryEncryption::is_encrypted_file(&recording).unwrap());
+        let mut plaintext = Vec::new();
+        encryption
+            .read_file_into(&recording, &mut plaintext)
+            .unwrap();
+        assert_eq!(plaintext, b"RIFF audio");
+
+        encryption.set_enabled(false);
+        assert_eq!(encryption.encode_text(&sealed).unwrap(), "meeting notes");
+        assert!(encryption.convert_file(&recording).unwrap());
+        assert_eq!(fs::read(&recording).unwrap(), b"RIFF audio");
+
+        // A different key can't open what this one sealed.
+        let other_dir = tempfile::tempdir().expect("create other key dir");
+        let other = HistoryEncryption::new(other_dir.path().to_path_buf(), false);
+        assert!(other.decode_text(&sealed).is_err());
+    }
+
+    #[test]
+    fn chunked_files_round_trip_and_reject_truncation() {
+        let key_dir = tempfile::tempdir().expect("create key dir");
+        let encryption = HistoryEncryption::new(key_dir.path().to_path_buf(), true);
+        let recording = key_dir.path().join("uttr-2.wav");
+
+        for len in [0, FILE_CHUNK_LEN, 2 * FILE_CHUNK_LEN + 5] {
+            let audio: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            encryption.write_file(&recording, &audio).unwrap();
+            let mut plaintext = Vec::new();
+            encryption
+                .read_file_into(&recording, &mut plaintext)
+                .unwrap();
+            assert_eq!(plaintext, audio, "length {len}");
+        }
+
+        let sealed = fs::read(&recording).unwrap();
+        fs::write(&recording, &sealed[..sealed.len() - 1]).unwrap();
+        assert!(encryption
+            .read_file_into(&recording, &mut Vec::new())
+            .is_err());
+
+        // Dropping the last chunk entirely leaves a file that still parses
+        // up to a chunk boundary, which the last-chunk flag catches.
+        let last_chunk_len = NONCE_LEN + 5 + TAG_LEN + 4;
+        fs::write(&recording, &sealed[..sealed.len() - last_chunk_len]).unwrap();
+        assert!(encryption
+            .read_file_into(&recording, &mut Vec::new())
+            .is_err());
+    }
+}
@@ -0,0 +1,347 @@
+//! Usage statistics over history: how much was dictated, how fast, in which
+//! apps, and roughly how much typing it replaced. Counts live in
+//! `transcription_stats`, written when an entry is saved, so they survive
+//! retention cleanup and can be summed without decrypting any text.
+
+use anyhow::Result;
+use log::debug;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::{BufReader, Cursor};
+use std::path::Path;
+
+use super::history::is_safe_recording_file_name;
+use super::history_crypto::HistoryEncryption;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+
+/// A common estimate for typing on a keyboard.
+pub const DEFAULT_TYPING_WPM: u32 = 40;
+const MAX_STATS_APPS: u32 = 10;
+// Meetings and imported files record other people too, so they count as
+// usage but not towards speaking rate or typing saved.
+const DICTATION_SQL: &str = "recording_source NOT IN ('full_system_audio', 'file_import')";
+const RANGE_SQL: &str = "(?1 IS NULL OR timestamp >= ?1) AND (?2 IS NULL OR timestamp <= ?2)";
+const DAY_SQL: &str = "date(timestamp, 'unixepoch', 'localtime')";
+// The Monday on or before the entry's day.
+const WEEK_SQL: &str = "date(timestamp, 'unixepoch', 'localtime', 'weekday 0', '-6 days')";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Type)]
+pub struct HistoryStats {
+    pub entry_count: u32,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub audio_duration_ms: i64,
+    /// Speaking rate across dictations, or `None` before any audio was kept.
+    pub average_wpm: Option<f64>,
+    /// Time typing the dictated words would have taken at `typing_wpm`,
+    /// less the time spent saying them.
+    pub time_saved_ms: i64,
+    pub typing_wpm: u32,
+    /// Oldest first, in local time.
+    pub days: Vec<HistoryStatsPeriod>,
+    /// Oldest first, starting on Mondays.
+    pub weeks: Vec<HistoryStatsPeriod>,
+    /// Most used first.
+    pub sources: Vec<HistorySourceStats>,
+    /// Most used first. Only entries whose app was captured are counted.
+    pub apps: Vec<HistoryAppStats>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct HistoryStatsPeriod {
+    /// `YYYY-MM-DD` of the first day in the period.
+    pub start_date: String,
+    pub entry_count: u32,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub audio_duration_ms: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct HistorySourceStats {
+    pub recording_source: String,
+    pub entry_count: u32,
+    pub word_count: i64,
+    pub audio_duration_ms: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct HistoryAppStats {
+    pub app_name: String,
+    pub entry_count: u32,
+    pub word_count: i64,
+}
+
+pub fn audio_duration_ms(sample_count: usize) -> i64 {
+    (sample_count as i64 * 1000) / i64::from(WHISPER_SAMPLE_RATE)
+}
+
+/// Counts what was said, which is the transcript rather than any cleaned-up
+/// text or Ask Selection answer.
+pub fn record_stats_with_conn(
+    conn: &Connection,
+    history_id: i64,
+    timestamp: i64,
+    recording_source: &str,
+    transcription_text: &str,
+    audio_duration_ms: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO transcription_stats
+             (history_id, timestamp, recording_source, word_count, char_count, audio_duration_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            history_id,
+            timestamp,
+            recording_source,
+            transcription_text.split_whitespace().count() as i64,
+            transcription_text.chars().count() as i64,
+            audio_duration_ms
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn set_app_name_with_conn(conn: &Connection, history_id: i64, app_name: &str) -> Result<()> {
+    conn.execute(
+        "UPDATE transcription_stats SET app_name = ?1 WHERE history_id = ?2",
+        params![app_name.trim(), history_id],
+    )?;
+    Ok(())
+}
+
+/// Adds stats for entries saved before they were kept, or imported from a
+/// bundle, reading the audio length from their recordings. Returns how many
+/// entries were added.
+pub fn backfill_stats_with_conn(
+    conn: &Connection,
+    recordings_dir: &Path,
+    encryption: &HistoryEncryption,
+) -> Result<usize> {
+    let mut stmt = conn.prepare(
+        "SELECT id, timestamp, recording_source, file_name,
+                history_decode(transcription_text) AS transcription_text
+         FROM transcription_history
+         WHERE NOT EXISTS (
+             SELECT 1 FROM transcription_stats WHERE history_id = transcription_history.id
+         )",
+    )?;
+    let missing = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>("id")?,
+                row.get::<_, i64>("timestamp")?,
+                row.get::<_, String>("recording_source")?,
+                row.get::<_, String>("file_name")?,
+                row.get::<_, String>("transcription_text")?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Recordings are read before the transaction opens so it only covers the
+    // inserts, which then land in a single commit.
+    let durations: Vec<i64> = missing
+        .iter()
+        .map(|(_, _, _, file_name, _)| {
+            is_safe_recording_file_name(file_name)
+                .then(|| recording_duration_ms(&recordings_dir.join(file_name), encryption))
+                .flatten()
+                .unwrap_or(0)
+        })
+        .collect();
+    let tx = conn.unchecked_transaction()?;
+    for ((id, timestamp, recording_source, _, text), duration_ms) in missing.iter().zip(durations) {
+        record_stats_with_conn(&tx, *id, *timestamp, recording_source, text, duration_ms)?;
+    }
+    tx.commit()?;
+
+    if !missing.is_empty() {
+        debug!(
+            "Backfilled usage stats for {} history entries",
+            missing.len()
+        );
+    }
+    Ok(missing.len())
+}
+
+/// Length of a saved recording, or `None` when it is missing or unreadable.
+/// Plain WAVs only have their header read.
+fn recording_duration_ms(path: &Path, encryption: &HistoryEncryption) -> Option<i64> {
+    let (frames, sample_rate) = if HistoryEncryption::is_encrypted_file(path).ok()? {
+        let mut bytes = Vec::new();
+        encryption.read_file_into(path, &mut bytes).ok()?;
+        let reader = hound::WavReader::new(Cursor::new(bytes)).ok()?;
+        (reader.duration(), reader.spec().sample_rate)
+    } else {
+        let reader = hound::WavReader::new(BufReader::new(std::fs::File::open(path).ok()?)).ok()?;
+        (reader.duration(), reader.spec().sample_rate)
+    };
+    (sample_rate > 0).then(|| i64::from(frames) * 1000 / i64::from(sample_rate))
+}
+
+/// Totals for entries between `from` and `to` (inclusive Unix seconds),
+/// either of which may be open.
+pub fn get_stats_with_conn(
+    conn: &Connection,
+    from: Option<i64>,
+    to: Option<i64>,
+    typing_wpm: u32,
+) -> Result<HistoryStats> {
+    let typing_wpm = typing_wpm.max(1);
+    let periods = |bucket: &str| -> Result<Vec<HistoryStatsPeriod>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {bucket} AS start_date, COUNT(*), SUM(word_count), SUM(char_count),
+                    SUM(audio_duration_ms)
+             FROM transcription_stats
+             WHERE {RANGE_SQL}
+             GROUP BY start_date
+             ORDER BY start_date"
+        ))?;
+        let rows = stmt.query_map(params![from, to], |row| {
+            Ok(HistoryStatsPeriod {
+                start_date: row.get(0)?,
+                entry_count: row.get(1)?,
+                word_count: row.get(2)?,
+                char_count: row.get(3)?,
+                audio_duration_ms: row.get(4)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    };
+    let days = periods(DAY_SQL)?;
+    let weeks = periods(WEEK_SQL)?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT recording_source, COUNT(*), SUM(word_count), SUM(audio_duration_ms)
+         FROM transcription_stats
+         WHERE {RANGE_SQL}
+         GROUP BY recording_source
+         ORDER BY COUNT(*) DESC, recording_source"
+    ))?;
+    let sources = stmt
+        .query_map(params![from, to], |row| {
+            Ok(HistorySourceStats {
+                recording_source: row.get(0)?,
+                entry_count: row.get(1)?,
+                word_count: row.get(2)?,
+                audio_duration_ms: row.get(3)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT app_name, COUNT(*), SUM(word_count)
+         FROM transcription_stats
+         WHERE {RANGE_SQL} AND app_name IS NOT NULL AND app_name != ''
+         GROUP BY app_name
+         ORDER BY COUNT(*) DESC, app_name
+         LIMIT ?3"
+    ))?;
+    let apps = stmt
+        .query_map(params![from, to, MAX_STATS_APPS], |row| {
+            Ok(HistoryAppStats {
+                app_name: row.get(0)?,
+                entry_count: row.get(1)?,
+                word_count: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Only dictations with audio say anything about speaking rate, and each
+    // one saves whatever typing it beat, never a negative amount.
+    let (spoken_words, spoken_ms, time_saved_ms): (i64, i64, i64) = conn.query_row(
+        &format!(
+            "SELECT COALESCE(SUM(word_count), 0), COALESCE(SUM(audio_duration_ms), 0),
+                    COALESCE(SUM(MAX(word_count * 60000 / ?3 - audio_duration_ms, 0)), 0)
+             FROM transcription_stats
+             WHERE {RANGE_SQL} AND {DICTATION_SQL} AND audio_duration_ms > 0"
+        ),
+        params![from, to, typing_wpm],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+
+    Ok(HistoryStats {
+        entry_count: sources.iter().map(|source| source.entry_count).sum(),
+        word_count: sources.iter().map(|source| source.word_count).sum(),
+        char_count: days.iter().map(|day| day.char_count).sum(),
+        audio_duration_ms: sources.iter().map(|source| source.audio_duration_ms).sum(),
+        average_wpm: (spoken_ms > 0).then(|| spoken_words as f64 * 60_000.0 / spoken_ms as f64),
+        time_saved_ms,
+        typing_wpm,
+        days,
+        weeks,
+        sources,
+        apps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::history::HistoryManager;
+
+    const SECOND_OF_SAMPLES: usize = WHISPER_SAMPLE_RATE as usize;
+
+    #[tokio::test]
+    async fn stats_count_dictations_and_survive_deletes() {
+        let root = tempfile::tempdir().expect("create history root");
+        let manager = HistoryManager::new_for_test(root.path()).expect("create history manager");
+        // 10 words in 2 seconds is 300 wpm, and 15 seconds of typing at 40 wpm.
+        let dictation = manager
+            .save_transcription(
+                vec![0.0; SECOND_OF_SAMPLES * 2],
+                "one two three four five six seven eight nine ten".to_string(),
+                Some("Cleaned up text that is not counted".to_string()),
+                None,
+                "dictation",
+            )
+            .await
+            .expect("save dictation");
+        manager
+            .save_app_name(dictation, "Notes")
+            .expect("save app name");
+        manager
+            .save_transcription(
+                vec![0.0; SECOND_OF_SAMPLES * 60],
+                "Me: short meeting".to_string(),
+                None,
+                None,
+                "full_system_audio",
+            )
+            .await
+            .expect("save meeting");
+        manager.delete_entry(dictation).await.expect("delete entry");
+
+        let stats = manager
+            .get_stats(None, None, DEFAULT_TYPING_WPM)
+            .await
+            .expect("get stats");
+        assert_eq!(stats.entry_count, 2);
+        assert_eq!(stats.word_count, 13);
+        assert_eq!(stats.audio_duration_ms, 62_000);
+        assert_eq!(stats.average_wpm, Some(300.0));
+        assert_eq!(stats.time_saved_ms, 13_000);
+        assert_eq!(stats.sources[0].entry_count, 1);
+        assert_eq!(
+            stats.apps,
+            vec![HistoryAppStats {
+                app_name: "Notes".to_string(),
+                entry_count: 1,
+                word_count: 10,
+            }]
+        );
+        assert_eq!(
+            stats.weeks.iter().map(|week| week.entry_count).sum::<u32>(),
+            2
+        );
+        assert_eq!(stats.char_count, 65);
+
+        let future = manager
+            .get_stats(Some(i64::MAX - 1), None, DEFAULT_TYPING_WPM)
+            .await
+            .expect("get stats");
+        assert_eq!(future.entry_count, 0);
+        assert_eq!(future.average_wpm, None);
+    }
+}
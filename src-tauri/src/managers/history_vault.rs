@@ -0,0 +1,406 @@
+//! Optional mirror of history into a folder of Markdown notes, for people who
+//! keep an Obsidian-style vault. Each entry becomes one note with YAML front
+//! matter. Notes are found again by the id at the end of their file name, so
+//! a retitled entry renames its note and a deleted entry removes it. The
+//! folder is listed once and then tracked in memory, so mirroring one entry
+//! does not scan a vault that may hold thousands of other notes.
+//!
+//! Vaults are often synced between machines, and history ids are only unique
+//! within one install. Note names therefore carry a random id for this
+//! install too, and notes written by other installs are never touched.
+//!
+//! The notes are plain Markdown even when history is encrypted at rest; the
+//! folder is chosen by the user as a place to read transcripts outside uttr.
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use uuid::Uuid;
+
+use super::history::{HistoryEntry, FILE_IMPORT_SOURCE};
+
+const NOTE_ID_PREFIX: &str = " (uttr-";
+const NOTE_ID_SUFFIX: &str = ").md";
+const MAX_NOTE_TITLE_CHARS: usize = 80;
+const INSTALL_ID_FILE: &str = "vault-install-id";
+
+/// Note paths by entry id for the folder they were listed from.
+type NoteIndex = (PathBuf, HashMap<i64, Vec<PathBuf>>);
+
+pub struct HistoryVault {
+    install_id: String,
+    dir: RwLock<Option<PathBuf>>,
+    notes: Mutex<Option<NoteIndex>>,
+}
+
+/// Reads the id that tells this install's notes apart from those of other
+/// installs syncing the same vault, creating it on first use.
+pub fn load_or_create_install_id(app_data_dir: &Path) -> Result<String> {
+    let path = app_data_dir.join(INSTALL_ID_FILE);
+    match fs::read_to_string(&path) {
+        Ok(id) if !id.trim().is_empty() => return Ok(id.trim().to_string()),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+        _ => {}
+    }
+    let id = Uuid::new_v4().simple().to_string()[..8].to_string();
+    fs::write(&path, &id)?;
+    Ok(id)
+}
+
+impl HistoryVault {
+    pub fn new(install_id: String, dir: Option<PathBuf>) -> Self {
+        Self {
+            install_id,
+            dir: RwLock::new(dir),
+            notes: Mutex::new(None),
+        }
+    }
+
+    pub fn dir(&self) -> Option<PathBuf> {
+        self.dir.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set_dir(&self, dir: Option<PathBuf>) {
+        *self.dir.write().unwrap_or_else(|e| e.into_inner()) = dir;
+    }
+
+    /// Writes the note for `entry`, removing any copy it left under another
+    /// name. Unchanged notes are not rewritten, so the vault's own sync and
+    /// file watchers only see real edits.
+    pub fn write_note(&self, entry: &HistoryEntry, folder: Option<&str>) -> Result<()> {
+        let Some(dir) = self.dir() else {
+            return Ok(());
+        };
+
+        let path = dir.join(note_file_name(entry, &self.install_id));
+        let mut index = self.notes.lock().unwrap_or_else(|e| e.into_inner());
+        let notes = notes_in(&mut index, &dir, &self.install_id)?;
+        for stale in notes.remove(&entry.id).unwrap_or_default() {
+            if stale != path {
+                remove_note_file(&stale)?;
+            }
+        }
+
+        let contents = render_note(entry, folder);
+        if fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+            fs::write(&path, contents)?;
+        }
+        notes.insert(entry.id, vec![path]);
+        Ok(())
+    }
+
+    pub fn remove_note(&self, id: i64) -> Result<()> {
+        let Some(dir) = self.dir() else {
+            return Ok(());
+        };
+        let mut index = self.notes.lock().unwrap_or_else(|e| e.into_inner());
+        for path in notes_in(&mut index, &dir, &self.install_id)?
+            .remove(&id)
+            .unwrap_or_default()
+        {
+            remove_note_file(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Removes this install's notes for entries that are no longer in
+    /// history. Returns how many were removed. The folder is listed again,
+    /// picking up notes renamed or copied outside uttr.
+    pub fn remove_notes_except(&self, ids: &HashSet<i64>) -> Result<usize> {
+        let Some(dir) = self.dir() else {
+            return Ok(0);
+        };
+        let mut index = self.notes.lock().unwrap_or_else(|e| e.into_inner());
+        let mut notes = list_notes(&dir, &self.install_id)?;
+        let stale_ids: Vec<i64> = notes
+            .keys()
+            .filter(|id| !ids.contains(id))
+            .copied()
+            .collect();
+        let mut removed = 0;
+        for id in stale_ids {
+            for path in notes.remove(&id).unwrap_or_default() {
+                remove_note_file(&path)?;
+                removed += 1;
+            }
+        }
+        *index = Some((dir, notes));
+        Ok(removed)
+    }
+}
+
+/// The note index for `dir`, listing the folder when the index is missing or
+/// was built for another folder.
+fn notes_in<'a>(
+    index: &'a mut Option<NoteIndex>,
+    dir: &Path,
+    install_id: &str,
+) -> Result<&'a mut HashMap<i64, Vec<PathBuf>>> {
+    if index
+        .as_ref()
+        .is_none_or(|(indexed_dir, _)| indexed_dir != dir)
+    {
+        *index = Some((dir.to_path_buf(), list_notes(dir, install_id)?));
+    }
+    Ok(&mut index.as_mut().expect("index was just filled").1)
+}
+
+/// This install's notes in `dir` by entry id. Other files in the vault,
+/// including notes from other installs, are never touched.
+fn list_notes(dir: &Path, install_id: &str) -> Result<HashMap<i64, Vec<PathBuf>>> {
+    let mut notes: HashMap<i64, Vec<PathBuf>> = HashMap::new();
+    for dir_entry in fs::read_dir(dir)? {
+        let path = dir_entry?.path();
+        if let Some(id) = note_id(&path, install_id) {
+            notes.entry(id).or_default().push(path);
+        }
+    }
+    Ok(notes)
+}
+
+/// Removes a note, ignoring one that was already deleted outside uttr.
+fn remove_note_file(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+fn note_id(path: &Path, install_id: &str) -> Option<i64> {
+    let name = path.file_name()?.to_str()?;
+    let (_, id) = name
+        .strip_suffix(NOTE_ID_SUFFIX)?
+        .rsplit_once(NOTE_ID_PREFIX)?;
+    id.strip_prefix(install_id)?.strip_prefix('-')?.parse().ok()
+}
+
+/// `<title> (uttr-<install>-<id>).md`, with characters that are not allowed
+/// in file names or that break Obsidian links replaced.
+fn note_file_name(entry: &HistoryEntry, install_id: &str) -> String {
+    let title: String = entry
+        .title
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    let title: String = title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_NOTE_TITLE_CHARS)
+        .collect();
+    let title = title.trim_matches(|c: char| c == '.' || c.is_whitespace());
+    let title = if title.is_empty() { "Untitled" } else { title };
+    format!(
+        "{title}{NOTE_ID_PREFIX}{install_id}-{}{NOTE_ID_SUFFIX}",
+        entry.id
+    )
+}
+
+/// JSON strings are valid YAML scalars, so they quote whatever the user typed.
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Renders an entry as a vault note. Meetings get their summary followed by
+/// the labeled transcript; dictations lead with the cleaned-up text and keep
+/// the raw transcript when it differs.
+pub fn render_note(entry: &HistoryEntry, folder: Option<&str>) -> String {
+    let is_meeting = entry.recording_source == "full_system_audio";
+    let mut note = String::from("---\n");
+    note.push_str(&format!("uttr_id: {}\n", entry.id));
+    note.push_str(&format!("title: {}\n", yaml_string(&entry.title)));
+    if let Some(datetime) = DateTime::from_timestamp(entry.timestamp, 0) {
+        note.push_str(&format!(
+            "created: {}\n",
+            datetime.with_timezone(&Local).to_rfc3339()
+        ));
+    }
+    let source = match entry.recording_source.as_str() {
+        "full_system_audio" => "meeting",
+        FILE_IMPORT_SOURCE => "file import",
+        _ => "dictation",
+    };
+    note.push_str(&format!("source: {source}\n"));
+    if let Some(model) = &entry.transcription_model {
+        note.push_str(&format!("model: {}\n", yaml_string(model)));
+    }
+    if let Some(route) = &entry.transcription_route {
+        note.push_str(&format!("route: {}\n", yaml_string(route)));
+    }
+    if let Some(folder) = folder {
+        note.push_str(&format!("folder: {}\n", yaml_string(folder)));
+    }
+    if entry.tags.is_empty() {
+        note.push_str("tags: []\n");
+    } else {
+        note.push_str("tags:\n");
+        for tag in &entry.tags {
+            note.push_str(&format!("  - {}\n", yaml_string(tag)));
+        }
+    }
+    note.push_str("---\n\n");
+
+    note.push_str(&format!("# {}\n\n", entry.title));
+    if let Some(user_note) = &entry.note {
+        for line in user_note.lines() {
+            note.push_str(&format!("> {line}\n"));
+        }
+        note.push('\n');
+    }
+
+    let transcript = entry.transcription_text.trim();
+    let processed = entry
+        .post_processed_text
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty() && *text != transcript);
+    match (is_meeting, processed) {
+        (true, Some(summary)) => {
+            note.push_str("## Summary\n\n");
+            note.push_str(summary);
+            note.push_str("\n\n## Transcript\n\n");
+            note.push_str(transcript);
+        }
+        (true, None) => {
+            note.push_str("## Transcript\n\n");
+            note.push_str(transcript);
+        }
+        (false, Some(text)) => {
+            note.push_str(text);
+            note.push_str("\n\n## Original transcript\n\n");
+            note.push_str(transcript);
+        }
+        (false, None) => note.push_str(transcript),
+    }
+    note.push('\n');
+    note
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, title: &str, source: &str) -> HistoryEntry {
+        HistoryEntry {
+            id,
+            file_name: format!("uttr-{id}.wav"),
+            timestamp: 100,
+            saved: false,
+            audio_purged: false,
+            title: title.to_string(),
+            transcription_text: "Me: ship it friday\nThem: sounds good".to_string(),
+            post_processed_text: Some("## Decisions\n\n- Ship Friday".to_string()),
+            post_process_prompt: None,
+            recording_source: source.to_string(),
+            source_path: None,
+            transcription_route: Some("local".to_string()),
+            fallback_from: None,
+            transcription_model: Some("parakeet-tdt-0.6b-v3".to_string()),
+            note: None,
+            folder_id: None,
+            tags: vec!["release".to_string(), "team: core".to_string()],
+        }
+    }
+
+    #[test]
+    fn notes_follow_titles_and_deletions() {
+        let dir = tempfile::tempdir().expect("create vault dir");
+        let unrelated = dir.path().join("Ideas.md");
+        fs::write(&unrelated, "my own note").unwrap();
+        let vault = HistoryVault::new("a1b2c3d4".to_string(), Some(dir.path().to_path_buf()));
+
+        let mut meeting = entry(7, "Standup: release/2.0", "full_system_audio");
+        vault.write_note(&meeting, Some("Work")).unwrap();
+        let path = dir.path().join("Standup- release-2.0 (uttr-a1b2c3d4-7).md");
+        let contents = fs::read_to_string(&path).expect("note written");
+        assert!(contents.starts_with("---\nuttr_id: 7\ntitle: \"Standup: release/2.0\"\n"));
+        assert!(contents.contains("source: meeting\nmodel: \"parakeet-tdt-0.6b-v3\"\n"));
+        assert!(contents.contains("folder: \"Work\"\ntags:\n  - \"release\"\n  - \"team: core\"\n"));
+        assert!(contents.contains(
+            "## Summary\n\n## Decisions\n\n- Ship Friday\n\n## Transcript\n\nMe: ship it friday\nThem: sounds good\n"
+        ));
+
+        meeting.title = "Release sync".to_string();
+        vault.write_note(&meeting, None).unwrap();
+        assert!(!path.exists());
+        let renamed = dir.path().join("Release sync (uttr-a1b2c3d4-7).md");
+        assert!(!fs::read_to_string(&renamed).unwrap().contains("folder:"));
+
+        let dictation = entry(8, "", "dictation");
+        vault.write_note(&dictation, None).unwrap();
+        let dictation_note =
+            fs::read_to_string(dir.path().join("Untitled (uttr-a1b2c3d4-8).md")).unwrap();
+        assert!(dictation_note.contains("source: dictation\n"));
+        assert!(dictation_note.contains("- Ship Friday\n\n## Original transcript\n\n"));
+
+        vault
+            .write_note(&entry(9, "Interview", FILE_IMPORT_SOURCE), None)
+            .unwrap();
+        let import_path = dir.path().join("Interview (uttr-a1b2c3d4-9).md");
+        assert!(fs::read_to_string(&import_path)
+            .unwrap()
+            .contains("source: file import\n"));
+        vault.remove_note(9).unwrap();
+
+        vault.remove_note(7).unwrap();
+        assert!(!renamed.exists());
+        assert_eq!(vault.remove_notes_except(&HashSet::new()).unwrap(), 1);
+        assert_eq!(fs::read_to_string(&unrelated).unwrap(), "my own note");
+    }
+
+    #[test]
+    fn notes_from_earlier_runs_and_outside_deletions_are_handled() {
+        let dir = tempfile::tempdir().expect("create vault dir");
+        let mut dictation = entry(3, "Draft", "dictation");
+        HistoryVault::new("a1b2c3d4".to_string(), Some(dir.path().to_path_buf()))
+            .write_note(&dictation, None)
+            .unwrap();
+
+        // A fresh vault, as after a restart, still finds the note by its id.
+        let vault = HistoryVault::new("a1b2c3d4".to_string(), Some(dir.path().to_path_buf()));
+        dictation.title = "Final".to_string();
+        vault.write_note(&dictation, None).unwrap();
+        assert!(!dir.path().join("Draft (uttr-a1b2c3d4-3).md").exists());
+
+        fs::remove_file(dir.path().join("Final (uttr-a1b2c3d4-3).md")).unwrap();
+        vault.remove_note(3).unwrap();
+        vault.write_note(&dictation, None).unwrap();
+        assert!(dir.path().join("Final (uttr-a1b2c3d4-3).md").exists());
+    }
+
+    #[test]
+    fn notes_from_other_installs_are_left_alone() {
+        let dir = tempfile::tempdir().expect("create vault dir");
+        let foreign = dir.path().join("Their standup (uttr-99ff00ee-7).md");
+        fs::write(&foreign, "synced from another machine").unwrap();
+        let vault = HistoryVault::new("a1b2c3d4".to_string(), Some(dir.path().to_path_buf()));
+
+        vault
+            .write_note(&entry(7, "Standup", "full_system_audio"), None)
+            .unwrap();
+        assert!(dir.path().join("Standup (uttr-a1b2c3d4-7).md").exists());
+        vault.remove_note(7).unwrap();
+        assert_eq!(vault.remove_notes_except(&HashSet::new()).unwrap(), 0);
+        assert_eq!(
+            fs::read_to_string(&foreign).unwrap(),
+            "synced from another machine"
+        );
+    }
+
+    #[test]
+    fn install_id_is_kept_across_runs() {
+        let dir = tempfile::tempdir().expect("create app data dir");
+        let id = load_or_create_install_id(dir.path()).unwrap();
+        assert_eq!(id.len(), 8);
+        assert_eq!(load_or_create_install_id(dir.path()).unwrap(), id);
+    }
+}
@@ -1,5 +1,9 @@
 pub mod audio;
 pub mod full_system_audio;
 pub mod history;
+pub mod history_bundle;
+pub mod history_crypto;
+pub mod history_stats;
+pub mod history_vault;
 pub mod model;
 pub mod transcription;
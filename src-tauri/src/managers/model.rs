@@ -28,10 +28,13 @@ pub enum EngineType {
 pub const GROQ_MODEL_WHISPER_LARGE_V3_TURBO: &str = "groq-whisper-large-v3-turbo";
 pub const GROQ_MODEL_WHISPER_LARGE_V3: &str = "groq-whisper-large-v3";
 pub const OPENAI_MODEL_GPT_4O_TRANSCRIBE: &str = "openai-gpt-4o-transcribe";
+pub const CUSTOM_TRANSCRIPTION_MODEL_ID: &str = "custom-openai-compatible";
 pub const DEFAULT_LOCAL_MODEL_ID: &str = "parakeet-tdt-0.6b-v3";
 
 pub fn is_cloud_model_id(model_id: &str) -> bool {
-    model_id.starts_with("groq-") || model_id.starts_with("openai-")
+    model_id.starts_with("groq-")
+        || model_id.starts_with("openai-")
+        || model_id == CUSTOM_TRANSCRIPTION_MODEL_ID
 }
 
 fn is_user_visible_model_id(model_id: &str) -> bool {
@@ -125,396 +128,7 @@ impl ModelManager {
             fs::create_dir_all(&models_dir)?;
         }
 
-        let mut available_models = HashMap::new();
-
-        // Whisper supported languages (99 languages from tokenizer)
-        // Including zh-Hans and zh-Hant variants to match frontend language codes
-        let whisper_languages: Vec<String> = vec![
-            "en", "zh", "zh-Hans", "zh-Hant", "de", "es", "ru", "ko", "fr", "ja", "pt", "tr", "pl",
-            "ca", "nl", "ar", "sv", "it", "id", "hi", "fi", "vi", "he", "uk", "el", "ms", "cs",
-            "ro", "da", "hu", "ta", "no", "th", "ur", "hr", "bg", "lt", "la", "mi", "ml", "cy",
-            "sk", "te", "fa", "lv", "bn", "sr", "az", "sl", "kn", "et", "mk", "br", "eu", "is",
-            "hy", "ne", "mn", "bs", "kk", "sq", "sw", "gl", "mr", "pa", "si", "km", "sn", "yo",
-            "so", "af", "oc", "ka", "be", "tg", "sd", "gu", "am", "yi", "lo", "uz", "fo", "ht",
-            "ps", "tk", "nn", "mt", "sa", "lb", "my", "bo", "tl", "mg", "as", "tt", "haw", "ln",
-            "ha", "ba", "jw", "su", "yue",
-        ]
-        .into_iter()
-        .map(String::from)
-        .collect();
-
-        // TODO this should be read from a JSON file or something..
-        available_models.insert(
-            "small".to_string(),
-            ModelInfo {
-                id: "small".to_string(),
-                name: "Whisper Small".to_string(),
-                description: "Fast and fairly accurate.".to_string(),
-                filename: "ggml-small.bin".to_string(),
-                url: Some("https://blob.handy.computer/ggml-small.bin".to_string()),
-                size_mb: 487,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.60,
-                speed_score: 0.85,
-                supports_translation: true,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        // Add downloadable models
-        available_models.insert(
-            "medium".to_string(),
-            ModelInfo {
-                id: "medium".to_string(),
-                name: "Whisper Medium".to_string(),
-                description: "Good accuracy, medium speed".to_string(),
-                filename: "whisper-medium-q4_1.bin".to_string(),
-                url: Some("https://blob.handy.computer/whisper-medium-q4_1.bin".to_string()),
-                size_mb: 492, // Approximate size
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.75,
-                speed_score: 0.60,
-                supports_translation: true,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "turbo".to_string(),
-            ModelInfo {
-                id: "turbo".to_string(),
-                name: "Whisper Turbo".to_string(),
-                description: "Balanced accuracy and speed.".to_string(),
-                filename: "ggml-large-v3-turbo.bin".to_string(),
-                url: Some("https://blob.handy.computer/ggml-large-v3-turbo.bin".to_string()),
-                size_mb: 1600, // Approximate size
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.80,
-                speed_score: 0.40,
-                supports_translation: false, // Turbo doesn't support translation
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "large".to_string(),
-            ModelInfo {
-                id: "large".to_string(),
-                name: "Whisper Large".to_string(),
-                description: "Good accuracy, but slow.".to_string(),
-                filename: "ggml-large-v3-q5_0.bin".to_string(),
-                url: Some("https://blob.handy.computer/ggml-large-v3-q5_0.bin".to_string()),
-                size_mb: 1100, // Approximate size
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.85,
-                speed_score: 0.30,
-                supports_translation: true,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "breeze-asr".to_string(),
-            ModelInfo {
-                id: "breeze-asr".to_string(),
-                name: "Breeze ASR".to_string(),
-                description: "Optimized for Taiwanese Mandarin. Code-switching support."
-                    .to_string(),
-                filename: "breeze-asr-q5_k.bin".to_string(),
-                url: Some("https://blob.handy.computer/breeze-asr-q5_k.bin".to_string()),
-                size_mb: 1080,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.85,
-                speed_score: 0.35,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
-            ModelInfo {
-                id: GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
-                name: "Groq Whisper Large V3 Turbo".to_string(),
-                description: "Extremely fast.".to_string(),
-                filename: GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
-                url: None,
-                size_mb: 0,
-                is_downloaded: true,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.80,
-                speed_score: 0.98,
-                supports_translation: true,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
-            ModelInfo {
-                id: GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
-                name: "Groq Whisper Large V3".to_string(),
-                description: "Higher quality.".to_string(),
-                filename: GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
-                url: None,
-                size_mb: 0,
-                is_downloaded: true,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.88,
-                speed_score: 0.92,
-                supports_translation: true,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
-            ModelInfo {
-                id: OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
-                name: "OpenAI GPT-4o Transcribe".to_string(),
-                description: "Accuracy-first dictation.".to_string(),
-                filename: OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
-                url: None,
-                size_mb: 0,
-                is_downloaded: true,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: false,
-                engine_type: EngineType::Whisper,
-                accuracy_score: 0.94,
-                speed_score: 0.88,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: whisper_languages.clone(),
-                is_custom: false,
-            },
-        );
-
-        // Add NVIDIA Parakeet models (directory-based)
-        available_models.insert(
-            "parakeet-tdt-0.6b-v2".to_string(),
-            ModelInfo {
-                id: "parakeet-tdt-0.6b-v2".to_string(),
-                name: "Parakeet V2".to_string(),
-                description: "English only. The best model for English speakers.".to_string(),
-                filename: "parakeet-tdt-0.6b-v2-int8".to_string(), // Directory name
-                url: Some("https://blob.handy.computer/parakeet-v2-int8.tar.gz".to_string()),
-                size_mb: 473, // Approximate size for int8 quantized model
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::Parakeet,
-                accuracy_score: 0.85,
-                speed_score: 0.85,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: vec!["en".to_string()],
-                is_custom: false,
-            },
-        );
-
-        // Parakeet V3 supported languages (25 EU languages + Russian/Ukrainian):
-        // bg, hr, cs, da, nl, en, et, fi, fr, de, el, hu, it, lv, lt, mt, pl, pt, ro, sk, sl, es, sv, ru, uk
-        let parakeet_v3_languages: Vec<String> = vec![
-            "bg", "hr", "cs", "da", "nl", "en", "et", "fi", "fr", "de", "el", "hu", "it", "lv",
-            "lt", "mt", "pl", "pt", "ro", "sk", "sl", "es", "sv", "ru", "uk",
-        ]
-        .into_iter()
-        .map(String::from)
-        .collect();
-
-        available_models.insert(
-            "parakeet-tdt-0.6b-v3".to_string(),
-            ModelInfo {
-                id: "parakeet-tdt-0.6b-v3".to_string(),
-                name: "Parakeet V3".to_string(),
-                description: "Fast and accurate. Supports 25 European languages.".to_string(),
-                filename: "parakeet-tdt-0.6b-v3-int8".to_string(), // Directory name
-                url: Some("https://blob.handy.computer/parakeet-v3-int8.tar.gz".to_string()),
-                size_mb: 478, // Approximate size for int8 quantized model
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::Parakeet,
-                accuracy_score: 0.80,
-                speed_score: 0.85,
-                supports_translation: false,
-                is_recommended: true,
-                supported_languages: parakeet_v3_languages,
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "moonshine-base".to_string(),
-            ModelInfo {
-                id: "moonshine-base".to_string(),
-                name: "Moonshine Base".to_string(),
-                description: "Very fast, English only. Handles accents well.".to_string(),
-                filename: "moonshine-base".to_string(),
-                url: Some("https://blob.handy.computer/moonshine-base.tar.gz".to_string()),
-                size_mb: 58,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::Moonshine,
-                accuracy_score: 0.70,
-                speed_score: 0.90,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: vec!["en".to_string()],
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "moonshine-tiny-streaming-en".to_string(),
-            ModelInfo {
-                id: "moonshine-tiny-streaming-en".to_string(),
-                name: "Moonshine V2 Tiny".to_string(),
-                description: "Ultra-fast, English only".to_string(),
-                filename: "moonshine-tiny-streaming-en".to_string(),
-                url: Some(
-                    "https://blob.handy.computer/moonshine-tiny-streaming-en.tar.gz".to_string(),
-                ),
-                size_mb: 31,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::MoonshineStreaming,
-                accuracy_score: 0.55,
-                speed_score: 0.95,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: vec!["en".to_string()],
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "moonshine-small-streaming-en".to_string(),
-            ModelInfo {
-                id: "moonshine-small-streaming-en".to_string(),
-                name: "Moonshine V2 Small".to_string(),
-                description: "Fast, English only. Good balance of speed and accuracy.".to_string(),
-                filename: "moonshine-small-streaming-en".to_string(),
-                url: Some(
-                    "https://blob.handy.computer/moonshine-small-streaming-en.tar.gz".to_string(),
-                ),
-                size_mb: 100,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::MoonshineStreaming,
-                accuracy_score: 0.65,
-                speed_score: 0.90,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: vec!["en".to_string()],
-                is_custom: false,
-            },
-        );
-
-        available_models.insert(
-            "moonshine-medium-streaming-en".to_string(),
-            ModelInfo {
-                id: "moonshine-medium-streaming-en".to_string(),
-                name: "Moonshine V2 Medium".to_string(),
-                description: "English only. High quality.".to_string(),
-                filename: "moonshine-medium-streaming-en".to_string(),
-                url: Some(
-                    "https://blob.handy.computer/moonshine-medium-streaming-en.tar.gz".to_string(),
-                ),
-                size_mb: 192,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::MoonshineStreaming,
-                accuracy_score: 0.75,
-                speed_score: 0.80,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: vec!["en".to_string()],
-                is_custom: false,
-            },
-        );
-
-        // SenseVoice supported languages
-        let sense_voice_languages: Vec<String> =
-            vec!["zh", "zh-Hans", "zh-Hant", "en", "yue", "ja", "ko"]
-                .into_iter()
-                .map(String::from)
-                .collect();
-
-        available_models.insert(
-            "sense-voice-int8".to_string(),
-            ModelInfo {
-                id: "sense-voice-int8".to_string(),
-                name: "SenseVoice".to_string(),
-                description: "Very fast. Chinese, English, Japanese, Korean, Cantonese."
-                    .to_string(),
-                filename: "sense-voice-int8".to_string(),
-                url: Some("https://blob.handy.computer/sense-voice-int8.tar.gz".to_string()),
-                size_mb: 160,
-                is_downloaded: false,
-                is_downloading: false,
-                partial_size: 0,
-                is_directory: true,
-                engine_type: EngineType::SenseVoice,
-                accuracy_score: 0.65,
-                speed_score: 0.95,
-                supports_translation: false,
-                is_recommended: false,
-                supported_languages: sense_voice_languages,
-                is_custom: false,
-            },
-        );
+        let mut available_models = built_in_models();
 
         // Auto-discover custom Whisper models (.bin files) in the models directory
         if let Err(e) = Self::discover_custom_whisper_models(&models_dir, &mut available_models) {
@@ -1300,6 +914,482 @@ impl ModelManager {
     }
 }
 
+/// The models Uttr ships a catalog entry for, before anything on disk is
+/// checked.
+fn built_in_models() -> HashMap<String, ModelInfo> {
+    let mut available_models = HashMap::new();
+
+    // Whisper supported languages (99 languages from tokenizer)
+    // Including zh-Hans and zh-Hant variants to match frontend language codes
+    let whisper_languages: Vec<String> = vec![
+        "en", "zh", "zh-Hans", "zh-Hant", "de", "es", "ru", "ko", "fr", "ja", "pt", "tr", "pl",
+        "ca", "nl", "ar", "sv", "it", "id", "hi", "fi", "vi", "he", "uk", "el", "ms", "cs", "ro",
+        "da", "hu", "ta", "no", "th", "ur", "hr", "bg", "lt", "la", "mi", "ml", "cy", "sk", "te",
+        "fa", "lv", "bn", "sr", "az", "sl", "kn", "et", "mk", "br", "eu", "is", "hy", "ne", "mn",
+        "bs", "kk", "sq", "sw", "gl", "mr", "pa", "si", "km", "sn", "yo", "so", "af", "oc", "ka",
+        "be", "tg", "sd", "gu", "am", "yi", "lo", "uz", "fo", "ht", "ps", "tk", "nn", "mt", "sa",
+        "lb", "my", "bo", "tl", "mg", "as", "tt", "haw", "ln", "ha", "ba", "jw", "su", "yue",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    // TODO this should be read from a JSON file or something..
+    available_models.insert(
+        "small".to_string(),
+        ModelInfo {
+            id: "small".to_string(),
+            name: "Whisper Small".to_string(),
+            description: "Fast and fairly accurate.".to_string(),
+            filename: "ggml-small.bin".to_string(),
+            url: Some("https://blob.handy.computer/ggml-small.bin".to_string()),
+            size_mb: 487,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.60,
+            speed_score: 0.85,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    // Add downloadable models
+    available_models.insert(
+        "medium".to_string(),
+        ModelInfo {
+            id: "medium".to_string(),
+            name: "Whisper Medium".to_string(),
+            description: "Good accuracy, medium speed".to_string(),
+            filename: "whisper-medium-q4_1.bin".to_string(),
+            url: Some("https://blob.handy.computer/whisper-medium-q4_1.bin".to_string()),
+            size_mb: 492, // Approximate size
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.75,
+            speed_score: 0.60,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "turbo".to_string(),
+        ModelInfo {
+            id: "turbo".to_string(),
+            name: "Whisper Turbo".to_string(),
+            description: "Balanced accuracy and speed.".to_string(),
+            filename: "ggml-large-v3-turbo.bin".to_string(),
+            url: Some("https://blob.handy.computer/ggml-large-v3-turbo.bin".to_string()),
+            size_mb: 1600, // Approximate size
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.80,
+            speed_score: 0.40,
+            supports_translation: false, // Turbo doesn't support translation
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "large".to_string(),
+        ModelInfo {
+            id: "large".to_string(),
+            name: "Whisper Large".to_string(),
+            description: "Good accuracy, but slow.".to_string(),
+            filename: "ggml-large-v3-q5_0.bin".to_string(),
+            url: Some("https://blob.handy.computer/ggml-large-v3-q5_0.bin".to_string()),
+            size_mb: 1100, // Approximate size
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.85,
+            speed_score: 0.30,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "breeze-asr".to_string(),
+        ModelInfo {
+            id: "breeze-asr".to_string(),
+            name: "Breeze ASR".to_string(),
+            description: "Optimized for Taiwanese Mandarin. Code-switching support.".to_string(),
+            filename: "breeze-asr-q5_k.bin".to_string(),
+            url: Some("https://blob.handy.computer/breeze-asr-q5_k.bin".to_string()),
+            size_mb: 1080,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.85,
+            speed_score: 0.35,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
+        ModelInfo {
+            id: GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
+            name: "Groq Whisper Large V3 Turbo".to_string(),
+            description: "Extremely fast.".to_string(),
+            filename: GROQ_MODEL_WHISPER_LARGE_V3_TURBO.to_string(),
+            url: None,
+            size_mb: 0,
+            is_downloaded: true,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.80,
+            speed_score: 0.98,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
+        ModelInfo {
+            id: GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
+            name: "Groq Whisper Large V3".to_string(),
+            description: "Higher quality.".to_string(),
+            filename: GROQ_MODEL_WHISPER_LARGE_V3.to_string(),
+            url: None,
+            size_mb: 0,
+            is_downloaded: true,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.88,
+            speed_score: 0.92,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
+        ModelInfo {
+            id: OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
+            name: "OpenAI GPT-4o Transcribe".to_string(),
+            description: "Accuracy-first dictation.".to_string(),
+            filename: OPENAI_MODEL_GPT_4O_TRANSCRIBE.to_string(),
+            url: None,
+            size_mb: 0,
+            is_downloaded: true,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.94,
+            speed_score: 0.88,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        CUSTOM_TRANSCRIPTION_MODEL_ID.to_string(),
+        ModelInfo {
+            id: CUSTOM_TRANSCRIPTION_MODEL_ID.to_string(),
+            name: "Custom Server".to_string(),
+            description: "Your own OpenAI-compatible transcription server.".to_string(),
+            filename: CUSTOM_TRANSCRIPTION_MODEL_ID.to_string(),
+            url: None,
+            size_mb: 0,
+            is_downloaded: true,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score: 0.0,
+            speed_score: 0.0,
+            supports_translation: true,
+            is_recommended: false,
+            supported_languages: whisper_languages.clone(),
+            is_custom: false,
+        },
+    );
+
+    // Add NVIDIA Parakeet models (directory-based)
+    available_models.insert(
+        "parakeet-tdt-0.6b-v2".to_string(),
+        ModelInfo {
+            id: "parakeet-tdt-0.6b-v2".to_string(),
+            name: "Parakeet V2".to_string(),
+            description: "English only. The best model for English speakers.".to_string(),
+            filename: "parakeet-tdt-0.6b-v2-int8".to_string(), // Directory name
+            url: Some("https://blob.handy.computer/parakeet-v2-int8.tar.gz".to_string()),
+            size_mb: 473, // Approximate size for int8 quantized model
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::Parakeet,
+            accuracy_score: 0.85,
+            speed_score: 0.85,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: vec!["en".to_string()],
+            is_custom: false,
+        },
+    );
+
+    // Parakeet V3 supported languages (25 EU languages + Russian/Ukrainian):
+    // bg, hr, cs, da, nl, en, et, fi, fr, de, el, hu, it, lv, lt, mt, pl, pt, ro, sk, sl, es, sv, ru, uk
+    let parakeet_v3_languages: Vec<String> = vec![
+        "bg", "hr", "cs", "da", "nl", "en", "et", "fi", "fr", "de", "el", "hu", "it", "lv", "lt",
+        "mt", "pl", "pt", "ro", "sk", "sl", "es", "sv", "ru", "uk",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    available_models.insert(
+        "parakeet-tdt-0.6b-v3".to_string(),
+        ModelInfo {
+            id: "parakeet-tdt-0.6b-v3".to_string(),
+            name: "Parakeet V3".to_string(),
+            description: "Fast and accurate. Supports 25 European languages.".to_string(),
+            filename: "parakeet-tdt-0.6b-v3-int8".to_string(), // Directory name
+            url: Some("https://blob.handy.computer/parakeet-v3-int8.tar.gz".to_string()),
+            size_mb: 478, // Approximate size for int8 quantized model
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::Parakeet,
+            accuracy_score: 0.80,
+            speed_score: 0.85,
+            supports_translation: false,
+            is_recommended: true,
+            supported_languages: parakeet_v3_languages,
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "moonshine-base".to_string(),
+        ModelInfo {
+            id: "moonshine-base".to_string(),
+            name: "Moonshine Base".to_string(),
+            description: "Very fast, English only. Handles accents well.".to_string(),
+            filename: "moonshine-base".to_string(),
+            url: Some("https://blob.handy.computer/moonshine-base.tar.gz".to_string()),
+            size_mb: 58,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::Moonshine,
+            accuracy_score: 0.70,
+            speed_score: 0.90,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: vec!["en".to_string()],
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "moonshine-tiny-streaming-en".to_string(),
+        ModelInfo {
+            id: "moonshine-tiny-streaming-en".to_string(),
+            name: "Moonshine V2 Tiny".to_string(),
+            description: "Ultra-fast, English only".to_string(),
+            filename: "moonshine-tiny-streaming-en".to_string(),
+            url: Some("https://blob.handy.computer/moonshine-tiny-streaming-en.tar.gz".to_string()),
+            size_mb: 31,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::MoonshineStreaming,
+            accuracy_score: 0.55,
+            speed_score: 0.95,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: vec!["en".to_string()],
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "moonshine-small-streaming-en".to_string(),
+        ModelInfo {
+            id: "moonshine-small-streaming-en".to_string(),
+            name: "Moonshine V2 Small".to_string(),
+            description: "Fast, English only. Good balance of speed and accuracy.".to_string(),
+            filename: "moonshine-small-streaming-en".to_string(),
+            url: Some(
+                "https://blob.handy.computer/moonshine-small-streaming-en.tar.gz".to_string(),
+            ),
+            size_mb: 100,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::MoonshineStreaming,
+            accuracy_score: 0.65,
+            speed_score: 0.90,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: vec!["en".to_string()],
+            is_custom: false,
+        },
+    );
+
+    available_models.insert(
+        "moonshine-medium-streaming-en".to_string(),
+        ModelInfo {
+            id: "moonshine-medium-streaming-en".to_string(),
+            name: "Moonshine V2 Medium".to_string(),
+            description: "English only. High quality.".to_string(),
+            filename: "moonshine-medium-streaming-en".to_string(),
+            url: Some(
+                "https://blob.handy.computer/moonshine-medium-streaming-en.tar.gz".to_string(),
+            ),
+            size_mb: 192,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::MoonshineStreaming,
+            accuracy_score: 0.75,
+            speed_score: 0.80,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: vec!["en".to_string()],
+            is_custom: false,
+        },
+    );
+
+    // SenseVoice supported languages
+    let sense_voice_languages: Vec<String> =
+        vec!["zh", "zh-Hans", "zh-Hant", "en", "yue", "ja", "ko"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+    available_models.insert(
+        "sense-voice-int8".to_string(),
+        ModelInfo {
+            id: "sense-voice-int8".to_string(),
+            name: "SenseVoice".to_string(),
+            description: "Very fast. Chinese, English, Japanese, Korean, Cantonese.".to_string(),
+            filename: "sense-voice-int8".to_string(),
+            url: Some("https://blob.handy.computer/sense-voice-int8.tar.gz".to_string()),
+            size_mb: 160,
+            is_downloaded: false,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: true,
+            engine_type: EngineType::SenseVoice,
+            accuracy_score: 0.65,
+            speed_score: 0.95,
+            supports_translation: false,
+            is_recommended: false,
+            supported_languages: sense_voice_languages,
+            is_custom: false,
+        },
+    );
+
+    available_models
+}
+
+/// Returns the path of a local model when it is fully downloaded.
+fn complete_model_path(models_dir: &Path, model: &ModelInfo) -> Option<PathBuf> {
+    let model_path = models_dir.join(&model.filename);
+    let partial_path = models_dir.join(format!("{}.partial", &model.filename));
+    let complete = model_path.exists()
+        && (!model.is_directory || model_path.is_dir())
+        && !partial_path.exists();
+    complete.then_some(model_path)
+}
+
+/// Read-only view of the model catalog and what is downloaded under a models
+/// directory, used by the command line. Unlike [`ModelManager`] it needs no
+/// running app and never downloads, migrates or cleans up files.
+pub struct ModelCatalog {
+    models_dir: PathBuf,
+    models: HashMap<String, ModelInfo>,
+}
+
+impl ModelCatalog {
+    pub fn load(models_dir: PathBuf) -> Self {
+        let mut models = built_in_models();
+        if let Err(e) = ModelManager::discover_custom_whisper_models(&models_dir, &mut models) {
+            warn!("Failed to discover custom models: {}", e);
+        }
+
+        for model in models.values_mut() {
+            model.is_downloaded =
+                is_cloud_model_id(&model.id) || complete_model_path(&models_dir, model).is_some();
+        }
+
+        Self { models_dir, models }
+    }
+
+    pub fn get_available_models(&self) -> Vec<ModelInfo> {
+        self.models
+            .values()
+            .filter(|model| is_user_visible_model_id(&model.id))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_model_info(&self, model_id: &str) -> Option<ModelInfo> {
+        self.models.get(model_id).cloned()
+    }
+
+    pub fn get_model_path(&self, model_id: &str) -> Result<PathBuf> {
+        if is_cloud_model_id(model_id) {
+            return Err(anyhow::anyhow!(
+                "Cloud models do not have a local model path."
+            ));
+        }
+
+        let model_info = self
+            .models
+            .get(model_id)
+            .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
+        complete_model_path(&self.models_dir, model_info)
+            .ok_or_else(|| anyhow::anyhow!("Model not available: {}", model_id))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1449,4 +1539,33 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(models.len(), count_before);
     }
+
+    #[test]
+    fn catalog_reports_only_complete_local_downloads() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().to_path_buf();
+        File::create(models_dir.join("ggml-small.bin")).unwrap();
+        File::create(models_dir.join("whisper-medium-q4_1.bin")).unwrap();
+        File::create(models_dir.join("whisper-medium-q4_1.bin.partial")).unwrap();
+        // Directory models only count once extracted into a directory.
+        File::create(models_dir.join("parakeet-tdt-0.6b-v3-int8")).unwrap();
+        File::create(models_dir.join("my-custom-model.bin")).unwrap();
+
+        let catalog = ModelCatalog::load(models_dir.clone());
+
+        assert_eq!(
+            catalog.get_model_path("small").unwrap(),
+            models_dir.join("ggml-small.bin")
+        );
+        assert!(
+            catalog
+                .get_model_info("my-custom-model")
+                .unwrap()
+                .is_downloaded
+        );
+        assert!(!catalog.get_model_info("medium").unwrap().is_downloaded);
+        assert!(catalog.get_model_path("medium").is_err());
+        assert!(catalog.get_model_path("parakeet-tdt-0.6b-v3").is_err());
+        assert!(catalog.get_model_path("large").is_err());
+    }
 }
@@ -1,21 +1,26 @@
 use crate::access::{bootstrap_install_state, refresh_entitlement_state, request_claim_token};
 use crate::audio_toolkit::{
-    apply_custom_words, filter_transcription_output, trim_proxy_upload_audio,
+    apply_custom_words, apply_inverse_text_normalization, filter_transcription_output,
+    trim_proxy_upload_audio,
 };
 use crate::diagnostics::{report_byok_transcription_failure, report_missing_byok_api_key};
 use crate::groq_client::{self, DirectTranscriptionProvider};
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::{TranscriptSegment, TranscriptTimestamps};
 use crate::managers::model::{
-    groq_api_model_name, is_cloud_model_id, openai_api_model_name, EngineType, ModelInfo,
-    ModelManager, DEFAULT_LOCAL_MODEL_ID, GROQ_MODEL_WHISPER_LARGE_V3,
+    groq_api_model_name, is_cloud_model_id, openai_api_model_name, EngineType, ModelCatalog,
+    ModelInfo, ModelManager, CUSTOM_TRANSCRIPTION_MODEL_ID, DEFAULT_LOCAL_MODEL_ID,
+    GROQ_MODEL_WHISPER_LARGE_V3, OPENAI_MODEL_GPT_4O_TRANSCRIBE,
 };
 use crate::settings::{
-    get_settings, write_settings, AccessState, AppSettings, ModelUnloadTimeout, TrialState,
+    get_settings, write_settings, AccessState, AppSettings, ModelUnloadTimeout,
+    TranscriptionFallbackPolicy, TrialState,
 };
 use anyhow::Result;
 use log::{debug, error, info, warn};
 use serde::Serialize;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex, MutexGuard};
 use std::thread;
@@ -26,7 +31,8 @@ use tokio::time::{sleep, timeout};
 use transcribe_rs::{
     engines::{
         moonshine::{
-            ModelVariant, MoonshineEngine, MoonshineModelParams, MoonshineStreamingEngine,
+            model::MoonshineError, streaming_model::StreamingModel,
+            streaming_state::StreamingState, ModelVariant, MoonshineEngine, MoonshineModelParams,
             StreamingModelParams,
         },
         parakeet::{
@@ -53,10 +59,166 @@ enum LoadedEngine {
     Whisper(WhisperEngine),
     Parakeet(ParakeetEngine),
     Moonshine(MoonshineEngine),
-    MoonshineStreaming(MoonshineStreamingEngine),
+    /// Held as the bare model so live partials can drive its incremental API.
+    MoonshineStreaming(StreamingModel),
     SenseVoice(SenseVoiceEngine),
 }
 
+impl LoadedEngine {
+    /// Loads the engine for `engine_type` from a downloaded model. Errors are
+    /// user-facing messages.
+    fn load(engine_type: &EngineType, model_path: &Path, model_id: &str) -> Result<Self, String> {
+        let engine = match engine_type {
+            EngineType::Whisper => {
+                let mut engine = WhisperEngine::new();
+                engine
+                    .load_model(model_path)
+                    .map_err(|e| format!("Failed to load whisper model {}: {}", model_id, e))?;
+                Self::Whisper(engine)
+            }
+            EngineType::Parakeet => {
+                let mut engine = ParakeetEngine::new();
+                engine
+                    .load_model_with_params(model_path, ParakeetModelParams::int8())
+                    .map_err(|e| format!("Failed to load parakeet model {}: {}", model_id, e))?;
+                Self::Parakeet(engine)
+            }
+            EngineType::Moonshine => {
+                let mut engine = MoonshineEngine::new();
+                engine
+                    .load_model_with_params(
+                        model_path,
+                        MoonshineModelParams::variant(ModelVariant::Base),
+                    )
+                    .map_err(|e| format!("Failed to load moonshine model {}: {}", model_id, e))?;
+                Self::Moonshine(engine)
+            }
+            EngineType::MoonshineStreaming => {
+                let model =
+                    StreamingModel::new(model_path, StreamingModelParams::default().num_threads)
+                        .map_err(|e| {
+                            format!(
+                                "Failed to load moonshine streaming model {}: {}",
+                                model_id, e
+                            )
+                        })?;
+                Self::MoonshineStreaming(model)
+            }
+            EngineType::SenseVoice => {
+                let mut engine = SenseVoiceEngine::new();
+                engine
+                    .load_model_with_params(model_path, SenseVoiceModelParams::int8())
+                    .map_err(|e| format!("Failed to load SenseVoice model {}: {}", model_id, e))?;
+                Self::SenseVoice(engine)
+            }
+        };
+        Ok(engine)
+    }
+
+    /// Runs one pass over 16 kHz mono audio with the language options from
+    /// `settings`.
+    fn transcribe(
+        &mut self,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+    ) -> Result<transcribe_rs::TranscriptionResult> {
+        match self {
+            Self::Whisper(whisper_engine) => {
+                let whisper_language = if settings.selected_language == "auto" {
+                    None
+                } else {
+                    let normalized = if settings.selected_language == "zh-Hans"
+                        || settings.selected_language == "zh-Hant"
+                    {
+                        "zh".to_string()
+                    } else {
+                        settings.selected_language.clone()
+                    };
+                    Some(normalized)
+                };
+
+                let params = WhisperInferenceParams {
+                    language: whisper_language,
+                    translate: settings.translate_to_english,
+                    ..Default::default()
+                };
+
+                whisper_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))
+            }
+            Self::Parakeet(parakeet_engine) => {
+                let params = ParakeetInferenceParams {
+                    timestamp_granularity: TimestampGranularity::Segment,
+                    ..Default::default()
+                };
+                parakeet_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))
+            }
+            Self::Moonshine(moonshine_engine) => moonshine_engine
+                .transcribe_samples(audio, None)
+                .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e)),
+            Self::MoonshineStreaming(streaming_model) => streaming_model
+                .generate(&audio, STREAMING_MAX_TOKENS_PER_SECOND, None)
+                .and_then(|tokens| streaming_model.decode_tokens(&tokens))
+                .map(|text| transcribe_rs::TranscriptionResult {
+                    text,
+                    segments: None,
+                })
+                .map_err(|e| anyhow::anyhow!("Moonshine streaming transcription failed: {}", e)),
+            Self::SenseVoice(sense_voice_engine) => {
+                let language = match settings.selected_language.as_str() {
+                    "zh" | "zh-Hans" | "zh-Hant" => SenseVoiceLanguage::Chinese,
+                    "en" => SenseVoiceLanguage::English,
+                    "ja" => SenseVoiceLanguage::Japanese,
+                    "ko" => SenseVoiceLanguage::Korean,
+                    "yue" => SenseVoiceLanguage::Cantonese,
+                    _ => SenseVoiceLanguage::Auto,
+                };
+                let params = SenseVoiceInferenceParams {
+                    language,
+                    use_itn: true,
+                };
+                sense_voice_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("SenseVoice transcription failed: {}", e))
+            }
+        }
+    }
+}
+
+/// A local model loaded outside the [`TranscriptionManager`], used by the
+/// command line. It needs no running app and has no idle unloading, events
+/// or cloud routing.
+pub struct LocalTranscriber {
+    engine: LoadedEngine,
+}
+
+impl LocalTranscriber {
+    pub fn load(catalog: &ModelCatalog, model_id: &str) -> Result<Self> {
+        let model_info = catalog
+            .get_model_info(model_id)
+            .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
+        let model_path = catalog.get_model_path(model_id)?;
+        let engine = LoadedEngine::load(&model_info.engine_type, &model_path, model_id)
+            .map_err(anyhow::Error::msg)?;
+        Ok(Self { engine })
+    }
+
+    /// Transcribes 16 kHz mono audio and returns the raw engine text.
+    pub fn transcribe(&mut self, audio: Vec<f32>, settings: &AppSettings) -> Result<String> {
+        if audio.is_empty() {
+            return Ok(String::new());
+        }
+
+        let result = self
+            .engine
+            .transcribe(prepare_transcription_audio(audio), settings)?;
+        Ok(result.text)
+    }
+}
+
 const SAMPLE_RATE: usize = 16_000;
 const CHUNK_SAMPLES: usize = SAMPLE_RATE * 10;
 const CHUNK_OVERLAP_SAMPLES: usize = SAMPLE_RATE * 3 / 2; // 1.5s
@@ -67,6 +229,17 @@ const STOP_IN_FLIGHT_WAIT: Duration = Duration::from_secs(4);
 const INCREMENTAL_TAIL_BASE_TIMEOUT: Duration = Duration::from_secs(6);
 const INCREMENTAL_TAIL_TIMEOUT_PER_CHUNK: Duration = Duration::from_secs(4);
 const INCREMENTAL_TAIL_MAX_TIMEOUT: Duration = Duration::from_secs(30);
+// Local chunks run on a blocking thread that cannot be aborted and hold the engine
+// lock, so cutting them short only delays the tail. Wait for them instead.
+const LOCAL_STOP_IN_FLIGHT_WAIT: Duration = Duration::from_secs(20);
+// The local tail also runs every chunk the worker had not reached yet, so its
+// budget grows with that backlog.
+const LOCAL_INCREMENTAL_TAIL_BASE_TIMEOUT: Duration = Duration::from_secs(60);
+const LOCAL_INCREMENTAL_TAIL_TIMEOUT_PER_CHUNK: Duration = Duration::from_secs(30);
+const STREAMING_PARTIAL_MIN_NEW_SAMPLES: usize = SAMPLE_RATE / 2;
+// Frontend feed size used by the streaming model's own batch path (80 ms).
+const STREAMING_FEED_SAMPLES: usize = 1280;
+const STREAMING_MAX_TOKENS_PER_SECOND: f32 = 6.5;
 const MAX_TOKEN_OVERLAP: usize = 25;
 const MODEL_LOADING_WAIT_TIMEOUT: Duration = Duration::from_secs(12);
 const LIVE_TRANSCRIPTION_OVERLAP_SAMPLES: usize = SAMPLE_RATE * 10;
@@ -79,11 +252,33 @@ const QUIET_AUDIO_MIN_GAIN: f32 = 1.15;
 const MIN_INCREMENTAL_FINALIZATION_CHUNKS: u64 = 2;
 const MIN_ONE_CHUNK_INCREMENTAL_FINALIZATION_SAMPLES: usize = SAMPLE_RATE * 15;
 
+const LOCAL_TRANSCRIPTION_ROUTE: &str = "local";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CloudTranscriptionRoute {
     DirectGroq,
     DirectOpenAi,
     BackendProxy,
+    DirectCustom,
+}
+
+impl CloudTranscriptionRoute {
+    fn as_str(self) -> &'static str {
+        match self {
+            CloudTranscriptionRoute::DirectGroq => "groq",
+            CloudTranscriptionRoute::DirectOpenAi => "openai",
+            CloudTranscriptionRoute::BackendProxy => "backend_proxy",
+            CloudTranscriptionRoute::DirectCustom => "custom",
+        }
+    }
+}
+
+/// The route that produced a transcription and, when the first choice failed,
+/// the route it fell back from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptionRouteRecord {
+    pub route: &'static str,
+    pub fallback_from: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -99,6 +294,8 @@ struct IncrementalRuntime {
     next_chunk_start: AtomicUsize,
     chunk_count: AtomicU64,
     assembled_raw: Mutex<String>,
+    /// Local engine timings of the committed chunks, on the recording timeline.
+    segments: Mutex<Vec<TranscriptSegment>>,
 }
 
 impl IncrementalRuntime {
@@ -110,16 +307,79 @@ impl IncrementalRuntime {
             next_chunk_start: AtomicUsize::new(0),
             chunk_count: AtomicU64::new(0),
             assembled_raw: Mutex::new(String::new()),
+            segments: Mutex::new(Vec::new()),
         }
     }
 }
 
 struct IncrementalSession {
     binding_id: String,
+    local_engine: bool,
     runtime: Arc<IncrementalRuntime>,
     worker_handle: tauri::async_runtime::JoinHandle<()>,
 }
 
+/// Live decoding state of a streaming engine for the audio from
+/// `window_start` on. Each recorded sample is fed to the engine once.
+struct StreamingPartial {
+    window_start: usize,
+    fed_until: usize,
+    state: Option<StreamingState>,
+}
+
+impl StreamingPartial {
+    fn starting_at(window_start: usize) -> Self {
+        Self {
+            window_start,
+            fed_until: window_start,
+            state: None,
+        }
+    }
+}
+
+/// Feeds newly recorded audio through the streaming frontend and encoder, then
+/// greedily decodes the hypothesis for everything fed so far. `fed_samples`
+/// bounds the token count the same way the batch path does.
+fn decode_streaming_hypothesis(
+    model: &mut StreamingModel,
+    state: &mut StreamingState,
+    new_audio: &[f32],
+    fed_samples: usize,
+) -> Result<String, MoonshineError> {
+    for frame in new_audio.chunks(STREAMING_FEED_SAMPLES) {
+        model.process_audio_chunk(state, frame)?;
+    }
+    model.encode(state, false)?;
+    if state.memory_len == 0 {
+        return Ok(String::new());
+    }
+
+    model.compute_cross_kv(state)?;
+    model.decoder_reset(state);
+    let max_tokens = ((fed_samples as f32 / SAMPLE_RATE as f32 * STREAMING_MAX_TOKENS_PER_SECOND)
+        .ceil() as usize)
+        .min(model.config.max_seq_len);
+    let mut tokens = Vec::new();
+    let mut token = model.config.bos_id;
+    for _ in 0..max_tokens {
+        let logits = model.decode_step(state, token)?;
+        let Some(next) = logits
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(index, _)| index as i64)
+        else {
+            break;
+        };
+        if next == model.config.eos_id {
+            break;
+        }
+        tokens.push(next);
+        token = next;
+    }
+    model.decode_tokens(&tokens)
+}
+
 fn normalized_silence_hallucination_text(text: &str) -> String {
     text.chars()
         .flat_map(|ch| ch.to_lowercase())
@@ -289,7 +549,34 @@ fn boost_audio_for_quiet_retry(audio: &[f32], levels: (f32, f32)) -> Option<Vec<
     quiet_transcription_audio_gain(levels).map(|gain| boost_audio_with_gain(audio, gain))
 }
 
-fn choose_local_fallback_model_id(
+/// Applies custom words, output filtering and inverse text normalization to
+/// raw engine text.
+pub fn apply_transcription_filters(raw_transcription: String, settings: &AppSettings) -> String {
+    let corrected_result = if !settings.custom_words.is_empty() {
+        apply_custom_words(
+            &raw_transcription,
+            &settings.custom_words,
+            settings.word_correction_threshold,
+        )
+    } else {
+        raw_transcription
+    };
+
+    let filtered = filter_transcription_output(&corrected_result);
+    if !settings.inverse_text_normalization_enabled {
+        return filtered;
+    }
+
+    // Translated output is always English, whatever the spoken language was.
+    let language = if settings.translate_to_english {
+        "en"
+    } else {
+        settings.selected_language.as_str()
+    };
+    apply_inverse_text_normalization(&filtered, language)
+}
+
+pub(crate) fn choose_local_fallback_model_id(
     available_models: Vec<ModelInfo>,
     preferred_local_model_id: Option<&str>,
 ) -> Option<String> {
@@ -417,6 +704,37 @@ fn incremental_tail_timeout(chunk_count: u64) -> Duration {
     timeout.min(INCREMENTAL_TAIL_MAX_TIMEOUT)
 }
 
+fn local_incremental_tail_timeout(tail_samples: usize) -> Duration {
+    let pending_chunks = tail_samples.div_ceil(CHUNK_SAMPLES) as u32;
+    LOCAL_INCREMENTAL_TAIL_BASE_TIMEOUT
+        + LOCAL_INCREMENTAL_TAIL_TIMEOUT_PER_CHUNK.saturating_mul(pending_chunks)
+}
+
+fn samples_to_ms(samples: usize) -> i64 {
+    (samples as u64 * 1000 / SAMPLE_RATE as u64) as i64
+}
+
+/// Shifts the timings of a chunk starting at `chunk_start` onto the recording
+/// timeline and keeps the segments past `committed_until`, the audio earlier
+/// chunks already cover. Segments centred in the overlap belong to the
+/// earlier chunk, mirroring how the overlapping text is stitched.
+fn merge_chunk_segments(
+    merged: &mut Vec<TranscriptSegment>,
+    chunk_segments: Vec<TranscriptSegment>,
+    chunk_start: usize,
+    committed_until: usize,
+) {
+    let offset_ms = samples_to_ms(chunk_start);
+    let committed_ms = samples_to_ms(committed_until);
+    for mut segment in chunk_segments {
+        segment.start_ms += offset_ms;
+        segment.end_ms += offset_ms;
+        if (segment.start_ms + segment.end_ms) / 2 >= committed_ms {
+            merged.push(segment);
+        }
+    }
+}
+
 fn safe_live_chunk_limit_bytes(route: CloudTranscriptionRoute) -> usize {
     match route {
         CloudTranscriptionRoute::DirectGroq => groq_client::DIRECT_GROQ_UPLOAD_LIMIT_BYTES
@@ -425,6 +743,8 @@ fn safe_live_chunk_limit_bytes(route: CloudTranscriptionRoute) -> usize {
             .saturating_sub(DIRECT_CHUNK_SAFETY_MARGIN_BYTES),
         CloudTranscriptionRoute::BackendProxy => groq_client::PROXY_GROQ_UPLOAD_LIMIT_BYTES
             .saturating_sub(PROXY_CHUNK_SAFETY_MARGIN_BYTES),
+        CloudTranscriptionRoute::DirectCustom => groq_client::DIRECT_CUSTOM_UPLOAD_LIMIT_BYTES
+            .saturating_sub(DIRECT_CHUNK_SAFETY_MARGIN_BYTES),
     }
 }
 
@@ -503,6 +823,10 @@ pub struct TranscriptionManager {
     incremental_session: Arc<Mutex<Option<IncrementalSession>>>,
     cancel_requested: Arc<AtomicBool>,
     cancel_generation: Arc<AtomicU64>,
+    /// Timings from the latest local engine pass, tagged with the input sample count.
+    last_timestamps: Arc<Mutex<Option<(usize, TranscriptTimestamps)>>>,
+    /// Route that produced the latest transcription text.
+    last_route: Arc<Mutex<Option<TranscriptionRouteRecord>>>,
 }
 
 impl TranscriptionManager {
@@ -525,6 +849,8 @@ impl TranscriptionManager {
             incremental_session: Arc::new(Mutex::new(None)),
             cancel_requested: Arc::new(AtomicBool::new(false)),
             cancel_generation: Arc::new(AtomicU64::new(0)),
+            last_timestamps: Arc::new(Mutex::new(None)),
+            last_route: Arc::new(Mutex::new(None)),
         };
 
         // Start the idle watcher
@@ -614,7 +940,7 @@ impl TranscriptionManager {
                     LoadedEngine::Whisper(ref mut e) => e.unload_model(),
                     LoadedEngine::Parakeet(ref mut e) => e.unload_model(),
                     LoadedEngine::Moonshine(ref mut e) => e.unload_model(),
-                    LoadedEngine::MoonshineStreaming(ref mut e) => e.unload_model(),
+                    LoadedEngine::MoonshineStreaming(_) => {}
                     LoadedEngine::SenseVoice(ref mut e) => e.unload_model(),
                 }
             }
@@ -693,6 +1019,24 @@ impl TranscriptionManager {
         None
     }
 
+    fn resolve_custom_transcription_api_key(&self) -> Option<String> {
+        let settings = get_settings(&self.app_handle);
+        match crate::byok_secrets::load_custom_transcription_api_key(&self.app_handle, &settings) {
+            Ok(Some(key)) => {
+                let key = key.trim();
+                if !key.is_empty() {
+                    return Some(key.to_string());
+                }
+            }
+            Ok(None) => {}
+            Err(error) => {
+                warn!("Failed to load custom transcription API key: {}", error);
+            }
+        }
+
+        None
+    }
+
     fn sync_cloud_access_state(
         &self,
         trial_state: TrialState,
@@ -782,6 +1126,8 @@ impl TranscriptionManager {
         audio: Vec<f32>,
         settings: &AppSettings,
     ) -> Result<String> {
+        let sample_count = audio.len();
+
         // Perform transcription with the appropriate local engine.
         // We use catch_unwind to prevent engine panics from poisoning the mutex,
         // which would make the app hang indefinitely on subsequent operations.
@@ -805,70 +1151,7 @@ impl TranscriptionManager {
 
             let transcribe_result = catch_unwind(AssertUnwindSafe(
                 || -> Result<transcribe_rs::TranscriptionResult> {
-                    match &mut engine {
-                        LoadedEngine::Whisper(whisper_engine) => {
-                            let whisper_language = if settings.selected_language == "auto" {
-                                None
-                            } else {
-                                let normalized = if settings.selected_language == "zh-Hans"
-                                    || settings.selected_language == "zh-Hant"
-                                {
-                                    "zh".to_string()
-                                } else {
-                                    settings.selected_language.clone()
-                                };
-                                Some(normalized)
-                            };
-
-                            let params = WhisperInferenceParams {
-                                language: whisper_language,
-                                translate: settings.translate_to_english,
-                                ..Default::default()
-                            };
-
-                            whisper_engine
-                                .transcribe_samples(audio, Some(params))
-                                .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))
-                        }
-                        LoadedEngine::Parakeet(parakeet_engine) => {
-                            let params = ParakeetInferenceParams {
-                                timestamp_granularity: TimestampGranularity::Segment,
-                                ..Default::default()
-                            };
-                            parakeet_engine
-                                .transcribe_samples(audio, Some(params))
-                                .map_err(|e| {
-                                    anyhow::anyhow!("Parakeet transcription failed: {}", e)
-                                })
-                        }
-                        LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                            .transcribe_samples(audio, None)
-                            .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e)),
-                        LoadedEngine::MoonshineStreaming(streaming_engine) => streaming_engine
-                            .transcribe_samples(audio, None)
-                            .map_err(|e| {
-                                anyhow::anyhow!("Moonshine streaming transcription failed: {}", e)
-                            }),
-                        LoadedEngine::SenseVoice(sense_voice_engine) => {
-                            let language = match settings.selected_language.as_str() {
-                                "zh" | "zh-Hans" | "zh-Hant" => SenseVoiceLanguage::Chinese,
-                                "en" => SenseVoiceLanguage::English,
-                                "ja" => SenseVoiceLanguage::Japanese,
-                                "ko" => SenseVoiceLanguage::Korean,
-                                "yue" => SenseVoiceLanguage::Cantonese,
-                                _ => SenseVoiceLanguage::Auto,
-                            };
-                            let params = SenseVoiceInferenceParams {
-                                language,
-                                use_itn: true,
-                            };
-                            sense_voice_engine
-                                .transcribe_samples(audio, Some(params))
-                                .map_err(|e| {
-                                    anyhow::anyhow!("SenseVoice transcription failed: {}", e)
-                                })
-                        }
-                    }
+                    engine.transcribe(audio, settings)
                 },
             ));
 
@@ -921,9 +1204,76 @@ impl TranscriptionManager {
             }
         };
 
+        let segments = result
+            .segments
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|segment| {
+                let text = self.apply_transcription_filters(segment.text, settings);
+                let text = text.trim();
+                (!text.is_empty()).then(|| TranscriptSegment {
+                    start_ms: (segment.start * 1000.0).round() as i64,
+                    end_ms: (segment.end * 1000.0).round() as i64,
+                    text: text.to_string(),
+                })
+            })
+            .collect();
+        *self
+            .last_timestamps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some((
+            sample_count,
+            TranscriptTimestamps {
+                segments,
+                words: Vec::new(),
+            },
+        ));
+
         Ok(result.text)
     }
 
+    /// Takes the timings recorded by the last full local pass when they were
+    /// produced for audio of `sample_count` samples, i.e. the audio being saved.
+    pub fn take_transcript_timestamps(&self, sample_count: usize) -> Option<TranscriptTimestamps> {
+        let last = self
+            .last_timestamps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        match last {
+            Some((count, timestamps))
+                if count == sample_count && !timestamps.segments.is_empty() =>
+            {
+                Some(timestamps)
+            }
+            _ => None,
+        }
+    }
+
+    fn record_transcription_route(&self, route: &'static str, fallback_from: Option<&'static str>) {
+        let mut last_route = self.last_route.lock().unwrap_or_else(|e| e.into_inner());
+        // Chunked dictations keep an earlier fallback so a later healthy chunk
+        // does not hide that part of the text came from another route.
+        let fallback_from =
+            fallback_from.or_else(|| last_route.and_then(|record| record.fallback_from));
+        *last_route = Some(TranscriptionRouteRecord {
+            route,
+            fallback_from,
+        });
+    }
+
+    fn clear_transcription_route(&self) {
+        *self.last_route.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Takes the route recorded for the latest transcription.
+    pub fn take_transcription_route(&self) -> Option<TranscriptionRouteRecord> {
+        self.last_route
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+    }
+
     fn update_last_activity(&self) {
         self.last_activity.store(
             SystemTime::now()
@@ -958,22 +1308,12 @@ impl TranscriptionManager {
         }
     }
 
-    fn apply_transcription_filters(
+    pub fn apply_transcription_filters(
         &self,
         raw_transcription: String,
         settings: &AppSettings,
     ) -> String {
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &raw_transcription,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
-        } else {
-            raw_transcription
-        };
-
-        filter_transcription_output(&corrected_result)
+        apply_transcription_filters(raw_transcription, settings)
     }
 
     fn transcribe_raw_local_with_settings(
@@ -1065,7 +1405,10 @@ impl TranscriptionManager {
         )
         .await
         {
-            Ok(text) => Ok(text),
+            Ok(text) => {
+                self.record_transcription_route(CloudTranscriptionRoute::DirectGroq.as_str(), None);
+                Ok(text)
+            }
             Err(groq_error) => {
                 report_byok_transcription_failure(
                     settings,
@@ -1082,50 +1425,16 @@ impl TranscriptionManager {
                     ));
                 }
 
-                let fallback_local_model_id = self
-                    .select_local_fallback_model_id(
-                        self.get_current_model()
-                            .filter(|id| !is_cloud_model_id(id))
-                            .or_else(|| {
-                                if settings.selected_model.is_empty() {
-                                    None
-                                } else {
-                                    Some(settings.selected_model.clone())
-                                }
-                            })
-                            .as_deref(),
-                    )
-                    .ok_or_else(|| {
-                        anyhow::anyhow!(
-                            "Groq transcription failed: {}. No downloaded local model available for fallback.",
-                            groq_error
-                        )
-                    })?;
-
-                let local_engine_loaded = {
-                    let engine_guard = self.lock_engine();
-                    engine_guard.is_some()
-                };
-                let current_model = self.get_current_model();
-                if current_model.as_deref() != Some(fallback_local_model_id.as_str())
-                    || !local_engine_loaded
-                {
-                    warn!(
-                        "Switching transcription to local fallback model '{}' after Groq failure",
-                        fallback_local_model_id
-                    );
-                    self.load_model(&fallback_local_model_id).map_err(|load_error| {
-                        anyhow::anyhow!(
-                            "Groq transcription failed: {}. Local fallback model '{}' failed to load: {}",
-                            groq_error,
-                            fallback_local_model_id,
-                            load_error
-                        )
-                    })?;
-                }
-
-                self.transcribe_raw_local_with_settings_async(audio, settings)
-                    .await
+                let issue = format!("transcription failed: {}", groq_error);
+                self.transcribe_with_fallback_after_cloud_issue(
+                    CloudTranscriptionRoute::DirectGroq,
+                    "Groq",
+                    &issue,
+                    !groq_error.kind().is_network_failure(),
+                    audio,
+                    settings,
+                )
+                .await
             }
         }
     }
@@ -1164,6 +1473,10 @@ impl TranscriptionManager {
         .await
         {
             Ok(text) => {
+                self.record_transcription_route(
+                    CloudTranscriptionRoute::DirectOpenAi.as_str(),
+                    None,
+                );
                 if !text.trim().is_empty() {
                     return Ok(text);
                 }
@@ -1216,19 +1529,19 @@ impl TranscriptionManager {
                     }
                 }
 
-                if self.resolve_byok_groq_api_key().is_some() {
-                    warn!(
-                        "Retrying empty OpenAI transcription with Groq fallback model '{}'",
-                        GROQ_MODEL_WHISPER_LARGE_V3
-                    );
-                    return self
-                        .transcribe_with_direct_groq(
-                            GROQ_MODEL_WHISPER_LARGE_V3,
-                            audio,
-                            settings,
-                            allow_local_fallback_on_cloud_error,
-                        )
-                        .await;
+                if settings.transcription_fallback_policy == TranscriptionFallbackPolicy::Disabled {
+                    return Ok(text);
+                }
+
+                if let Some(groq_text) = self
+                    .transcribe_with_alternate_cloud_provider(
+                        CloudTranscriptionRoute::DirectOpenAi,
+                        &audio,
+                        settings,
+                    )
+                    .await
+                {
+                    return Ok(groq_text);
                 }
 
                 if !allow_local_fallback_on_cloud_error {
@@ -1238,6 +1551,7 @@ impl TranscriptionManager {
                 }
 
                 self.transcribe_with_local_fallback_after_cloud_issue(
+                    CloudTranscriptionRoute::DirectOpenAi,
                     "OpenAI",
                     "returned an empty transcription for non-silent audio",
                     audio,
@@ -1262,16 +1576,199 @@ impl TranscriptionManager {
                 }
 
                 let issue = format!("transcription failed: {}", openai_error);
-                self.transcribe_with_local_fallback_after_cloud_issue(
-                    "OpenAI", &issue, audio, settings,
+                self.transcribe_with_fallback_after_cloud_issue(
+                    CloudTranscriptionRoute::DirectOpenAi,
+                    "OpenAI",
+                    &issue,
+                    !openai_error.kind().is_network_failure(),
+                    audio,
+                    settings,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn transcribe_with_direct_custom(
+        &self,
+        model_id: &str,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+        allow_local_fallback_on_cloud_error: bool,
+    ) -> Result<String> {
+        let api_key = self.resolve_custom_transcription_api_key();
+
+        let request_started = Instant::now();
+        match groq_client::transcribe_samples_direct_custom(
+            &settings.custom_transcription_base_url,
+            api_key.as_deref(),
+            &settings.custom_transcription_model,
+            &audio,
+            &settings.selected_language,
+            settings.translate_to_english,
+        )
+        .await
+        {
+            Ok(text) => {
+                self.record_transcription_route(
+                    CloudTranscriptionRoute::DirectCustom.as_str(),
+                    None,
+                );
+                Ok(text)
+            }
+            Err(custom_error) => {
+                report_byok_transcription_failure(
+                    settings,
+                    model_id,
+                    audio.len(),
+                    request_started.elapsed(),
+                    &custom_error,
+                );
+
+                if !allow_local_fallback_on_cloud_error {
+                    return Err(anyhow::anyhow!(
+                        "Custom server transcription failed during incremental chunking: {}",
+                        custom_error
+                    ));
+                }
+
+                let issue = format!("transcription failed: {}", custom_error);
+                self.transcribe_with_fallback_after_cloud_issue(
+                    CloudTranscriptionRoute::DirectCustom,
+                    "Custom server",
+                    &issue,
+                    !custom_error.kind().is_network_failure(),
+                    audio,
+                    settings,
                 )
                 .await
             }
         }
     }
 
+    /// Applies the configured fallback policy after `failed_route` could not
+    /// produce a transcript.
+    async fn transcribe_with_fallback_after_cloud_issue(
+        &self,
+        failed_route: CloudTranscriptionRoute,
+        provider_label: &str,
+        issue: &str,
+        try_other_cloud_provider: bool,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+    ) -> Result<String> {
+        match settings.transcription_fallback_policy {
+            TranscriptionFallbackPolicy::Disabled => {
+                return Err(anyhow::anyhow!("{} {}", provider_label, issue));
+            }
+            TranscriptionFallbackPolicy::CloudThenLocal if try_other_cloud_provider => {
+                if let Some(text) = self
+                    .transcribe_with_alternate_cloud_provider(failed_route, &audio, settings)
+                    .await
+                {
+                    return Ok(text);
+                }
+            }
+            TranscriptionFallbackPolicy::LocalModel
+            | TranscriptionFallbackPolicy::CloudThenLocal => {}
+        }
+
+        self.transcribe_with_local_fallback_after_cloud_issue(
+            failed_route,
+            provider_label,
+            issue,
+            audio,
+            settings,
+        )
+        .await
+    }
+
+    /// Retries once on the other direct provider when a key for it is configured.
+    /// Returns `None` when there is no alternate provider or it failed as well.
+    async fn transcribe_with_alternate_cloud_provider(
+        &self,
+        failed_route: CloudTranscriptionRoute,
+        audio: &[f32],
+        settings: &AppSettings,
+    ) -> Option<String> {
+        let (alternate_route, model_id, api_key) = match failed_route {
+            CloudTranscriptionRoute::DirectOpenAi => (
+                CloudTranscriptionRoute::DirectGroq,
+                GROQ_MODEL_WHISPER_LARGE_V3,
+                self.resolve_byok_groq_api_key()?,
+            ),
+            CloudTranscriptionRoute::DirectGroq | CloudTranscriptionRoute::BackendProxy => (
+                CloudTranscriptionRoute::DirectOpenAi,
+                OPENAI_MODEL_GPT_4O_TRANSCRIBE,
+                self.resolve_byok_openai_api_key()?,
+            ),
+            // A self-hosted server is usually chosen to keep audio off third-party
+            // clouds, so only the local model is used as its fallback.
+            CloudTranscriptionRoute::DirectCustom => return None,
+        };
+        warn!(
+            "Retrying transcription with '{}' after {} failure",
+            model_id,
+            failed_route.as_str()
+        );
+
+        let request_started = Instant::now();
+        // The alternate is always one of the two direct providers picked above.
+        let result = if alternate_route == CloudTranscriptionRoute::DirectGroq {
+            groq_client::transcribe_samples_direct(
+                &api_key,
+                groq_api_model_name(model_id)?,
+                audio,
+                &settings.selected_language,
+                settings.translate_to_english,
+            )
+            .await
+        } else {
+            groq_client::transcribe_samples_direct_openai(
+                &api_key,
+                openai_api_model_name(model_id)?,
+                audio,
+                &settings.selected_language,
+                settings.translate_to_english,
+            )
+            .await
+        };
+
+        match result {
+            Ok(text) if !text.trim().is_empty() => {
+                self.record_transcription_route(
+                    alternate_route.as_str(),
+                    Some(failed_route.as_str()),
+                );
+                Some(text)
+            }
+            Ok(_) => {
+                warn!(
+                    "Alternate cloud transcription with '{}' was empty",
+                    model_id
+                );
+                None
+            }
+            Err(error) => {
+                report_byok_transcription_failure(
+                    settings,
+                    model_id,
+                    audio.len(),
+                    request_started.elapsed(),
+                    &error,
+                );
+                warn!(
+                    "Alternate cloud transcription with '{}' failed: {}",
+                    model_id, error
+                );
+                None
+            }
+        }
+    }
+
     async fn transcribe_with_local_fallback_after_cloud_issue(
         &self,
+        failed_route: CloudTranscriptionRoute,
         provider_label: &str,
         issue: &str,
         audio: Vec<f32>,
@@ -1322,8 +1819,11 @@ impl TranscriptionManager {
                 })?;
         }
 
-        self.transcribe_raw_local_with_settings_async(audio, settings)
-            .await
+        let text = self
+            .transcribe_raw_local_with_settings_async(audio, settings)
+            .await?;
+        self.record_transcription_route(LOCAL_TRANSCRIPTION_ROUTE, Some(failed_route.as_str()));
+        Ok(text)
     }
 
     async fn transcribe_with_proxy_groq(
@@ -1379,6 +1879,10 @@ impl TranscriptionManager {
                     result.access_state,
                     result.entitlement_state,
                 );
+                self.record_transcription_route(
+                    CloudTranscriptionRoute::BackendProxy.as_str(),
+                    None,
+                );
                 Ok(result.text)
             }
             Err(error) => {
@@ -1393,50 +1897,16 @@ impl TranscriptionManager {
                         AccessState::Trialing | AccessState::Subscribed
                     )
                 {
-                    let fallback_local_model_id = self
-                        .select_local_fallback_model_id(
-                            self.get_current_model()
-                                .filter(|id| !is_cloud_model_id(id))
-                                .or_else(|| {
-                                    if settings.selected_model.is_empty() {
-                                        None
-                                    } else {
-                                        Some(settings.selected_model.clone())
-                                    }
-                                })
-                                .as_deref(),
-                        )
-                        .ok_or_else(|| {
-                            anyhow::anyhow!(
-                                "Backend transcription failed: {}. No downloaded local model available for fallback.",
-                                error.to_message()
-                            )
-                        })?;
-
-                    let local_engine_loaded = {
-                        let engine_guard = self.lock_engine();
-                        engine_guard.is_some()
-                    };
-                    let current_model = self.get_current_model();
-                    if current_model.as_deref() != Some(fallback_local_model_id.as_str())
-                        || !local_engine_loaded
-                    {
-                        warn!(
-                            "Switching transcription to local fallback model '{}' after backend proxy failure",
-                            fallback_local_model_id
-                        );
-                        self.load_model(&fallback_local_model_id).map_err(|load_error| {
-                            anyhow::anyhow!(
-                                "Backend transcription failed: {}. Local fallback model '{}' failed to load: {}",
-                                error.to_message(),
-                                fallback_local_model_id,
-                                load_error
-                            )
-                        })?;
-                    }
-
+                    let issue = format!("transcription failed: {}", error.to_message());
                     return self
-                        .transcribe_raw_local_with_settings_async(audio, settings)
+                        .transcribe_with_fallback_after_cloud_issue(
+                            CloudTranscriptionRoute::BackendProxy,
+                            "Backend",
+                            &issue,
+                            !error.is_network_failure(),
+                            audio,
+                            settings,
+                        )
                         .await;
                 }
 
@@ -1487,6 +1957,15 @@ impl TranscriptionManager {
                     )
                     .await
                 }
+                CloudTranscriptionRoute::DirectCustom => {
+                    self.transcribe_with_direct_custom(
+                        model_id,
+                        audio,
+                        settings,
+                        allow_local_fallback_on_cloud_error,
+                    )
+                    .await
+                }
             };
         }
 
@@ -1552,6 +2031,15 @@ impl TranscriptionManager {
                     )
                     .await?
                 }
+                CloudTranscriptionRoute::DirectCustom => {
+                    self.transcribe_with_direct_custom(
+                        model_id,
+                        chunk_audio,
+                        settings,
+                        allow_local_fallback_on_cloud_error,
+                    )
+                    .await?
+                }
             };
 
             append_stitched_text(&mut assembled, &chunk_text);
@@ -1604,13 +2092,30 @@ impl TranscriptionManager {
             .unwrap_or(false);
 
         if !is_cloud_model {
-            self.transcribe_raw_local_with_settings_async(audio, settings)
-                .await
+            let text = self
+                .transcribe_raw_local_with_settings_async(audio, settings)
+                .await?;
+            self.record_transcription_route(LOCAL_TRANSCRIPTION_ROUTE, None);
+            Ok(text)
         } else {
             let model_id = active_model_id
                 .as_deref()
                 .ok_or_else(|| anyhow::anyhow!("No cloud model selected for transcription."))?;
 
+            if model_id == CUSTOM_TRANSCRIPTION_MODEL_ID {
+                debug!("Using custom OpenAI-compatible server for cloud transcription");
+                return self
+                    .transcribe_cloud_with_settings(
+                        model_id,
+                        audio,
+                        settings,
+                        allow_local_fallback_on_cloud_error,
+                        proxy_metadata,
+                        CloudTranscriptionRoute::DirectCustom,
+                    )
+                    .await;
+            }
+
             if openai_api_model_name(model_id).is_some() {
                 debug!("Using direct OpenAI routing for cloud transcription");
                 return self
@@ -1713,111 +2218,19 @@ impl TranscriptionManager {
 
         let model_path = self.model_manager.get_model_path(model_id)?;
 
-        // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
-            EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
-                LoadedEngine::Whisper(engine)
-            }
-            EngineType::Parakeet => {
-                let mut engine = ParakeetEngine::new();
-                engine
-                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Parakeet(engine)
-            }
-            EngineType::Moonshine => {
-                let mut engine = MoonshineEngine::new();
-                engine
-                    .load_model_with_params(
-                        &model_path,
-                        MoonshineModelParams::variant(ModelVariant::Base),
-                    )
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load moonshine model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Moonshine(engine)
-            }
-            EngineType::MoonshineStreaming => {
-                let mut engine = MoonshineStreamingEngine::new();
-                engine
-                    .load_model_with_params(&model_path, StreamingModelParams::default())
-                    .map_err(|e| {
-                        let error_msg = format!(
-                            "Failed to load moonshine streaming model {}: {}",
-                            model_id, e
-                        );
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::MoonshineStreaming(engine)
-            }
-            EngineType::SenseVoice => {
-                let mut engine = SenseVoiceEngine::new();
-                engine
-                    .load_model_with_params(&model_path, SenseVoiceModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load SenseVoice model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::SenseVoice(engine)
-            }
-        };
+        let loaded_engine = LoadedEngine::load(&model_info.engine_type, &model_path, model_id)
+            .map_err(|error_msg| {
+                let _ = self.app_handle.emit(
+                    "model-state-changed",
+                    ModelStateEvent {
+                        event_type: "loading_failed".to_string(),
+                        model_id: Some(model_id.to_string()),
+                        model_name: Some(model_info.name.clone()),
+                        error: Some(error_msg.clone()),
+                    },
+                );
+                anyhow::anyhow!(error_msg)
+            })?;
 
         // Update the current engine and model ID
         {
@@ -1913,31 +2326,36 @@ impl TranscriptionManager {
         self.clear_cancel_request();
 
         let settings = get_settings(&self.app_handle);
-        if !settings.incremental_transcription_enabled {
-            return Ok(());
-        }
-
-        if settings.translate_to_english {
-            debug!(
-                "Skipping incremental session for binding '{}' because translate_to_english is enabled",
-                binding_id
-            );
-            return Ok(());
-        }
-
         let active_model_id = if settings.selected_model.is_empty() {
             self.get_current_model().unwrap_or_default()
         } else {
             settings.selected_model.clone()
         };
-        if active_model_id.is_empty() || !is_cloud_model_id(&active_model_id) {
+        if active_model_id.is_empty() {
             debug!(
-                "Skipping incremental session for binding '{}' because active model '{}' is not cloud",
-                binding_id, active_model_id
+                "Skipping incremental session for binding '{}' because no model is active",
+                binding_id
+            );
+            return Ok(());
+        }
+        let local_engine = !is_cloud_model_id(&active_model_id);
+        let streaming_partials = local_engine
+            && self
+                .model_manager
+                .get_model_info(&active_model_id)
+                .is_some_and(|info| matches!(info.engine_type, EngineType::MoonshineStreaming));
+        // Live partials from a streaming engine do not depend on chunked finalization.
+        let commit_chunks =
+            settings.incremental_transcription_enabled && !settings.translate_to_english;
+        if !commit_chunks && !streaming_partials {
+            debug!(
+                "Skipping incremental session for binding '{}' because chunking is off and the model does not stream",
+                binding_id
             );
             return Ok(());
         }
 
+        self.clear_transcription_route();
         let runtime = Arc::new(IncrementalRuntime::new());
         let binding = binding_id.to_string();
         let manager_clone = self.clone();
@@ -1945,7 +2363,13 @@ impl TranscriptionManager {
         let worker_binding = binding.clone();
         let worker_handle = tauri::async_runtime::spawn(async move {
             manager_clone
-                .run_incremental_worker(worker_binding, audio_manager, runtime_clone)
+                .run_incremental_worker(
+                    worker_binding,
+                    audio_manager,
+                    runtime_clone,
+                    commit_chunks,
+                    streaming_partials,
+                )
                 .await;
         });
 
@@ -1959,6 +2383,7 @@ impl TranscriptionManager {
         }
         *guard = Some(IncrementalSession {
             binding_id: binding,
+            local_engine,
             runtime,
             worker_handle,
         });
@@ -1971,10 +2396,14 @@ impl TranscriptionManager {
         binding_id: String,
         audio_manager: Arc<AudioRecordingManager>,
         runtime: Arc<IncrementalRuntime>,
+        commit_chunks: bool,
+        streaming_partials: bool,
     ) {
         let started_at = Instant::now();
         let mut speech_buffer = Vec::<f32>::new();
         let mut saw_pause = false;
+        let mut partial_decoded_len = 0usize;
+        let mut streaming_partial = StreamingPartial::starting_at(0);
 
         loop {
             if runtime.stop_requested.load(Ordering::Relaxed)
@@ -1984,7 +2413,8 @@ impl TranscriptionManager {
                 break;
             }
 
-            if started_at.elapsed() < CHUNK_WARMUP_DURATION {
+            // Streaming engines decode from the first frame so partials show up while speaking.
+            if !streaming_partials && started_at.elapsed() < CHUNK_WARMUP_DURATION {
                 sleep(Duration::from_millis(200)).await;
                 continue;
             }
@@ -2003,6 +2433,10 @@ impl TranscriptionManager {
             }
 
             'chunking: loop {
+                if !commit_chunks {
+                    break;
+                }
+
                 let next_start = runtime.next_chunk_start.load(Ordering::Relaxed);
                 let available = speech_buffer.len().saturating_sub(next_start);
                 if available < CHUNK_SAMPLES {
@@ -2078,6 +2512,14 @@ impl TranscriptionManager {
                         if self.cancel_requested.load(Ordering::Relaxed) {
                             break 'chunking;
                         }
+                        if !is_cloud_model {
+                            self.commit_incremental_segments(
+                                &runtime,
+                                chunk_start,
+                                chunk_end - chunk_start,
+                                next_start,
+                            );
+                        }
                         let mut assembled = runtime.assembled_raw.lock().unwrap();
                         append_stitched_text(&mut assembled, &chunk_text);
                         runtime.next_chunk_start.store(chunk_end, Ordering::Relaxed);
@@ -2109,12 +2551,110 @@ impl TranscriptionManager {
                 break;
             }
 
+            if streaming_partials
+                && speech_buffer.len() >= partial_decoded_len + STREAMING_PARTIAL_MIN_NEW_SAMPLES
+                && !runtime.stop_requested.load(Ordering::Relaxed)
+            {
+                partial_decoded_len = speech_buffer.len();
+                self.emit_streaming_partial(&runtime, &mut streaming_partial, &speech_buffer)
+                    .await;
+                made_progress = true;
+            }
+
             if !made_progress {
                 sleep(CHUNK_POLL_INTERVAL).await;
             }
         }
     }
 
+    /// Feeds the audio recorded since the last partial to the streaming engine and
+    /// shows its hypothesis, stitched onto the committed text, in the recording
+    /// overlay. Partials never touch the saved timings or the committed text.
+    async fn emit_streaming_partial(
+        &self,
+        runtime: &IncrementalRuntime,
+        partial: &mut StreamingPartial,
+        speech_buffer: &[f32],
+    ) {
+        // A committed chunk replaces what the stream decoded so far; restart it at
+        // the overlap before the audio that is still uncommitted.
+        let window_start = runtime
+            .next_chunk_start
+            .load(Ordering::Relaxed)
+            .saturating_sub(CHUNK_OVERLAP_SAMPLES);
+        if partial.window_start != window_start {
+            *partial = StreamingPartial::starting_at(window_start);
+        }
+
+        let new_audio = speech_buffer[partial.fed_until..].to_vec();
+        let fed_samples = speech_buffer.len() - partial.window_start;
+        let mut state = partial.state.take();
+        let manager = self.clone();
+
+        runtime.in_flight.store(true, Ordering::Relaxed);
+        let decoded = tauri::async_runtime::spawn_blocking(move || {
+            let text = manager.decode_streaming_partial(&mut state, &new_audio, fed_samples);
+            (state, text)
+        })
+        .await;
+        runtime.in_flight.store(false, Ordering::Relaxed);
+
+        let partial_result = match decoded {
+            Ok((state, Ok(text))) => {
+                partial.state = state;
+                partial.fed_until = speech_buffer.len();
+                Ok(text)
+            }
+            // The stream state is unusable after a failed step; refeed the window.
+            Ok((_, Err(err))) => {
+                *partial = StreamingPartial::starting_at(window_start);
+                Err(err)
+            }
+            Err(join_err) => {
+                *partial = StreamingPartial::starting_at(window_start);
+                Err(anyhow::anyhow!(
+                    "Streaming partial task failed to join: {}",
+                    join_err
+                ))
+            }
+        };
+
+        // The overlay has moved on to the transcribing state once stop is requested.
+        if runtime.stop_requested.load(Ordering::Relaxed)
+            || self.cancel_requested.load(Ordering::Relaxed)
+        {
+            return;
+        }
+
+        match partial_result {
+            Ok(partial_text) => {
+                let mut preview = runtime.assembled_raw.lock().unwrap().clone();
+                append_stitched_text(&mut preview, &partial_text);
+                if !preview.is_empty() {
+                    crate::overlay::emit_overlay_partial(&self.app_handle, &preview);
+                }
+            }
+            Err(err) => {
+                debug!("Streaming partial decode failed: {}", err);
+            }
+        }
+    }
+
+    fn decode_streaming_partial(
+        &self,
+        state: &mut Option<StreamingState>,
+        new_audio: &[f32],
+        fed_samples: usize,
+    ) -> Result<String> {
+        let mut engine_guard = self.lock_engine();
+        let Some(LoadedEngine::MoonshineStreaming(model)) = engine_guard.as_mut() else {
+            return Err(anyhow::anyhow!("Streaming model is not loaded"));
+        };
+        let state = state.get_or_insert_with(|| model.create_state());
+        decode_streaming_hypothesis(model, state, new_audio, fed_samples)
+            .map_err(|e| anyhow::anyhow!("Moonshine streaming partial failed: {}", e))
+    }
+
     pub async fn finish_incremental_session(
         &self,
         binding_id: &str,
@@ -2153,8 +2693,13 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!("Transcription cancelled"));
         }
 
+        let in_flight_wait = if session.local_engine {
+            LOCAL_STOP_IN_FLIGHT_WAIT
+        } else {
+            STOP_IN_FLIGHT_WAIT
+        };
         let mut worker_handle = session.worker_handle;
-        match timeout(STOP_IN_FLIGHT_WAIT, async { (&mut worker_handle).await }).await {
+        match timeout(in_flight_wait, async { (&mut worker_handle).await }).await {
             Ok(join_result) => {
                 if let Err(join_err) = join_result {
                     warn!("Incremental worker join error: {}", join_err);
@@ -2175,6 +2720,7 @@ impl TranscriptionManager {
             ) {
                 return self.finalize_completed_incremental_chunks(
                     &session.runtime,
+                    full_samples.len(),
                     completed_chunk_count,
                     next_chunk_start,
                     0,
@@ -2227,7 +2773,11 @@ impl TranscriptionManager {
             session.runtime.in_flight.store(true, Ordering::Relaxed);
             let tail_st = Instant::now();
             let settings = get_settings(&self.app_handle);
-            let tail_timeout = incremental_tail_timeout(completed_chunk_count);
+            let tail_timeout = if session.local_engine {
+                local_incremental_tail_timeout(tail_sample_count)
+            } else {
+                incremental_tail_timeout(completed_chunk_count)
+            };
             let tail_result = timeout(
                 tail_timeout,
                 self.transcribe_raw_with_settings(
@@ -2245,6 +2795,14 @@ impl TranscriptionManager {
                     if self.cancel_requested.load(Ordering::Relaxed) {
                         return Err(anyhow::anyhow!("Transcription cancelled"));
                     }
+                    if session.local_engine {
+                        self.commit_incremental_segments(
+                            &session.runtime,
+                            tail_start,
+                            tail_sample_count,
+                            next_chunk_start,
+                        );
+                    }
                     let mut assembled = session.runtime.assembled_raw.lock().unwrap();
                     append_stitched_text(&mut assembled, &tail_text);
                     debug!(
@@ -2259,6 +2817,7 @@ impl TranscriptionManager {
                     );
                     return self.finalize_completed_incremental_chunks(
                         &session.runtime,
+                        full_samples.len(),
                         completed_chunk_count,
                         next_chunk_start,
                         tail_sample_count,
@@ -2273,6 +2832,7 @@ impl TranscriptionManager {
                     );
                     return self.finalize_completed_incremental_chunks(
                         &session.runtime,
+                        full_samples.len(),
                         completed_chunk_count,
                         next_chunk_start,
                         tail_sample_count,
@@ -2293,6 +2853,7 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!("Transcription cancelled"));
         }
 
+        self.store_incremental_timestamps(&session.runtime, full_samples.len());
         let settings = get_settings(&self.app_handle);
         let final_result = self.apply_transcription_filters(raw, &settings);
         info!(
@@ -2308,6 +2869,7 @@ impl TranscriptionManager {
     fn finalize_completed_incremental_chunks(
         &self,
         runtime: &IncrementalRuntime,
+        full_sample_count: usize,
         completed_chunk_count: u64,
         next_chunk_start: usize,
         tail_samples: usize,
@@ -2324,6 +2886,7 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!("Transcription cancelled"));
         }
 
+        self.store_incremental_timestamps(runtime, full_sample_count);
         let settings = get_settings(&self.app_handle);
         let final_result = self.apply_transcription_filters(raw, &settings);
         info!(
@@ -2339,6 +2902,41 @@ impl TranscriptionManager {
         Ok(final_result)
     }
 
+    /// Moves the timings of the local pass over `sample_count` samples starting
+    /// at `chunk_start` into the session, skipping audio up to `committed_until`.
+    fn commit_incremental_segments(
+        &self,
+        runtime: &IncrementalRuntime,
+        chunk_start: usize,
+        sample_count: usize,
+        committed_until: usize,
+    ) {
+        if let Some(timestamps) = self.take_transcript_timestamps(sample_count) {
+            merge_chunk_segments(
+                &mut runtime.segments.lock().unwrap(),
+                timestamps.segments,
+                chunk_start,
+                committed_until,
+            );
+        }
+    }
+
+    /// Records the merged chunk timings as those of the full recording so the
+    /// caller can save them like a full-pass result.
+    fn store_incremental_timestamps(&self, runtime: &IncrementalRuntime, full_sample_count: usize) {
+        let segments = std::mem::take(&mut *runtime.segments.lock().unwrap());
+        *self
+            .last_timestamps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some((
+            full_sample_count,
+            TranscriptTimestamps {
+                segments,
+                words: Vec::new(),
+            },
+        ));
+    }
+
     pub fn signal_incremental_stop(&self, binding_id: &str) {
         let guard = self.incremental_session.lock().unwrap();
         if let Some(session) = guard.as_ref() {
@@ -2430,6 +3028,11 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!("Transcription cancelled"));
         }
 
+        *self
+            .last_timestamps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = None;
+        self.clear_transcription_route();
         let settings = get_settings(&self.app_handle);
         let raw_transcription = self
             .transcribe_raw_with_settings(
@@ -2703,6 +3306,51 @@ mod tests {
                 "chunks={chunks}"
             );
         }
+
+        let local_cases = [
+            (SAMPLE_RATE * 5, 90),
+            (CHUNK_SAMPLES + CHUNK_OVERLAP_SAMPLES, 120),
+            (CHUNK_SAMPLES * 10, 360),
+        ];
+        for (tail_samples, expected_secs) in local_cases {
+            assert_eq!(
+                local_incremental_tail_timeout(tail_samples),
+                Duration::from_secs(expected_secs),
+                "tail_samples={tail_samples}"
+            );
+        }
+    }
+
+    #[test]
+    fn chunk_segments_are_offset_and_drop_the_overlap() {
+        let segment = |start_ms, end_ms, text: &str| TranscriptSegment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+        };
+        let mut merged = Vec::new();
+        merge_chunk_segments(
+            &mut merged,
+            vec![segment(0, 4_000, "first"), segment(4_000, 10_000, "second")],
+            0,
+            0,
+        );
+        // The second chunk starts 1.5 s before the first one ended.
+        merge_chunk_segments(
+            &mut merged,
+            vec![segment(0, 1_400, "second"), segment(1_400, 6_000, "third")],
+            CHUNK_SAMPLES - CHUNK_OVERLAP_SAMPLES,
+            CHUNK_SAMPLES,
+        );
+
+        assert_eq!(
+            merged,
+            vec![
+                segment(0, 4_000, "first"),
+                segment(4_000, 10_000, "second"),
+                segment(9_900, 14_500, "third"),
+            ]
+        );
     }
 
     #[test]
@@ -2710,6 +3358,7 @@ mod tests {
         for route in [
             CloudTranscriptionRoute::DirectGroq,
             CloudTranscriptionRoute::DirectOpenAi,
+            CloudTranscriptionRoute::DirectCustom,
         ] {
             let limit = safe_live_chunk_limit_bytes(route);
             let ranges = plan_live_chunk_ranges(SAMPLE_RATE * 60 * 31, limit).unwrap();
@@ -4,6 +4,8 @@
 
 use crate::managers::model::ModelManager;
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::TranscriptTimestamps;
+use crate::settings::AppSettings;
 use anyhow::Result;
 use serde::Serialize;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -18,6 +20,12 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptionRouteRecord {
+    pub route: &'static str,
+    pub fallback_from: Option<&'static str>,
+}
+
 #[derive(Clone)]
 pub struct TranscriptionManager {
     #[allow(dead_code)]
@@ -67,6 +75,30 @@ impl TranscriptionManager {
         self.transcribe(audio).await
     }
 
+    pub fn apply_transcription_filters(
+        &self,
+        raw_transcription: String,
+        _settings: &AppSettings,
+    ) -> String {
+        raw_transcription
+    }
+
+    pub async fn transcribe_local_file_with_settings(
+        &self,
+        _audio: Vec<f32>,
+        _settings: &AppSettings,
+    ) -> Result<String> {
+        Ok(String::new())
+    }
+
+    pub fn take_transcript_timestamps(&self, _sample_count: usize) -> Option<TranscriptTimestamps> {
+        None
+    }
+
+    pub fn take_transcription_route(&self) -> Option<TranscriptionRouteRecord> {
+        None
+    }
+
     pub fn start_incremental_session(
         &self,
         _binding_id: &str,
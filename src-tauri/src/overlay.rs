@@ -44,6 +44,7 @@ const OVERLAY_WIDTH: f64 = 172.0;
 const OVERLAY_HEIGHT: f64 = 42.0;
 const OVERLAY_ALERT_WIDTH: f64 = 260.0;
 const OVERLAY_ALERT_HEIGHT: f64 = 72.0;
+const OVERLAY_PARTIAL_WIDTH: f64 = 360.0;
 const OVERLAY_LABEL_BASE: &str = "recording_overlay";
 const ASK_SELECTION_LABEL: &str = "ask_selection_panel";
 const ASK_SELECTION_WIDTH: f64 = 760.0;
@@ -756,6 +757,33 @@ pub fn emit_overlay_alert(app_handle: &AppHandle, kind: &str) {
     }
 }
 
+/// Shows a live partial transcript in the recording overlay, widening it to fit.
+pub fn emit_overlay_partial(app_handle: &AppHandle, text: &str) {
+    apply_overlay_dimensions(app_handle, OVERLAY_PARTIAL_WIDTH, OVERLAY_HEIGHT);
+    #[cfg(target_os = "macos")]
+    {
+        let emit_epoch = current_overlay_session_epoch();
+        let text = text.to_string();
+        let app = app_handle.clone();
+        let _ = app_handle.run_on_main_thread(move || {
+            if !overlay_session_epoch_is_current(emit_epoch) {
+                return;
+            }
+            if let Some(overlay_window) = app.get_webview_window(OVERLAY_LABEL_BASE) {
+                let _ = overlay_window.emit("overlay-partial", &text);
+            }
+        });
+        return;
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        if let Some(overlay_window) = app_handle.get_webview_window(OVERLAY_LABEL_BASE) {
+            let _ = overlay_window.emit("overlay-partial", text.to_string());
+        }
+    }
+}
+
 /// Updates the overlay window position based on current settings
 pub fn update_overlay_position(app_handle: &AppHandle) {
     #[cfg(target_os = "macos")]
@@ -0,0 +1,637 @@
+//! Headless `uttr-eval` runner for the post-processing golden set.
+//!
+//! Sends every case in `evals/post-processing/golden.jsonl` through the app's
+//! own `post_process_transcription` path, scores the output against the case
+//! expectations and writes JSON and Markdown reports broken down by tag. The
+//! `--replay` mode re-scores outputs recorded by an earlier run so it works
+//! without network access or API keys. Like `uttr transcribe`, it reads the
+//! app's settings and keys from disk and never starts the Tauri runtime.
+
+use crate::actions::post_process_transcription;
+use crate::headless::{app_data_dir, flag_value, run_command, ParseOutcome};
+use crate::settings::{read_settings_in_dir, AppSettings, CleaningPromptPreset};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+const DEFAULT_DATASET: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../evals/post-processing/golden.jsonl"
+);
+const DEFAULT_OUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../evals/post-processing/runs");
+
+const USAGE: &str = "\
+Usage: uttr-eval [OPTIONS]
+
+Runs the post-processing golden set through the configured post-processing
+provider and writes results.json, results.jsonl and report.md to a new run
+directory.
+
+Options:
+      --dataset <PATH>      Golden JSONL file [default: evals/post-processing/golden.jsonl]
+      --out-dir <PATH>      Directory for run reports [default: evals/post-processing/runs]
+      --provider <ID>       Post-processing provider id (defaults to the app setting)
+      --model <ID>          Model for the provider (defaults to the app setting)
+      --prompt-file <PATH>  Use this file as the system prompt, e.g. prompts/nuanced.md
+      --replay <PATH>       Re-score outputs from an earlier run's results.jsonl instead
+                            of calling a provider
+      --ids <ID,...>        Only run these case ids
+  -h, --help                Print this help
+";
+
+#[derive(Debug, Clone, PartialEq)]
+struct EvalOptions {
+    dataset: PathBuf,
+    out_dir: PathBuf,
+    provider: Option<String>,
+    model: Option<String>,
+    prompt_file: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoldenCase {
+    id: String,
+    input: String,
+    #[serde(default)]
+    expected: GoldenExpectations,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct GoldenExpectations {
+    must_include: Vec<String>,
+    must_include_exact: Vec<String>,
+    must_not_include: Vec<String>,
+    must_not_include_exact: Vec<String>,
+    must_not_equal: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct EvalCheck {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    passed: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct CaseResult {
+    id: String,
+    tags: Vec<String>,
+    input: String,
+    output: String,
+    latency_ms: Option<u64>,
+    passed: bool,
+    checks: Vec<EvalCheck>,
+}
+
+/// The subset of a recorded [`CaseResult`] needed to replay it.
+#[derive(Debug, Deserialize)]
+struct RecordedOutput {
+    id: String,
+    input: String,
+    output: String,
+}
+
+#[derive(Debug, Serialize)]
+struct RunInfo {
+    id: String,
+    dataset: String,
+    provider: String,
+    model: String,
+    prompt: String,
+    started_at: String,
+    finished_at: String,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq)]
+struct TagSummary {
+    total: usize,
+    passed: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RunSummary {
+    total: usize,
+    passed: usize,
+    by_tag: BTreeMap<String, TagSummary>,
+}
+
+#[derive(Serialize)]
+struct RunReport<'a> {
+    run: &'a RunInfo,
+    summary: &'a RunSummary,
+    results: &'a [CaseResult],
+}
+
+/// Entry point for the `uttr-eval` binary. `args` excludes the program name.
+pub fn run_post_process_eval(args: Vec<String>) -> i32 {
+    run_command(parse_eval_args(&args), USAGE, run_eval)
+}
+
+fn parse_eval_args(args: &[String]) -> Result<ParseOutcome<EvalOptions>, String> {
+    let mut options = EvalOptions {
+        dataset: PathBuf::from(DEFAULT_DATASET),
+        out_dir: PathBuf::from(DEFAULT_OUT_DIR),
+        provider: None,
+        model: None,
+        prompt_file: None,
+        replay: None,
+        ids: Vec::new(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value_for = |flag: &str| flag_value(&mut iter, flag);
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(ParseOutcome::Help),
+            "--dataset" => options.dataset = PathBuf::from(value_for(arg)?),
+            "--out-dir" => options.out_dir = PathBuf::from(value_for(arg)?),
+            "--provider" => options.provider = Some(value_for(arg)?),
+            "--model" => options.model = Some(value_for(arg)?),
+            "--prompt-file" => options.prompt_file = Some(PathBuf::from(value_for(arg)?)),
+            "--replay" => options.replay = Some(PathBuf::from(value_for(arg)?)),
+            "--ids" => {
+                options.ids = value_for(arg)?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            other => return Err(format!("unknown argument '{}'", other)),
+        }
+    }
+
+    if options.replay.is_some()
+        && (options.provider.is_some() || options.model.is_some() || options.prompt_file.is_some())
+    {
+        return Err("--replay cannot be combined with --provider, --model or --prompt-file".into());
+    }
+
+    Ok(ParseOutcome::Run(options))
+}
+
+fn read_jsonl<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|error| format!("{}:{}: {}", path.display(), index + 1, error))
+        })
+        .collect()
+}
+
+fn select_cases(cases: Vec<GoldenCase>, ids: &[String]) -> Result<Vec<GoldenCase>, String> {
+    if ids.is_empty() {
+        return Ok(cases);
+    }
+
+    let missing: Vec<&str> = ids
+        .iter()
+        .filter(|id| !cases.iter().any(|case| &case.id == *id))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("unknown case id(s): {}", missing.join(", ")));
+    }
+
+    Ok(cases
+        .into_iter()
+        .filter(|case| ids.contains(&case.id))
+        .collect())
+}
+
+/// Applies the eval overrides to a copy of the app settings. Nothing is saved.
+fn eval_settings(mut settings: AppSettings, options: &EvalOptions) -> Result<AppSettings, String> {
+    if let Some(provider) = &options.provider {
+        if !settings
+            .post_process_providers
+            .iter()
+            .any(|candidate| &candidate.id == provider)
+        {
+            return Err(format!("unknown post-processing provider '{}'", provider));
+        }
+        settings.post_process_provider_id = provider.clone();
+    }
+
+    if let Some(model) = &options.model {
+        settings
+            .post_process_models
+            .insert(settings.post_process_provider_id.clone(), model.clone());
+    }
+
+    if let Some(path) = &options.prompt_file {
+        let prompt = std::fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+        settings.post_process_cleaning_prompt_preset = CleaningPromptPreset::Custom;
+        settings.post_process_system_prompt = prompt;
+    }
+
+    // Cases are scored against the prompt alone, without the user's vocabulary.
+    settings.custom_vocabulary_terms.clear();
+    settings.post_process_enabled = true;
+    Ok(settings)
+}
+
+fn contains_ignoring_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Mirrors `scoreCase` in scripts/run-post-processing-evals.mjs, so both
+/// runners report the same pass rate for the same outputs.
+fn score_case(expected: &GoldenExpectations, output: &str) -> Vec<EvalCheck> {
+    let check = |name, value: &String, passed| EvalCheck {
+        name,
+        value: Some(value.clone()),
+        passed,
+    };
+
+    let mut checks = vec![EvalCheck {
+        name: "non_empty",
+        value: None,
+        passed: !output.trim().is_empty(),
+    }];
+    for phrase in &expected.must_include {
+        checks.push(check(
+            "must_include",
+            phrase,
+            contains_ignoring_case(output, phrase),
+        ));
+    }
+    for phrase in &expected.must_include_exact {
+        checks.push(check(
+            "must_include_exact",
+            phrase,
+            output.contains(phrase.as_str()),
+        ));
+    }
+    for phrase in &expected.must_not_include {
+        checks.push(check(
+            "must_not_include",
+            phrase,
+            !contains_ignoring_case(output, phrase),
+        ));
+    }
+    for phrase in &expected.must_not_include_exact {
+        checks.push(check(
+            "must_not_include_exact",
+            phrase,
+            !output.contains(phrase.as_str()),
+        ));
+    }
+    for phrase in &expected.must_not_equal {
+        checks.push(check(
+            "must_not_equal",
+            phrase,
+            output.trim().to_lowercase() != phrase.trim().to_lowercase(),
+        ));
+    }
+    checks
+}
+
+fn summarize(results: &[CaseResult]) -> RunSummary {
+    let mut by_tag: BTreeMap<String, TagSummary> = BTreeMap::new();
+    for result in results {
+        for tag in &result.tags {
+            let summary = by_tag.entry(tag.clone()).or_default();
+            summary.total += 1;
+            if result.passed {
+                summary.passed += 1;
+            }
+        }
+    }
+
+    RunSummary {
+        total: results.len(),
+        passed: results.iter().filter(|result| result.passed).count(),
+        by_tag,
+    }
+}
+
+fn pass_rate(passed: usize, total: usize) -> String {
+    if total == 0 {
+        return "-".to_string();
+    }
+    format!("{:.0}%", passed as f64 * 100.0 / total as f64)
+}
+
+fn markdown_report(run: &RunInfo, summary: &RunSummary, results: &[CaseResult]) -> String {
+    let mut lines = vec![
+        "# Post-Processing Eval Report".to_string(),
+        String::new(),
+        format!("- Run: {}", run.id),
+        format!("- Dataset: {}", run.dataset),
+        format!("- Provider: {}", run.provider),
+        format!("- Model: {}", run.model),
+        format!("- Prompt: {}", run.prompt),
+        format!("- Started: {}", run.started_at),
+        format!("- Finished: {}", run.finished_at),
+        format!(
+            "- Passed: {}/{} ({})",
+            summary.passed,
+            summary.total,
+            pass_rate(summary.passed, summary.total)
+        ),
+        String::new(),
+        "## By Tag".to_string(),
+        String::new(),
+        "| tag | passed | total | pass rate |".to_string(),
+        "|---|---:|---:|---:|".to_string(),
+    ];
+    for (tag, tag_summary) in &summary.by_tag {
+        lines.push(format!(
+            "| {} | {} | {} | {} |",
+            tag,
+            tag_summary.passed,
+            tag_summary.total,
+            pass_rate(tag_summary.passed, tag_summary.total)
+        ));
+    }
+
+    let failures: Vec<&CaseResult> = results.iter().filter(|result| !result.passed).collect();
+    if !failures.is_empty() {
+        lines.push(String::new());
+        lines.push("## Failures".to_string());
+    }
+    for result in failures {
+        let failed_checks: Vec<String> = result
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| match &check.value {
+                Some(value) => format!("{}: {}", check.name, value),
+                None => check.name.to_string(),
+            })
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("### {}", result.id));
+        lines.push(String::new());
+        lines.push(format!("Tags: {}", result.tags.join(", ")));
+        lines.push(format!("Failed checks: {}", failed_checks.join("; ")));
+        lines.push(String::new());
+        lines.push("```text".to_string());
+        lines.push(result.input.clone());
+        lines.push("```".to_string());
+        lines.push(String::new());
+        lines.push("```text".to_string());
+        lines.push(result.output.clone());
+        lines.push("```".to_string());
+    }
+
+    format!("{}\n", lines.join("\n"))
+}
+
+fn write_reports(
+    run_dir: &Path,
+    run: &RunInfo,
+    summary: &RunSummary,
+    results: &[CaseResult],
+) -> Result<(), String> {
+    std::fs::create_dir_all(run_dir)
+        .map_err(|error| format!("Failed to create {}: {}", run_dir.display(), error))?;
+
+    let write = |name: &str, contents: String| {
+        let path = run_dir.join(name);
+        std::fs::write(&path, contents)
+            .map_err(|error| format!("Failed to write {}: {}", path.display(), error))
+    };
+
+    let report = RunReport {
+        run,
+        summary,
+        results,
+    };
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|error| format!("Failed to serialize eval report: {}", error))?;
+    write("results.json", format!("{}\n", json))?;
+
+    let mut jsonl = String::new();
+    for result in results {
+        let line = serde_json::to_string(result)
+            .map_err(|error| format!("Failed to serialize eval result: {}", error))?;
+        jsonl.push_str(&line);
+        jsonl.push('\n');
+    }
+    write("results.jsonl", jsonl)?;
+    write("report.md", markdown_report(run, summary, results))
+}
+
+async fn run_eval(options: EvalOptions) -> Result<(), String> {
+    let app_data_dir = app_data_dir()?;
+    let cases = select_cases(read_jsonl::<GoldenCase>(&options.dataset)?, &options.ids)?;
+    let started_at = chrono::Utc::now();
+
+    let (settings, provider, model, prompt) = match &options.replay {
+        Some(path) => (
+            None,
+            "replay".to_string(),
+            path.display().to_string(),
+            "recorded".to_string(),
+        ),
+        None => {
+            let settings = eval_settings(read_settings_in_dir(&app_data_dir), &options)?;
+            let provider = settings.post_process_provider_id.clone();
+            let model = settings
+                .post_process_models
+                .get(&provider)
+                .cloned()
+                .unwrap_or_default();
+            let prompt = match &options.prompt_file {
+                Some(path) => path.display().to_string(),
+                None => format!("{:?}", settings.post_process_cleaning_prompt_preset),
+            };
+            (Some(settings), provider, model, prompt)
+        }
+    };
+
+    let recorded: HashMap<String, RecordedOutput> = match &options.replay {
+        Some(path) => read_jsonl::<RecordedOutput>(path)?
+            .into_iter()
+            .map(|record| (record.id.clone(), record))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    let mut results = Vec::with_capacity(cases.len());
+    for case in cases {
+        let started = Instant::now();
+        let (output, runner_error) = match &settings {
+            Some(settings) => {
+                match post_process_transcription(&app_data_dir, settings, &case.input, None).await {
+                    Some(output) => (output, None),
+                    None => (
+                        String::new(),
+                        Some("provider returned no output".to_string()),
+                    ),
+                }
+            }
+            None => match recorded.get(&case.id) {
+                Some(record) if record.input == case.input => (record.output.clone(), None),
+                Some(_) => (
+                    String::new(),
+                    Some("recorded input differs from the golden case".to_string()),
+                ),
+                None => (String::new(), Some("no recorded output".to_string())),
+            },
+        };
+        let latency_ms = settings
+            .as_ref()
+            .map(|_| started.elapsed().as_millis() as u64);
+
+        let mut checks = score_case(&case.expected, &output);
+        if let Some(error) = runner_error {
+            checks.insert(
+                0,
+                EvalCheck {
+                    name: "runner_error",
+                    value: Some(error),
+                    passed: false,
+                },
+            );
+        }
+        let passed = checks.iter().all(|check| check.passed);
+        println!("{} {}", if passed { "PASS" } else { "FAIL" }, case.id);
+
+        results.push(CaseResult {
+            id: case.id,
+            tags: case.tags,
+            input: case.input,
+            output,
+            latency_ms,
+            passed,
+            checks,
+        });
+    }
+
+    let run = RunInfo {
+        id: started_at.format("%Y-%m-%dT%H-%M-%SZ").to_string(),
+        dataset: options.dataset.display().to_string(),
+        provider,
+        model,
+        prompt,
+        started_at: started_at.to_rfc3339(),
+        finished_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let summary = summarize(&results);
+    let run_dir = options.out_dir.join(&run.id);
+    write_reports(&run_dir, &run, &summary, &results)?;
+
+    println!(
+        "\nPassed {}/{} ({}). Wrote {}",
+        summary.passed,
+        summary.total,
+        pass_rate(summary.passed, summary.total),
+        run_dir.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_eval_options() {
+        let ParseOutcome::Run(options) = parse_eval_args(&args(&[
+            "--provider",
+            "groq",
+            "--prompt-file",
+            "prompts/nuanced.md",
+            "--ids",
+            "a, b,",
+        ]))
+        .unwrap() else {
+            panic!("expected run options");
+        };
+        assert_eq!(options.provider.as_deref(), Some("groq"));
+        assert_eq!(
+            options.prompt_file,
+            Some(PathBuf::from("prompts/nuanced.md"))
+        );
+        assert_eq!(options.ids, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(options.dataset, PathBuf::from(DEFAULT_DATASET));
+
+        assert_eq!(parse_eval_args(&args(&["-h"])), Ok(ParseOutcome::Help));
+        assert!(parse_eval_args(&args(&["--replay", "old.jsonl", "--model", "x"])).is_err());
+        assert!(parse_eval_args(&args(&["--ids"])).is_err());
+        assert!(parse_eval_args(&args(&["--verbose"])).is_err());
+    }
+
+    #[test]
+    fn scores_golden_expectations() {
+        let case: GoldenCase = serde_json::from_str(
+            r#"{"id":"x","input":"ship it period","expected":{"must_include":["ship it."],"must_not_include":["period"],"must_include_exact":["Ship"]},"tags":["spoken-punctuation"]}"#,
+        )
+        .unwrap();
+
+        let checks = score_case(&case.expected, "Ship it.");
+        assert!(checks.iter().all(|check| check.passed));
+
+        let checks = score_case(&case.expected, "ship it period");
+        let failed: Vec<&str> = checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name)
+            .collect();
+        assert_eq!(
+            failed,
+            vec!["must_include", "must_include_exact", "must_not_include"]
+        );
+    }
+
+    #[test]
+    fn summarizes_results_by_tag() {
+        let result = |id: &str, tags: &[&str], passed| CaseResult {
+            id: id.to_string(),
+            tags: args(tags),
+            input: String::new(),
+            output: String::new(),
+            latency_ms: None,
+            passed,
+            checks: Vec::new(),
+        };
+        let results = vec![
+            result("a", &["numbers", "punctuation"], true),
+            result("b", &["numbers"], false),
+        ];
+
+        let summary = summarize(&results);
+        assert_eq!((summary.passed, summary.total), (1, 2));
+        assert_eq!(
+            summary.by_tag.get("numbers"),
+            Some(&TagSummary {
+                total: 2,
+                passed: 1
+            })
+        );
+
+        let run = RunInfo {
+            id: "run".to_string(),
+            dataset: "golden.jsonl".to_string(),
+            provider: "replay".to_string(),
+            model: String::new(),
+            prompt: "recorded".to_string(),
+            started_at: String::new(),
+            finished_at: String::new(),
+        };
+        let report = markdown_report(&run, &summary, &results);
+        assert!(report.contains("| numbers | 1 | 2 | 50% |"));
+        assert!(report.contains("### b"));
+        assert!(!report.contains("### a"));
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use sha2::{Digest, Sha256};
 use specta::Type;
 use std::collections::HashMap;
+use std::path::Path;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_store::StoreExt;
 use uuid::Uuid;
@@ -284,6 +285,51 @@ pub enum RecordingRetentionPeriod {
     Days3,
     Weeks2,
     Months3,
+    Years1,
+}
+
+/// What to do when a cloud transcription provider fails mid-dictation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionFallbackPolicy {
+    /// Surface the provider error.
+    Disabled,
+    /// Retry on a downloaded local model.
+    LocalModel,
+    /// Retry on the other cloud provider when a key is configured, then on a
+    /// downloaded local model.
+    CloudThenLocal,
+}
+
+impl Default for TranscriptionFallbackPolicy {
+    fn default() -> Self {
+        TranscriptionFallbackPolicy::LocalModel
+    }
+}
+
+/// Spoken editing commands recognized in dictated text.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum VoiceCommand {
+    NewLine,
+    NewParagraph,
+    /// Removes the phrase dictated since the previous command, or the whole
+    /// previous dictation when spoken on its own.
+    ScratchThat,
+    DeleteLastSentence,
+    CapitalizeNext,
+    AllCaps,
+}
+
+impl VoiceCommand {
+    pub const ALL: [VoiceCommand; 6] = [
+        VoiceCommand::NewLine,
+        VoiceCommand::NewParagraph,
+        VoiceCommand::ScratchThat,
+        VoiceCommand::DeleteLastSentence,
+        VoiceCommand::CapitalizeNext,
+        VoiceCommand::AllCaps,
+    ];
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -469,6 +515,22 @@ pub struct AppSettings {
     pub history_limit: usize,
     #[serde(default = "default_recording_retention_period")]
     pub recording_retention_period: RecordingRetentionPeriod,
+    /// How long meeting entries are kept. Only time periods apply; meetings
+    /// are never trimmed by `history_limit`.
+    #[serde(default = "default_recording_retention_period")]
+    pub meeting_retention_period: RecordingRetentionPeriod,
+    /// How long dictation recordings are kept. Older entries keep their text.
+    #[serde(default = "default_recording_retention_period")]
+    pub dictation_audio_retention_period: RecordingRetentionPeriod,
+    /// How long meeting recordings are kept. Older entries keep their text.
+    #[serde(default = "default_recording_retention_period")]
+    pub meeting_audio_retention_period: RecordingRetentionPeriod,
+    /// Encrypt transcript text and recordings in the history database.
+    #[serde(default)]
+    pub encrypt_history_at_rest: bool,
+    /// Folder that history is mirrored to as Markdown notes, if any.
+    #[serde(default)]
+    pub history_vault_dir: Option<String>,
     #[serde(default)]
     pub paste_method: PasteMethod,
     #[serde(default)]
@@ -507,6 +569,21 @@ pub struct AppSettings {
     #[serde(default = "default_incremental_transcription_enabled")]
     pub incremental_transcription_enabled: bool,
     #[serde(default)]
+    pub transcription_fallback_policy: TranscriptionFallbackPolicy,
+    /// Base URL of a self-hosted OpenAI-compatible server, e.g. `http://localhost:8080/v1`.
+    #[serde(default)]
+    pub custom_transcription_base_url: String,
+    #[serde(default)]
+    pub custom_transcription_model: String,
+    /// Rewrites spoken punctuation and numbers ("comma", "twenty five percent")
+    /// without needing post-processing.
+    #[serde(default = "default_inverse_text_normalization_enabled")]
+    pub inverse_text_normalization_enabled: bool,
+    #[serde(default)]
+    pub voice_commands_enabled: bool,
+    #[serde(default = "default_voice_commands")]
+    pub voice_commands: Vec<VoiceCommand>,
+    #[serde(default)]
     pub keyboard_implementation: KeyboardImplementation,
     #[serde(default = "default_show_tray_icon")]
     pub show_tray_icon: bool,
@@ -514,8 +591,14 @@ pub struct AppSettings {
     pub paste_delay_ms: u64,
     #[serde(default = "default_typing_tool")]
     pub typing_tool: TypingTool,
-    #[serde(default)]
-    pub file_transcription_history: Vec<SavedFileTranscription>,
+    // File transcriptions now live in history. Older versions kept them here;
+    // they stay until history has moved them, and the key then disappears.
+    #[serde(
+        default,
+        rename = "file_transcription_history",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub legacy_file_transcription_history: Vec<SavedFileTranscription>,
     #[serde(default, rename = "latest_file_transcription", skip_serializing)]
     pub legacy_latest_file_transcription: Option<SavedFileTranscription>,
 }
@@ -631,6 +714,14 @@ fn default_incremental_transcription_enabled() -> bool {
     true
 }
 
+fn default_inverse_text_normalization_enabled() -> bool {
+    false
+}
+
+fn default_voice_commands() -> Vec<VoiceCommand> {
+    VoiceCommand::ALL.to_vec()
+}
+
 fn default_app_language() -> String {
     tauri_plugin_os::locale()
         .and_then(|l| l.split(['-', '_']).next().map(String::from))
@@ -881,14 +972,16 @@ fn ensure_file_transcription_history_defaults(settings: &mut AppSettings) -> boo
     let mut changed = false;
 
     if let Some(legacy_entry) = settings.legacy_latest_file_transcription.take() {
-        if settings.file_transcription_history.is_empty() {
-            settings.file_transcription_history.push(legacy_entry);
+        if settings.legacy_file_transcription_history.is_empty() {
+            settings
+                .legacy_file_transcription_history
+                .push(legacy_entry);
         }
         changed = true;
     }
 
-    if settings.file_transcription_history.len() > 5 {
-        settings.file_transcription_history.truncate(5);
+    if settings.legacy_file_transcription_history.len() > 5 {
+        settings.legacy_file_transcription_history.truncate(5);
         changed = true;
     }
 
@@ -1155,6 +1248,11 @@ pub fn get_default_settings() -> AppSettings {
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
         recording_retention_period: default_recording_retention_period(),
+        meeting_retention_period: default_recording_retention_period(),
+        dictation_audio_retention_period: default_recording_retention_period(),
+        meeting_audio_retention_period: default_recording_retention_period(),
+        encrypt_history_at_rest: false,
+        history_vault_dir: None,
         paste_method: PasteMethod::default(),
         clipboard_handling: ClipboardHandling::default(),
         auto_submit: default_auto_submit(),
@@ -1172,11 +1270,17 @@ pub fn get_default_settings() -> AppSettings {
         append_trailing_space: false,
         app_language: default_app_language(),
         incremental_transcription_enabled: default_incremental_transcription_enabled(),
+        transcription_fallback_policy: TranscriptionFallbackPolicy::default(),
+        custom_transcription_base_url: String::new(),
+        custom_transcription_model: String::new(),
+        inverse_text_normalization_enabled: default_inverse_text_normalization_enabled(),
+        voice_commands_enabled: false,
+        voice_commands: default_voice_commands(),
         keyboard_implementation: KeyboardImplementation::default(),
         show_tray_icon: default_show_tray_icon(),
         paste_delay_ms: default_paste_delay_ms(),
         typing_tool: default_typing_tool(),
-        file_transcription_history: Vec::new(),
+        legacy_file_transcription_history: Vec::new(),
         legacy_latest_file_transcription: None,
     }
 }
@@ -1396,6 +1500,20 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
     settings
 }
 
+/// Reads the settings saved under `app_data_dir` without a running app. The
+/// command line uses this, so it never migrates or rewrites the store.
+pub fn read_settings_in_dir(app_data_dir: &Path) -> AppSettings {
+    let stored = std::fs::read_to_string(app_data_dir.join(SETTINGS_STORE_PATH))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .and_then(|mut store| store.get_mut("settings").map(serde_json::Value::take))
+        .and_then(|value| serde_json::from_value::<AppSettings>(value).ok());
+
+    let mut settings = stored.unwrap_or_else(get_default_settings);
+    ensure_post_process_defaults(&mut settings);
+    settings
+}
+
 pub fn write_settings(app: &AppHandle, mut settings: AppSettings) {
     match migrate_plaintext_api_keys_before_settings_write(app, &mut settings) {
         Ok(_) => {}
@@ -1447,6 +1565,36 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
     settings.recording_retention_period
 }
 
+pub fn get_meeting_retention_period(app: &AppHandle) -> RecordingRetentionPeriod {
+    get_settings(app).meeting_retention_period
+}
+
+pub fn get_dictation_audio_retention_period(app: &AppHandle) -> RecordingRetentionPeriod {
+    get_settings(app).dictation_audio_retention_period
+}
+
+pub fn get_meeting_audio_retention_period(app: &AppHandle) -> RecordingRetentionPeriod {
+    get_settings(app).meeting_audio_retention_period
+}
+
+pub fn get_encrypt_history_at_rest(app: &AppHandle) -> bool {
+    get_settings(app).encrypt_history_at_rest
+}
+
+pub fn get_history_vault_dir(app: &AppHandle) -> Option<String> {
+    get_settings(app).history_vault_dir
+}
+
+pub fn get_legacy_file_transcription_history(app: &AppHandle) -> Vec<SavedFileTranscription> {
+    get_settings(app).legacy_file_transcription_history
+}
+
+pub fn clear_legacy_file_transcription_history(app: &AppHandle) {
+    let mut settings = get_settings(app);
+    settings.legacy_file_transcription_history.clear();
+    write_settings(app, settings);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1761,15 +1909,15 @@ mod tests {
         let changed = ensure_file_transcription_history_defaults(&mut settings);
 
         assert!(changed);
-        assert_eq!(settings.file_transcription_history.len(), 1);
+        assert_eq!(settings.legacy_file_transcription_history.len(), 1);
         assert_eq!(
-            settings.file_transcription_history[0].file_name,
+            settings.legacy_file_transcription_history[0].file_name,
             "sample.wav"
         );
         assert!(settings.legacy_latest_file_transcription.is_none());
 
         let mut settings = get_default_settings();
-        settings.file_transcription_history = (0..7)
+        settings.legacy_file_transcription_history = (0..7)
             .map(|index| SavedFileTranscription {
                 file_name: format!("file-{}.wav", index),
                 transcription_text: format!("text {}", index),
@@ -1781,10 +1929,34 @@ mod tests {
         let changed = ensure_file_transcription_history_defaults(&mut settings);
 
         assert!(changed);
-        assert_eq!(settings.file_transcription_history.len(), 5);
+        assert_eq!(settings.legacy_file_transcription_history.len(), 5);
         assert_eq!(
-            settings.file_transcription_history[4].file_name,
+            settings.legacy_file_transcription_history[4].file_name,
             "file-4.wav"
         );
     }
+
+    #[test]
+    fn read_settings_in_dir_reads_the_store_without_rewriting_it() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            read_settings_in_dir(dir.path()).selected_model,
+            get_default_settings().selected_model
+        );
+        assert!(!dir.path().join(SETTINGS_STORE_PATH).exists());
+
+        let mut saved = get_default_settings();
+        saved.selected_model = "parakeet-tdt-0.6b-v3".to_string();
+        saved.selected_language = "de".to_string();
+        let contents = serde_json::json!({ "settings": saved }).to_string();
+        std::fs::write(dir.path().join(SETTINGS_STORE_PATH), &contents).unwrap();
+
+        let settings = read_settings_in_dir(dir.path());
+        assert_eq!(settings.selected_model, "parakeet-tdt-0.6b-v3");
+        assert_eq!(settings.selected_language, "de");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join(SETTINGS_STORE_PATH)).unwrap(),
+            contents
+        );
+    }
 }
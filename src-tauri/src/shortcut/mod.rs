@@ -26,7 +26,8 @@ use crate::managers::model::{GROQ_MODEL_WHISPER_LARGE_V3, OPENAI_MODEL_GPT_4O_TR
 use crate::settings::{
     self, get_settings, normalize_custom_vocabulary_terms, AutoSubmitKey, ByokValidationState,
     ClipboardHandling, KeyboardImplementation, OverlayPosition, PasteMethod, ShortcutBinding,
-    SoundTheme, TypingTool, APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
+    SoundTheme, TranscriptionFallbackPolicy, TypingTool, VoiceCommand,
+    APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::transcription_coordinator::transcription_session_is_active;
 use crate::tray;
@@ -886,6 +887,63 @@ pub fn change_auto_submit_key_setting(app: AppHandle, key: String) -> Result<(),
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcription_fallback_policy_setting(
+    app: AppHandle,
+    policy: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match policy.as_str() {
+        "disabled" => TranscriptionFallbackPolicy::Disabled,
+        "local_model" => TranscriptionFallbackPolicy::LocalModel,
+        "cloud_then_local" => TranscriptionFallbackPolicy::CloudThenLocal,
+        other => {
+            warn!(
+                "Invalid transcription fallback policy '{}', defaulting to local_model",
+                other
+            );
+            TranscriptionFallbackPolicy::LocalModel
+        }
+    };
+    settings.transcription_fallback_policy = parsed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_custom_transcription_server_setting(
+    app: AppHandle,
+    base_url: String,
+    model: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.custom_transcription_base_url = if base_url.trim().is_empty() {
+        String::new()
+    } else {
+        crate::groq_client::normalize_custom_base_url(&base_url)
+            .ok_or_else(|| "Custom server URL must start with http:// or https://".to_string())?
+    };
+    settings.custom_transcription_model = model.trim().to_string();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_custom_transcription_api_key_setting(
+    app: AppHandle,
+    api_key: String,
+) -> Result<(), String> {
+    let provider_id = crate::byok_secrets::CUSTOM_TRANSCRIPTION_SECRET_KEY;
+    if api_key.trim().is_empty() {
+        crate::byok_secrets::clear_provider_api_key(&app, provider_id)
+    } else {
+        crate::byok_secrets::save_provider_api_key(&app, provider_id, &api_key)
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -1125,6 +1183,42 @@ pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_inverse_text_normalization_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.inverse_text_normalization_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_commands_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_commands_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_commands_setting(
+    app: AppHandle,
+    commands: Vec<VoiceCommand>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_commands = VoiceCommand::ALL
+        .into_iter()
+        .filter(|command| commands.contains(command))
+        .collect();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_app_language_setting(app: AppHandle, language: String) -> Result<(), String> {
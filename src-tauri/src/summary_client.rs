@@ -470,18 +470,31 @@ pub async fn transform_with_codex_app(
         .map_err(|error| format!("Codex edit transform task failed: {}", error))?
 }
 
-pub async fn summarize_with_provider(
+/// Streams a summary from `provider`, passing the reply received so far to
+/// `on_partial_summary` as it grows.
+pub async fn summarize_with_provider<F>(
     provider: &PostProcessProvider,
     api_key: String,
     model: &str,
     prompt: String,
     system_prompt: &str,
-) -> Result<String, String> {
-    crate::llm_client::send_chat_completion(provider, api_key, model, prompt, Some(system_prompt))
-        .await?
-        .map(|summary| summary.trim().to_string())
-        .filter(|summary| !summary.is_empty())
-        .ok_or_else(|| "OpenAI returned an empty summary.".to_string())
+    on_partial_summary: F,
+) -> Result<String, String>
+where
+    F: FnMut(&str) -> bool,
+{
+    crate::llm_client::stream_chat_completion(
+        provider,
+        api_key,
+        model,
+        prompt,
+        Some(system_prompt),
+        on_partial_summary,
+    )
+    .await?
+    .map(|summary| summary.trim().to_string())
+    .filter(|summary| !summary.is_empty())
+    .ok_or_else(|| format!("{} returned an empty summary.", provider.label))
 }
 
 pub async fn summarize_with_backend(
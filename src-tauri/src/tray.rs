@@ -313,11 +313,19 @@ mod tests {
             file_name: "uttr-1.wav".to_string(),
             timestamp: 0,
             saved: false,
+            audio_purged: false,
             title: "Recording".to_string(),
             transcription_text: transcription.to_string(),
             post_processed_text: post_processed.map(|text| text.to_string()),
             post_process_prompt: None,
             recording_source: "dictation".to_string(),
+            source_path: None,
+            transcription_route: None,
+            fallback_from: None,
+            transcription_model: None,
+            note: None,
+            folder_id: None,
+            tags: Vec::new(),
         }
     }
 
@@ -0,0 +1,275 @@
+//! Proposes custom vocabulary from a user's correction of a transcript, so
+//! names the transcriber keeps mangling can be added without retyping them.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+
+use crate::audio_toolkit::apply_custom_words;
+
+/// Longest phrase, in words, that is proposed as a term. Matches the n-gram
+/// limit `apply_custom_words` can correct.
+const MAX_SUGGESTION_WORDS: usize = 4;
+const MAX_SUGGESTION_CHARS: usize = 50;
+/// Upper bound on the word-diff table so very long meetings stay cheap.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+/// Words trimmed from either end of a replaced phrase, so "their" to
+/// "the Acme" is not proposed as the term "the Acme".
+const FUNCTION_WORDS: &[&str] = &[
+    "a", "an", "the", "this", "that", "these", "those", "my", "our", "your", "his", "her", "its",
+    "their", "of", "to", "in", "on", "at", "for", "with", "and", "or",
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct VocabularySuggestion {
+    /// Words as they read before the correction.
+    pub heard: String,
+    /// The user's spelling, proposed as the new term.
+    pub corrected: String,
+    /// Whether adding `corrected` to custom words would already turn `heard`
+    /// into it at the current correction threshold. Always false for
+    /// corrections to post-processed text, which custom words never see;
+    /// the term then only helps as a post-processing vocabulary hint.
+    pub fixable_by_custom_words: bool,
+}
+
+fn trim_word(word: &str) -> &str {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+}
+
+fn ends_sentence(word: &str) -> bool {
+    word.ends_with(['.', '?', '!'])
+}
+
+/// Word-level longest common subsequence diff. Returns the differing hunks as
+/// index ranges into `original` and `corrected`.
+fn diff_hunks(
+    original: &[&str],
+    corrected: &[&str],
+) -> Option<Vec<(std::ops::Range<usize>, std::ops::Range<usize>)>> {
+    let prefix = original
+        .iter()
+        .zip(corrected)
+        .take_while(|(a, b)| trim_word(a) == trim_word(b))
+        .count();
+    let suffix = original[prefix..]
+        .iter()
+        .rev()
+        .zip(corrected[prefix..].iter().rev())
+        .take_while(|(a, b)| trim_word(a) == trim_word(b))
+        .count();
+    let a = &original[prefix..original.len() - suffix];
+    let b = &corrected[prefix..corrected.len() - suffix];
+    if (a.len() + 1) * (b.len() + 1) > MAX_DIFF_CELLS {
+        return None;
+    }
+
+    // lengths[i][j] is the LCS length of a[i..] and b[j..].
+    let mut lengths = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if trim_word(a[i]) == trim_word(b[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut hunks = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let (mut hunk_i, mut hunk_j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && trim_word(a[i]) == trim_word(b[j]) {
+            if hunk_i < i || hunk_j < j {
+                hunks.push((prefix + hunk_i..prefix + i, prefix + hunk_j..prefix + j));
+            }
+            i += 1;
+            j += 1;
+            (hunk_i, hunk_j) = (i, j);
+        } else if j < b.len() && (i == a.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            j += 1;
+        } else {
+            i += 1;
+        }
+    }
+    if hunk_i < i || hunk_j < j {
+        hunks.push((prefix + hunk_i..prefix + i, prefix + hunk_j..prefix + j));
+    }
+    Some(hunks)
+}
+
+/// Whether a corrected phrase looks like a name or term rather than an
+/// ordinary wording fix such as "their" to "there".
+fn looks_like_term(corrected: &str, starts_sentence: bool) -> bool {
+    corrected
+        .split_whitespace()
+        .enumerate()
+        .any(|(index, word)| {
+            word.chars().enumerate().any(|(position, c)| {
+                let sentence_capital = index == 0 && position == 0 && starts_sentence;
+                c.is_ascii_digit() || (c.is_uppercase() && !sentence_capital)
+            })
+        })
+}
+
+/// Compares `original` with the user's `corrected` text and proposes terms
+/// for the replaced phrases. Terms already in `custom_words` or
+/// `vocabulary_terms` are left out.
+pub fn suggest_vocabulary(
+    original: &str,
+    corrected: &str,
+    custom_words: &[String],
+    vocabulary_terms: &[String],
+    threshold: f64,
+) -> Vec<VocabularySuggestion> {
+    let original_words: Vec<&str> = original.split_whitespace().collect();
+    let corrected_words: Vec<&str> = corrected.split_whitespace().collect();
+    let Some(hunks) = diff_hunks(&original_words, &corrected_words) else {
+        return Vec::new();
+    };
+
+    let mut seen: HashSet<String> = custom_words
+        .iter()
+        .chain(vocabulary_terms)
+        .map(|term| term.trim().to_lowercase())
+        .collect();
+    let mut suggestions = Vec::new();
+
+    for (heard_range, corrected_range) in hunks {
+        if heard_range.is_empty()
+            || corrected_range.is_empty()
+            || heard_range.len() > MAX_SUGGESTION_WORDS
+            || corrected_range.len() > MAX_SUGGESTION_WORDS
+        {
+            continue;
+        }
+
+        let heard_words = trim_function_words(&original_words[heard_range]);
+        let (term_offset, term_words) =
+            trim_function_words_with_offset(&corrected_words[corrected_range.clone()]);
+        let heard = trim_phrase(heard_words);
+        let term = trim_phrase(term_words);
+        let term_start = corrected_range.start + term_offset;
+        let starts_sentence = term_start == 0 || ends_sentence(corrected_words[term_start - 1]);
+        if heard.is_empty()
+            || term.is_empty()
+            || term.chars().count() > MAX_SUGGESTION_CHARS
+            || !looks_like_term(&term, starts_sentence)
+            || !seen.insert(term.to_lowercase())
+        {
+            continue;
+        }
+
+        let fixed = apply_custom_words(&heard, std::slice::from_ref(&term), threshold);
+        suggestions.push(VocabularySuggestion {
+            fixable_by_custom_words: fixed.to_lowercase() == term.to_lowercase(),
+            heard,
+            corrected: term,
+        });
+    }
+
+    suggestions
+}
+
+fn is_function_word(word: &str) -> bool {
+    FUNCTION_WORDS.contains(&trim_word(word).to_lowercase().as_str())
+}
+
+/// Drops [`FUNCTION_WORDS`] from both ends of a run of words.
+fn trim_function_words<'a, 'b>(words: &'b [&'a str]) -> &'b [&'a str] {
+    trim_function_words_with_offset(words).1
+}
+
+/// Like [`trim_function_words`], also returning how many leading words were
+/// dropped.
+fn trim_function_words_with_offset<'a, 'b>(words: &'b [&'a str]) -> (usize, &'b [&'a str]) {
+    let start = words
+        .iter()
+        .position(|word| !is_function_word(word))
+        .unwrap_or(words.len());
+    let end = words
+        .iter()
+        .rposition(|word| !is_function_word(word))
+        .map_or(start, |index| index + 1);
+    (start, &words[start..end])
+}
+
+/// Joins a run of words, dropping punctuation around the run but keeping it
+/// inside (for example "Node.js").
+fn trim_phrase(words: &[&str]) -> String {
+    trim_word(&words.join(" ")).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(original: &str, corrected: &str) -> Vec<(String, String, bool)> {
+        suggest_vocabulary(original, corrected, &[], &[], 0.18)
+            .into_iter()
+            .map(|s| (s.heard, s.corrected, s.fixable_by_custom_words))
+            .collect()
+    }
+
+    #[test]
+    fn proposes_replaced_names_and_skips_ordinary_fixes() {
+        assert_eq!(
+            suggest(
+                "Ask their team to try chat GBT and open AI today.",
+                "Ask the Acme team to try ChatGPT and OpenAI today.",
+            ),
+            vec![
+                ("chat GBT".to_string(), "ChatGPT".to_string(), true),
+                ("open AI".to_string(), "OpenAI".to_string(), true),
+            ]
+        );
+
+        // Function words around a replaced name are left out of the term.
+        assert_eq!(
+            suggest(
+                "ask their team about open AI",
+                "ask the Acme team about the OpenAI"
+            ),
+            vec![("open AI".to_string(), "OpenAI".to_string(), true)]
+        );
+
+        // Wording fixes, sentence capitals and long rewrites are not terms.
+        assert!(suggest("their going home. the end", "they're going home. The end").is_empty());
+        assert!(suggest(
+            "we should do it",
+            "We Should Really Consider Doing It Later"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn skips_terms_that_are_already_known() {
+        let suggestions = suggest_vocabulary(
+            "ship it with cube control",
+            "ship it with kubectl k8s",
+            &["Kubectl".to_string()],
+            &[],
+            0.18,
+        );
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].corrected, "kubectl k8s");
+
+        let suggestions = suggest_vocabulary(
+            "ship it with cube control",
+            "ship it with kubectl",
+            &["Kubectl".to_string()],
+            &[],
+            0.18,
+        );
+        assert!(suggestions.is_empty());
+        let suggestions = suggest_vocabulary(
+            "email open ai today",
+            "email OpenAI today",
+            &[],
+            &["OpenAI".to_string()],
+            0.18,
+        );
+        assert!(suggestions.is_empty());
+    }
+}
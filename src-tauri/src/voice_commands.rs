@@ -0,0 +1,257 @@
+//! Local voice editing commands ("new line", "scratch that", ...) applied to
+//! dictated text before post-processing, so they work without an LLM.
+
+use crate::settings::VoiceCommand;
+
+/// Edits that reach back into text pasted by an earlier dictation. These are
+/// replayed as key presses just before the current text is pasted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceKeyAction {
+    DeletePreviousDictation,
+    DeletePreviousSentence,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VoiceCommandOutput {
+    pub text: String,
+    pub key_actions: Vec<VoiceKeyAction>,
+}
+
+#[derive(Clone, Copy)]
+enum WordTransform {
+    Capitalize,
+    Uppercase,
+}
+
+fn command_phrase(command: VoiceCommand) -> &'static [&'static str] {
+    match command {
+        VoiceCommand::NewLine => &["new", "line"],
+        VoiceCommand::NewParagraph => &["new", "paragraph"],
+        VoiceCommand::ScratchThat => &["scratch", "that"],
+        VoiceCommand::DeleteLastSentence => &["delete", "last", "sentence"],
+        VoiceCommand::CapitalizeNext => &["capitalize", "next"],
+        VoiceCommand::AllCaps => &["all", "caps"],
+    }
+}
+
+fn normalize_token(token: &str) -> String {
+    token
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn match_command(
+    normalized: &[String],
+    index: usize,
+    enabled: &[VoiceCommand],
+) -> Option<(VoiceCommand, usize)> {
+    enabled
+        .iter()
+        .filter_map(|command| {
+            let phrase = command_phrase(*command);
+            let end = index + phrase.len();
+            (end <= normalized.len()
+                && normalized[index..end]
+                    .iter()
+                    .zip(phrase)
+                    .all(|(token, word)| token == word))
+            .then_some((*command, phrase.len()))
+        })
+        .max_by_key(|(_, len)| *len)
+}
+
+fn transform_word(word: &str, transform: WordTransform) -> String {
+    match transform {
+        WordTransform::Uppercase => word.to_uppercase(),
+        WordTransform::Capitalize => {
+            let Some(start) = word.find(char::is_alphanumeric) else {
+                return word.to_string();
+            };
+            let (prefix, rest) = word.split_at(start);
+            let mut chars = rest.chars();
+            match chars.next() {
+                Some(first) => format!("{prefix}{}{}", first.to_uppercase(), chars.as_str()),
+                None => word.to_string(),
+            }
+        }
+    }
+}
+
+/// Byte offset where the last sentence of `text` starts, ignoring trailing
+/// whitespace. Sentences end at `.`, `?`, `!` or a line break.
+pub fn last_sentence_start(text: &str) -> usize {
+    let trimmed = text.trim_end();
+    let body = trimmed.trim_end_matches(['.', '?', '!']);
+    body.char_indices()
+        .rev()
+        .find(|(_, c)| matches!(c, '.' | '?' | '!' | '\n'))
+        .map(|(index, c)| index + c.len_utf8())
+        .unwrap_or(0)
+}
+
+fn truncate_to_sentence_start(text: &mut String) {
+    let start = last_sentence_start(text);
+    text.truncate(start);
+    let kept = text.trim_end_matches([' ', '\t']).len();
+    text.truncate(kept);
+}
+
+/// Applies the enabled spoken commands to `text`. Commands that would edit
+/// beyond the start of this dictation become [`VoiceKeyAction`]s instead.
+pub fn apply_voice_commands(text: &str, enabled: &[VoiceCommand]) -> VoiceCommandOutput {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let normalized: Vec<String> = tokens.iter().map(|token| normalize_token(token)).collect();
+
+    let mut output = VoiceCommandOutput::default();
+    // Start offsets of each phrase or line break, so "scratch that" can drop
+    // the most recent one.
+    let mut units: Vec<usize> = Vec::new();
+    let mut in_phrase = false;
+    let mut pending_transform: Option<WordTransform> = None;
+    let mut matched_any = false;
+
+    let mut index = 0;
+    while index < tokens.len() {
+        let Some((command, len)) = match_command(&normalized, index, enabled) else {
+            let out = &mut output.text;
+            if !in_phrase {
+                units.push(out.len());
+                in_phrase = true;
+            }
+            if !out.is_empty() && !out.ends_with('\n') {
+                out.push(' ');
+            }
+            match pending_transform.take() {
+                Some(transform) => out.push_str(&transform_word(tokens[index], transform)),
+                None => out.push_str(tokens[index]),
+            }
+            index += 1;
+            continue;
+        };
+
+        matched_any = true;
+        in_phrase = false;
+        index += len;
+        let out = &mut output.text;
+        match command {
+            VoiceCommand::NewLine | VoiceCommand::NewParagraph => {
+                // Transcribers often put a comma before a spoken command.
+                let kept = out.trim_end_matches([' ', ',']).len();
+                out.truncate(kept);
+                units.retain(|start| *start <= kept);
+                units.push(out.len());
+                out.push_str(if command == VoiceCommand::NewLine {
+                    "\n"
+                } else {
+                    "\n\n"
+                });
+            }
+            VoiceCommand::ScratchThat => match units.pop() {
+                Some(start) => out.truncate(start),
+                None => output
+                    .key_actions
+                    .push(VoiceKeyAction::DeletePreviousDictation),
+            },
+            VoiceCommand::DeleteLastSentence => {
+                if out.trim().is_empty() {
+                    out.clear();
+                    units.clear();
+                    output
+                        .key_actions
+                        .push(VoiceKeyAction::DeletePreviousSentence);
+                } else {
+                    truncate_to_sentence_start(out);
+                    let len = out.len();
+                    units.retain(|start| *start < len);
+                    in_phrase = !out.is_empty() && !out.ends_with('\n');
+                }
+            }
+            VoiceCommand::CapitalizeNext => pending_transform = Some(WordTransform::Capitalize),
+            VoiceCommand::AllCaps => pending_transform = Some(WordTransform::Uppercase),
+        }
+    }
+
+    if !matched_any {
+        output.text = text.to_string();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str) -> VoiceCommandOutput {
+        apply_voice_commands(text, &VoiceCommand::ALL)
+    }
+
+    #[test]
+    fn formatting_commands_edit_text() {
+        let cases = [
+            ("Dear team, new line. Thanks.", "Dear team\nThanks."),
+            (
+                "First point new paragraph second point",
+                "First point\n\nsecond point",
+            ),
+            ("meet capitalize next alice tomorrow", "meet Alice tomorrow"),
+            ("ship it all caps asap", "ship it ASAP"),
+            ("I'll be there at five. Scratch that. At six.", "At six."),
+            (
+                "Hello. new line Wrong line. Scratch that. Right line.",
+                "Hello.\nRight line.",
+            ),
+            (
+                "One thing. Another thing. Delete last sentence. Done.",
+                "One thing. Done.",
+            ),
+            (
+                "no commands here,  kept   verbatim",
+                "no commands here,  kept   verbatim",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let output = apply(input);
+            assert_eq!(output.text, expected, "input: {input}");
+            assert!(output.key_actions.is_empty(), "input: {input}");
+        }
+    }
+
+    #[test]
+    fn commands_without_local_text_target_previous_paste() {
+        assert_eq!(
+            apply("Scratch that."),
+            VoiceCommandOutput {
+                text: String::new(),
+                key_actions: vec![VoiceKeyAction::DeletePreviousDictation],
+            }
+        );
+        assert_eq!(
+            apply("Delete last sentence. Actually, let's go."),
+            VoiceCommandOutput {
+                text: "Actually, let's go.".to_string(),
+                key_actions: vec![VoiceKeyAction::DeletePreviousSentence],
+            }
+        );
+    }
+
+    #[test]
+    fn disabled_commands_are_left_as_text() {
+        let output = apply_voice_commands(
+            "Start a new line of products. Scratch that.",
+            &[VoiceCommand::ScratchThat],
+        );
+        assert!(output.text.is_empty());
+        assert!(output.key_actions.is_empty());
+
+        let output = apply_voice_commands("Start a new line of products.", &[]);
+        assert_eq!(output.text, "Start a new line of products.");
+    }
+
+    #[test]
+    fn last_sentence_start_skips_trailing_terminator() {
+        assert_eq!(last_sentence_start("One. Two."), 4);
+        assert_eq!(last_sentence_start("One.\nTwo "), 5);
+        assert_eq!(last_sentence_start("Only one"), 0);
+    }
+}
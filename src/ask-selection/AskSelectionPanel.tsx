@@ -31,6 +31,10 @@ type AskSelectionPayload = {
   messages?: AskSelectionMessage[] | null;
 };
 
+// Pending assistant messages carry this placeholder until the first part of
+// a streamed answer arrives.
+const PENDING_ANSWER_TEXT = "Thinking...";
+
 const DEFAULT_PAYLOAD: AskSelectionPayload = {
   state: "thinking",
   text: null,
@@ -196,12 +200,14 @@ const useAskSelectionPanelController = () => {
     };
   }, [payload, refreshPayload]);
 
+  // Streamed answers grow in place, so follow the last message's text too.
+  const lastMessageText = payload?.messages?.slice(-1)[0]?.text;
   useEffect(() => {
     messageListRef.current?.scrollTo({
       top: messageListRef.current.scrollHeight,
       behavior: "smooth",
     });
-  }, [payload?.messages?.length, payload?.state]);
+  }, [payload?.messages?.length, lastMessageText, payload?.state]);
 
   const handleCopy = async (text: string | null | undefined) => {
     const cleanText = text?.trim();
@@ -233,7 +239,7 @@ const useAskSelectionPanelController = () => {
     const optimisticMessages = [
       ...(payload?.messages ?? []),
       { role: "user", text: message, pending: false },
-      { role: "assistant", text: "Thinking...", pending: true },
+      { role: "assistant", text: PENDING_ANSWER_TEXT, pending: true },
     ];
     const optimisticPayload: AskSelectionPayload = {
       ...(payload ?? DEFAULT_PAYLOAD),
@@ -387,7 +393,11 @@ export default function AskSelectionPanel() {
             <div className="ask-selection-messages">
               {messages.map((message, index) => {
                 const isAssistant = message.role === "assistant";
-                const messageClass = `ask-selection-message ask-selection-message-${isAssistant ? "assistant" : "user"}${message.pending ? " ask-selection-message-pending" : ""}`;
+                const isPlaceholder =
+                  isAssistant &&
+                  message.pending &&
+                  message.text === PENDING_ANSWER_TEXT;
+                const messageClass = `ask-selection-message ask-selection-message-${isAssistant ? "assistant" : "user"}${isPlaceholder ? " ask-selection-message-pending" : ""}`;
                 if (isAssistant && !message.pending) {
                   return (
                     <button
@@ -406,7 +416,7 @@ export default function AskSelectionPanel() {
                     className={messageClass}
                     key={`${message.role}-${index}`}
                   >
-                    {message.pending && isAssistant ? (
+                    {isPlaceholder ? (
                       <>
                         <RoseThreeLoader
                           className="ask-selection-message-loader"
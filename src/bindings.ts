@@ -152,6 +152,30 @@ async changeAutoSubmitKeySetting(key: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changeTranscriptionFallbackPolicySetting(policy: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_transcription_fallback_policy_setting", { policy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeCustomTranscriptionServerSetting(baseUrl: string, model: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_custom_transcription_server_setting", { baseUrl, model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeCustomTranscriptionApiKeySetting(apiKey: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_custom_transcription_api_key_setting", { apiKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_enabled_setting", { enabled }) };
@@ -287,6 +311,30 @@ async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
+async changeInverseTextNormalizationSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_inverse_text_normalization_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeVoiceCommandsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeVoiceCommandsSetting(commands: VoiceCommand[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_setting", { commands }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAppLanguageSetting(language: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_app_language_setting", { language }) };
@@ -815,9 +863,35 @@ async transcribeAudioFile(path: string) : Promise<Result<FileTranscriptionResult
     else return { status: "error", error: e  as any };
 }
 },
-async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
+/**
+ * Transcribes a history entry's recording again with `model_id` (the
+ * selected model when `None`) and `language`, and keeps the result as an
+ * alternate beside the entry's current text. Meetings are refused: their
+ * transcript is labeled by speaker and their summary comes from the live
+ * session, and neither survives transcribing the mixed recording again.
+ * 
+ * A local model may be swapped in for the run, so it holds the file
+ * transcription guard and refuses to start while a recording is active.
+ */
+async retranscribeHistoryEntry(id: number, modelId: string | null, language: string | null, postProcess: boolean) : Promise<Result<HistoryAlternate, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("retranscribe_history_entry", { id, modelId, language, postProcess }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryEntries(cursor: HistoryCursor | null, limit: number | null, filter: HistoryPageFilter) : Promise<Result<HistoryPage, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entries", { cursor, limit, filter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async searchHistoryEntries(query: string, filters: HistorySearchFilters, limit: number | null) : Promise<Result<HistorySearchResult[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+    return { status: "ok", data: await TAURI_INVOKE("search_history_entries", { query, filters, limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -831,7 +905,146 @@ async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
+async updateHistoryEntry(id: number, update: HistoryEntryUpdate) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_history_entry", { id, update }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryEntryRevisions(id: number) : Promise<Result<HistoryRevision[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entry_revisions", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async revertHistoryEntry(id: number, revisionId: number) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("revert_history_entry", { id, revisionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Runs post-processing again on an entry's raw transcript with another
+ * prompt or provider. The previous post-processed text stays available as a
+ * revision. Only the post-processing step runs; the raw transcript already
+ * went through the rest of the pipeline when it was saved. Meetings are
+ * refused, since their post-processed text is the session summary.
+ */
+async reprocessHistoryEntry(id: number, preset: CleaningPromptPreset, customPrompt: string | null, providerId: string | null) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reprocess_history_entry", { id, preset, customPrompt, providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryEntryAlternates(id: number) : Promise<Result<HistoryAlternate[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entry_alternates", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async applyHistoryEntryAlternate(id: number, alternateId: number) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("apply_history_entry_alternate", { id, alternateId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteHistoryEntryAlternate(id: number, alternateId: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry_alternate", { id, alternateId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setHistoryEntryTags(id: number, tags: string[]) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_entry_tags", { id, tags }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setHistoryEntryNote(id: number, note: string | null) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_entry_note", { id, note }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setHistoryEntryFolder(id: number, folderId: number | null) : Promise<Result<HistoryEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_entry_folder", { id, folderId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryFolders() : Promise<Result<HistoryFolder[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_folders") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async createHistoryFolder(name: string) : Promise<Result<HistoryFolder, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_history_folder", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async renameHistoryFolder(id: number, name: string) : Promise<Result<HistoryFolder, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rename_history_folder", { id, name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteHistoryFolder(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_folder", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryTags() : Promise<Result<HistoryTag[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_tags") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves a corrected transcript like `update_history_entry` and returns the
+ * terms the correction suggests adding to custom vocabulary.
+ */
+async submitHistoryCorrection(id: number, correctedText: string) : Promise<Result<VocabularySuggestion[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("submit_history_correction", { id, correctedText }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAudioFilePath(fileName: string) : Promise<Result<PlayableAudio, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
 } catch (e) {
@@ -839,6 +1052,18 @@ async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Deletes a decrypted copy returned by `get_audio_file_path` once the
+ * webview has loaded it.
+ */
+async releasePlayableAudio(path: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("release_playable_audio", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
@@ -847,6 +1072,38 @@ async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async getHistoryEntryTimestamps(id: number) : Promise<Result<TranscriptTimestamps, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entry_timestamps", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportHistoryEntryTranscript(id: number, format: TranscriptExportFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_history_entry_transcript", { id, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportHistoryBundle(ids: number[] | null, path: string) : Promise<Result<HistoryExportSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_history_bundle", { ids, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async importHistoryBundle(path: string) : Promise<Result<HistoryImportSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_history_bundle", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
@@ -863,6 +1120,62 @@ async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
+async updateMeetingRetentionPeriod(period: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_meeting_retention_period", { period }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sets how long recordings are kept for dictations or meetings. Entries
+ * past the period keep their text.
+ */
+async updateAudioRetentionPeriod(meetings: boolean, period: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_audio_retention_period", { meetings, period }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Turns encryption at rest on or off, converting existing history before
+ * the setting is saved.
+ */
+async setHistoryEncryption(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_encryption", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Mirrors history to Markdown notes in `path`, or stops mirroring with
+ * `None`. Existing entries are written before the setting is saved.
+ */
+async setHistoryVaultDir(path: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_vault_dir", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Usage totals for entries between `from_timestamp` and `to_timestamp`
+ * (Unix seconds), with typing time saved estimated at `typing_wpm`.
+ */
+async getHistoryStats(fromTimestamp: number | null, toTimestamp: number | null, typingWpm: number | null) : Promise<Result<HistoryStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_stats", { fromTimestamp, toTimestamp, typingWpm }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Checks if the Mac is a laptop by detecting battery presence
  * 
@@ -890,7 +1203,33 @@ async isLaptop() : Promise<Result<boolean, string>> {
 /** user-defined types **/
 
 export type AccessState = "blocked" | "trialing" | "subscribed"
-export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; record_full_system_audio?: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; onboarding_completed?: boolean; install_id?: string; device_fingerprint_hash?: string; install_token?: string; anonymous_trial_state?: TrialState; access_state?: AccessState; entitlement_state?: EntitlementState; byok_enabled?: boolean; byok_validation_state?: ByokValidationState; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; custom_vocabulary_terms?: string[]; edit_mode_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; auto_submit?: boolean; auto_submit_key?: AutoSubmitKey; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_models?: Partial<{ [key in string]: string }>; post_process_timeout_secs?: number; post_process_cleaning_prompt_preset?: CleaningPromptPreset; post_process_preset_migrated?: boolean; post_process_system_prompt?: string; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; incremental_transcription_enabled?: boolean; keyboard_implementation?: KeyboardImplementation; show_tray_icon?: boolean; paste_delay_ms?: number; typing_tool?: TypingTool; file_transcription_history?: SavedFileTranscription[] }
+export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; record_full_system_audio?: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; onboarding_completed?: boolean; install_id?: string; device_fingerprint_hash?: string; install_token?: string; anonymous_trial_state?: TrialState; access_state?: AccessState; entitlement_state?: EntitlementState; byok_enabled?: boolean; byok_validation_state?: ByokValidationState; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; custom_vocabulary_terms?: string[]; edit_mode_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; /**
+ * How long meeting entries are kept. Only time periods apply; meetings
+ * are never trimmed by `history_limit`.
+ */
+meeting_retention_period?: RecordingRetentionPeriod; /**
+ * How long dictation recordings are kept. Older entries keep their text.
+ */
+dictation_audio_retention_period?: RecordingRetentionPeriod; /**
+ * How long meeting recordings are kept. Older entries keep their text.
+ */
+meeting_audio_retention_period?: RecordingRetentionPeriod; /**
+ * Encrypt transcript text and recordings in the history database.
+ */
+encrypt_history_at_rest?: boolean; 
+/**
+ * Folder that history is mirrored to as Markdown notes, if any.
+ */
+history_vault_dir?: string | null; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; auto_submit?: boolean; auto_submit_key?: AutoSubmitKey; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_models?: Partial<{ [key in string]: string }>; post_process_timeout_secs?: number; post_process_cleaning_prompt_preset?: CleaningPromptPreset; post_process_preset_migrated?: boolean; post_process_system_prompt?: string; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; incremental_transcription_enabled?: boolean; transcription_fallback_policy?: TranscriptionFallbackPolicy; 
+/**
+ * Base URL of a self-hosted OpenAI-compatible server, e.g. `http://localhost:8080/v1`.
+ */
+custom_transcription_base_url?: string; custom_transcription_model?: string; 
+/**
+ * Rewrites spoken punctuation and numbers ("comma", "twenty five percent")
+ * without needing post-processing.
+ */
+inverse_text_normalization_enabled?: boolean; voice_commands_enabled?: boolean; voice_commands?: VoiceCommand[]; keyboard_implementation?: KeyboardImplementation; show_tray_icon?: boolean; paste_delay_ms?: number; typing_tool?: TypingTool; file_transcription_history?: SavedFileTranscription[] }
 export type AskSelectionMessage = { role: string; text: string; pending: boolean }
 export type AskSelectionPayload = { state: string; text: string | null; selectedText: string | null; error: string | null; sessionId: number | null; messages: AskSelectionMessage[] }
 export type AudioDevice = { index: string; name: string; is_default: boolean }
@@ -907,7 +1246,141 @@ export type FileTranscriptionResult = { file_name: string; transcription_text: s
 export type FullSystemAudioReadinessStatus = { supported: boolean; ready: boolean; screen_recording_permission_granted: boolean | null; reason: string | null }
 export type FullSystemAudioSupportStatus = { supported: boolean; reason: string | null }
 export type FullSystemAudioToggleResult = { requested_enabled: boolean; stored_enabled: boolean; support: FullSystemAudioSupportStatus; readiness: FullSystemAudioReadinessStatus; error: string | null }
-export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; recording_source: string }
+/**
+ * Another transcript of an entry's recording, made by transcribing it again
+ * with a different model or language. It sits beside the entry until it is
+ * applied or deleted.
+ */
+export type HistoryAlternate = { id: number; history_id: number; created_at: number; transcription_model: string | null; 
+/**
+ * Language the run was asked for, e.g. `auto` or `de`.
+ */
+language: string | null; transcription_route: string | null; transcription_text: string; post_processed_text: string | null }
+export type HistoryAppStats = { app_name: string; entry_count: number; word_count: number }
+/**
+ * Position of the last entry on a page. Pages are ordered newest first, with
+ * `id` breaking ties between entries saved in the same second.
+ */
+export type HistoryCursor = { timestamp: number; id: number }
+export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; 
+/**
+ * No recording is stored: audio retention deleted it, or the text came
+ * from an imported file.
+ */
+audio_purged?: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; recording_source: string; 
+/**
+ * Path of the imported file for [`FILE_IMPORT_SOURCE`] entries.
+ */
+source_path?: string | null; 
+/**
+ * Route that produced the text, e.g. `local`, `groq`, `openai` or `backend_proxy`.
+ */
+transcription_route: string | null; 
+/**
+ * Route that failed first when the text came from a fallback.
+ */
+fallback_from: string | null; 
+/**
+ * Model id that produced the text, e.g. `parakeet-tdt-0.6b-v3`.
+ */
+transcription_model: string | null; 
+/**
+ * Free-form note from the user, kept apart from the transcript.
+ */
+note: string | null; folder_id: number | null; 
+/**
+ * Sorted case-insensitively.
+ */
+tags?: string[] }
+/**
+ * Changes for [`HistoryManager::update_entry`]. `None` keeps the current
+ * value and an empty `post_processed_text` clears it.
+ */
+export type HistoryEntryUpdate = { title: string | null; transcription_text: string | null; post_processed_text: string | null }
+export type HistoryExportSummary = { entries: number; recordings: number }
+/**
+ * A user-created group of entries, such as one per client.
+ */
+export type HistoryFolder = { id: number; name: string; entry_count: number }
+export type HistoryImportSummary = { imported: number; 
+/**
+ * Entries that were already in history, matched by time, source and
+ * transcript.
+ */
+skipped: number }
+export type HistoryPage = { entries: HistoryEntry[]; 
+/**
+ * `None` once the last page has been returned.
+ */
+next_cursor: HistoryCursor | null; 
+/**
+ * Entries matching the filter across all pages. Only counted for the
+ * first page; later pages return `None`.
+ */
+total_count: number | null }
+/**
+ * Filters for [`HistoryManager::get_history_page`]. Tags match
+ * case-insensitively.
+ */
+export type HistoryPageFilter = { source?: HistorySourceFilter; folder_id: number | null; tag: string | null }
+/**
+ * An entry's text as it was before one of its edits.
+ */
+export type HistoryRevision = { id: number; history_id: number; 
+/**
+ * When this version was replaced, in Unix seconds.
+ */
+created_at: number; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null }
+/**
+ * Optional filters for [`HistoryManager::search_entries`]. Timestamps are
+ * inclusive Unix seconds.
+ */
+export type HistorySearchFilters = { recording_source: string | null; from_timestamp: number | null; to_timestamp: number | null; saved: boolean | null; folder_id: number | null; tag: string | null }
+export type HistorySearchResult = { entry: HistoryEntry; 
+/**
+ * Excerpt around the best match with terms wrapped in
+ * `SEARCH_MATCH_START`/`SEARCH_MATCH_END`. Empty for filter-only searches.
+ */
+snippet: string }
+/**
+ * Which recordings a history page includes. Meetings are full system audio
+ * sessions; every other source, file imports included, counts as a
+ * dictation.
+ */
+export type HistorySourceFilter = "all" | "dictations" | "meetings" | "file_imports"
+export type HistorySourceStats = { recording_source: string; entry_count: number; word_count: number; audio_duration_ms: number }
+export type HistoryStats = { entry_count: number; word_count: number; char_count: number; audio_duration_ms: number; 
+/**
+ * Speaking rate across dictations, or `None` before any audio was kept.
+ */
+average_wpm: number | null; 
+/**
+ * Time typing the dictated words would have taken at `typing_wpm`,
+ * less the time spent saying them.
+ */
+time_saved_ms: number; typing_wpm: number; 
+/**
+ * Oldest first, in local time.
+ */
+days: HistoryStatsPeriod[]; 
+/**
+ * Oldest first, starting on Mondays.
+ */
+weeks: HistoryStatsPeriod[]; 
+/**
+ * Most used first.
+ */
+sources: HistorySourceStats[]; 
+/**
+ * Most used first. Only entries whose app was captured are counted.
+ */
+apps: HistoryAppStats[] }
+export type HistoryStatsPeriod = { 
+/**
+ * `YYYY-MM-DD` of the first day in the period.
+ */
+start_date: string; entry_count: number; word_count: number; char_count: number; audio_duration_ms: number }
+export type HistoryTag = { name: string; entry_count: number }
 /**
  * Result of changing keyboard implementation
  */
@@ -924,13 +1397,70 @@ export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null
 export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
 export type OverlayPosition = "none" | "top" | "bottom"
 export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
+/**
+ * A recording the webview can load. `temporary` marks a decrypted copy that
+ * has to be released once it has been read.
+ */
+export type PlayableAudio = { path: string; temporary: boolean }
 export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
-export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
+export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3" | "years_1"
 export type SavedFileTranscription = { file_name: string; transcription_text: string; post_processed_text?: string | null; source_path?: string | null }
 export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
 export type SoundTheme = "marimba" | "pop" | "custom"
+export type TranscriptExportFormat = "srt" | "vtt" | "json"
+/**
+ * A span of transcript text with offsets (in milliseconds) into the saved WAV.
+ */
+export type TranscriptSegment = { start_ms: number; end_ms: number; text: string }
+/**
+ * Timings reported by the engine for one entry. `words` stays empty for
+ * engines that only report segment-level timings.
+ */
+export type TranscriptTimestamps = { segments: TranscriptSegment[]; words: TranscriptSegment[] }
+/**
+ * What to do when a cloud transcription provider fails mid-dictation.
+ */
+export type TranscriptionFallbackPolicy = 
+/**
+ * Surface the provider error.
+ */
+"disabled" | 
+/**
+ * Retry on a downloaded local model.
+ */
+"local_model" | 
+/**
+ * Retry on the other cloud provider when a key is configured, then on a
+ * downloaded local model.
+ */
+"cloud_then_local"
 export type TrialState = "new" | "trialing" | "expired" | "linked"
 export type TypingTool = "auto" | "wtype" | "kwtype" | "dotool" | "ydotool" | "xdotool"
+export type VocabularySuggestion = { 
+/**
+ * Words as they read before the correction.
+ */
+heard: string; 
+/**
+ * The user's spelling, proposed as the new term.
+ */
+corrected: string; 
+/**
+ * Whether adding `corrected` to custom words would already turn `heard`
+ * into it at the current correction threshold. Always false for
+ * corrections to post-processed text, which custom words never see;
+ * the term then only helps as a post-processing vocabulary hint.
+ */
+fixable_by_custom_words: boolean }
+/**
+ * Spoken editing commands recognized in dictated text.
+ */
+export type VoiceCommand = "new_line" | "new_paragraph" | 
+/**
+ * Removes the phrase dictated since the previous command, or the whole
+ * previous dictation when spoken on its own.
+ */
+"scratch_that" | "delete_last_sentence" | "capitalize_next" | "all_caps"
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,82 @@
+import React, { useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+import { commands } from "@/bindings";
+
+interface HistoryEncryptionProgress {
+  processed: number;
+  total: number;
+}
+
+interface HistoryEncryptionToggleProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const HistoryEncryptionToggle: React.FC<HistoryEncryptionToggleProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const [isUpdating, setIsUpdating] = useState(false);
+    const [progress, setProgress] = useState<HistoryEncryptionProgress | null>(
+      null,
+    );
+    const encrypted = getSetting("encrypt_history_at_rest") ?? false;
+
+    // Existing history is converted before the setting changes, which can
+    // take a while for long meeting recordings.
+    const handleChange = async (enabled: boolean) => {
+      setIsUpdating(true);
+      const unlisten = await listen<HistoryEncryptionProgress>(
+        "history-encryption-progress",
+        (event) => setProgress(event.payload),
+      );
+      try {
+        const result = await commands.setHistoryEncryption(enabled);
+        if (result.status === "error") {
+          throw new Error(result.error);
+        }
+        await refreshSettings();
+      } catch (error) {
+        console.error("Failed to change history encryption:", error);
+        toast.error(error instanceof Error ? error.message : String(error));
+      } finally {
+        unlisten();
+        setProgress(null);
+        setIsUpdating(false);
+      }
+    };
+
+    return (
+      <ToggleSwitch
+        checked={encrypted}
+        onChange={(enabled) => {
+          void handleChange(enabled);
+        }}
+        isUpdating={isUpdating}
+        label={t("settings.advanced.historyEncryption.label", {
+          defaultValue: "Encrypt history",
+        })}
+        description={
+          progress
+            ? t("settings.advanced.historyEncryption.progress", {
+                defaultValue:
+                  "Converting recordings: {{processed}} of {{total}}",
+                processed: progress.processed,
+                total: progress.total,
+              })
+            : t("settings.advanced.historyEncryption.description", {
+                defaultValue:
+                  "Encrypt transcripts, notes and recordings on disk with this device's key. Search is slower on large histories.",
+              })
+        }
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
+
+HistoryEncryptionToggle.displayName = "HistoryEncryptionToggle";
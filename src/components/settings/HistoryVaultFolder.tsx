@@ -0,0 +1,102 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import { commands } from "@/bindings";
+
+interface HistoryVaultFolderProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const HistoryVaultFolder: React.FC<HistoryVaultFolderProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const [isUpdating, setIsUpdating] = useState(false);
+    const vaultDir = getSetting("history_vault_dir") ?? null;
+
+    // Existing history is written to the folder before the setting is saved.
+    const setVaultDir = async (path: string | null) => {
+      setIsUpdating(true);
+      try {
+        const result = await commands.setHistoryVaultDir(path);
+        if (result.status === "error") {
+          throw new Error(result.error);
+        }
+        await refreshSettings();
+      } catch (error) {
+        console.error("Failed to change history vault folder:", error);
+        toast.error(error instanceof Error ? error.message : String(error));
+      } finally {
+        setIsUpdating(false);
+      }
+    };
+
+    const chooseFolder = async () => {
+      const selected = await open({
+        multiple: false,
+        directory: true,
+        defaultPath: vaultDir ?? undefined,
+      });
+      if (typeof selected === "string") {
+        await setVaultDir(selected);
+      }
+    };
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.historyVault.title", {
+          defaultValue: "Markdown vault",
+        })}
+        description={t("settings.advanced.historyVault.description", {
+          defaultValue:
+            "Keep a Markdown note for every history entry in a folder, such as an Obsidian vault. Notes follow edits and deletions and are not encrypted.",
+        })}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center gap-2">
+          {vaultDir && (
+            <span
+              className="max-w-48 truncate text-xs text-text/60"
+              title={vaultDir}
+            >
+              {vaultDir}
+            </span>
+          )}
+          <Button
+            variant="secondary"
+            size="sm"
+            onClick={() => void chooseFolder()}
+            disabled={isUpdating}
+          >
+            {vaultDir
+              ? t("settings.advanced.historyVault.change", {
+                  defaultValue: "Change…",
+                })
+              : t("settings.advanced.historyVault.choose", {
+                  defaultValue: "Choose folder…",
+                })}
+          </Button>
+          {vaultDir && (
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => void setVaultDir(null)}
+              disabled={isUpdating}
+            >
+              {t("settings.advanced.historyVault.stop", {
+                defaultValue: "Stop",
+              })}
+            </Button>
+          )}
+        </div>
+      </SettingContainer>
+    );
+  });
+
+HistoryVaultFolder.displayName = "HistoryVaultFolder";
@@ -43,6 +43,12 @@ export const RecordingRetentionPeriodSelector: React.FC<RecordingRetentionPeriod
         value: "months_3",
         label: t("settings.debug.recordingRetention.months3"),
       },
+      {
+        value: "years_1",
+        label: t("settings.debug.recordingRetention.years1", {
+          defaultValue: "After 1 year",
+        }),
+      },
     ];
 
     return (
@@ -0,0 +1,125 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import { RecordingRetentionPeriod } from "@/bindings";
+
+type TimeRetentionSetting =
+  | "meeting_retention_period"
+  | "dictation_audio_retention_period"
+  | "meeting_audio_retention_period";
+
+interface TimeRetentionPeriodProps {
+  setting: TimeRetentionSetting;
+  title: string;
+  description: string;
+  descriptionMode: "inline" | "tooltip";
+  grouped: boolean;
+}
+
+// These policies only expire by age, so there is no "keep latest" option.
+const TimeRetentionPeriodSelector: React.FC<TimeRetentionPeriodProps> = ({
+  setting,
+  title,
+  description,
+  descriptionMode,
+  grouped,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+
+  const options = [
+    { value: "never", label: t("settings.debug.recordingRetention.never") },
+    { value: "days_3", label: t("settings.debug.recordingRetention.days3") },
+    { value: "weeks_2", label: t("settings.debug.recordingRetention.weeks2") },
+    {
+      value: "months_3",
+      label: t("settings.debug.recordingRetention.months3"),
+    },
+    {
+      value: "years_1",
+      label: t("settings.debug.recordingRetention.years1", {
+        defaultValue: "After 1 year",
+      }),
+    },
+  ];
+
+  return (
+    <SettingContainer
+      title={title}
+      description={description}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <Dropdown
+        options={options}
+        selectedValue={getSetting(setting) || "never"}
+        onSelect={(period) =>
+          void updateSetting(setting, period as RecordingRetentionPeriod)
+        }
+        placeholder={t("settings.debug.recordingRetention.placeholder")}
+        disabled={isUpdating(setting)}
+      />
+    </SettingContainer>
+  );
+};
+
+interface SourceRetentionPeriodsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SourceRetentionPeriods: React.FC<SourceRetentionPeriodsProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+
+    return (
+      <>
+        <TimeRetentionPeriodSelector
+          setting="meeting_retention_period"
+          title={t("settings.advanced.meetingRetention.title", {
+            defaultValue: "Auto-Delete Meetings",
+          })}
+          description={t("settings.advanced.meetingRetention.description", {
+            defaultValue:
+              "Delete meeting transcripts and recordings after this long. Saved meetings are kept.",
+          })}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <TimeRetentionPeriodSelector
+          setting="dictation_audio_retention_period"
+          title={t("settings.advanced.dictationAudioRetention.title", {
+            defaultValue: "Delete Dictation Audio",
+          })}
+          description={t(
+            "settings.advanced.dictationAudioRetention.description",
+            {
+              defaultValue:
+                "Delete dictation recordings after this long but keep their text.",
+            },
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <TimeRetentionPeriodSelector
+          setting="meeting_audio_retention_period"
+          title={t("settings.advanced.meetingAudioRetention.title", {
+            defaultValue: "Delete Meeting Audio",
+          })}
+          description={t(
+            "settings.advanced.meetingAudioRetention.description",
+            {
+              defaultValue:
+                "Delete meeting recordings after this long but keep their transcripts and summaries.",
+            },
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+      </>
+    );
+  });
+
+SourceRetentionPeriods.displayName = "SourceRetentionPeriods";
@@ -0,0 +1,50 @@
+import React from "react";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import { TranscriptionFallbackPolicy } from "@/bindings";
+
+interface TranscriptionFallbackPolicyProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const fallbackOptions = [
+  { value: "local_model", label: "Local model" },
+  { value: "cloud_then_local", label: "Other cloud provider, then local" },
+  { value: "disabled", label: "Don't retry" },
+];
+
+export const TranscriptionFallbackPolicySelector: React.FC<TranscriptionFallbackPolicyProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const selectedPolicy =
+      getSetting("transcription_fallback_policy") || "local_model";
+
+    const handlePolicySelect = async (policy: string) => {
+      await updateSetting(
+        "transcription_fallback_policy",
+        policy as TranscriptionFallbackPolicy,
+      );
+    };
+
+    return (
+      <SettingContainer
+        title="Cloud Fallback"
+        description="What to do when cloud transcription fails, e.g. on a flaky connection. Local fallback needs a downloaded model."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={fallbackOptions}
+          selectedValue={selectedPolicy}
+          onSelect={handlePolicySelect}
+          disabled={isUpdating("transcription_fallback_policy")}
+        />
+      </SettingContainer>
+    );
+  });
+
+TranscriptionFallbackPolicySelector.displayName =
+  "TranscriptionFallbackPolicySelector";
@@ -0,0 +1,100 @@
+import React from "react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+import { VoiceCommand } from "@/bindings";
+
+interface VoiceCommandsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const voiceCommandOptions: {
+  value: VoiceCommand;
+  label: string;
+  description: string;
+}[] = [
+  {
+    value: "new_line",
+    label: '"New line"',
+    description: "Starts a new line.",
+  },
+  {
+    value: "new_paragraph",
+    label: '"New paragraph"',
+    description: "Inserts a blank line.",
+  },
+  {
+    value: "scratch_that",
+    label: '"Scratch that"',
+    description:
+      "Removes the phrase since the last command. On its own, deletes the previous dictation.",
+  },
+  {
+    value: "delete_last_sentence",
+    label: '"Delete last sentence"',
+    description:
+      "Removes the last sentence. On its own, deletes the last sentence of the previous dictation.",
+  },
+  {
+    value: "capitalize_next",
+    label: '"Capitalize next"',
+    description: "Capitalizes the next word.",
+  },
+  {
+    value: "all_caps",
+    label: '"All caps"',
+    description: "Writes the next word in capitals.",
+  },
+];
+
+export const VoiceCommands: React.FC<VoiceCommandsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("voice_commands_enabled") ?? false;
+    const selectedCommands =
+      getSetting("voice_commands") ??
+      voiceCommandOptions.map((option) => option.value);
+    const commandsUpdating = isUpdating("voice_commands");
+
+    const handleCommandToggle = (command: VoiceCommand, checked: boolean) => {
+      const nextCommands = checked
+        ? [...selectedCommands, command]
+        : selectedCommands.filter((value) => value !== command);
+      void updateSetting("voice_commands", nextCommands);
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(checked) =>
+            updateSetting("voice_commands_enabled", checked)
+          }
+          isUpdating={isUpdating("voice_commands_enabled")}
+          label="Voice Commands"
+          description='Edit by voice without post-processing, e.g. say "new line" or "scratch that". Works offline.'
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled &&
+          voiceCommandOptions.map((option) => (
+            <ToggleSwitch
+              key={option.value}
+              checked={selectedCommands.includes(option.value)}
+              onChange={(checked) =>
+                handleCommandToggle(option.value, checked)
+              }
+              isUpdating={commandsUpdating}
+              label={option.label}
+              description={option.description}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            />
+          ))}
+      </>
+    );
+  },
+);
+
+VoiceCommands.displayName = "VoiceCommands";
@@ -15,11 +15,25 @@ export const ApiKeysSettings: React.FC = () => {
     postProcessApiKeyStatuses,
     refreshInstallAccess,
     updatePostProcessApiKey,
+    updateCustomTranscriptionServer,
+    updateCustomTranscriptionApiKey,
+    getSetting,
     isUpdating,
   } = useSettings();
 
   const [groqApiKeyDraft, setGroqApiKeyDraft] = useState("");
   const [openAiApiKeyDraft, setOpenAiApiKeyDraft] = useState("");
+  const savedCustomBaseUrl = getSetting("custom_transcription_base_url") ?? "";
+  const savedCustomModel = getSetting("custom_transcription_model") ?? "";
+  const [customBaseUrlDraft, setCustomBaseUrlDraft] =
+    useState(savedCustomBaseUrl);
+  const [customModelDraft, setCustomModelDraft] = useState(savedCustomModel);
+  const [customApiKeyDraft, setCustomApiKeyDraft] = useState("");
+
+  useEffect(() => {
+    setCustomBaseUrlDraft(savedCustomBaseUrl);
+    setCustomModelDraft(savedCustomModel);
+  }, [savedCustomBaseUrl, savedCustomModel]);
 
   useEffect(() => {
     if (installAccess === null) {
@@ -32,6 +46,13 @@ export const ApiKeysSettings: React.FC = () => {
 
   const isGroqKeyUpdating = isUpdating("post_process_api_key:groq");
   const isOpenAiKeyUpdating = isUpdating("post_process_api_key:openai");
+  const hasStoredCustomSecret =
+    postProcessApiKeyStatuses.custom_transcription ?? false;
+  const isCustomServerUpdating = isUpdating("custom_transcription_server");
+  const isCustomKeyUpdating = isUpdating("custom_transcription_api_key");
+  const customServerChanged =
+    customBaseUrlDraft.trim() !== savedCustomBaseUrl ||
+    customModelDraft.trim() !== savedCustomModel;
 
   const handleSaveGroqKey = async () => {
     await updatePostProcessApiKey("groq", groqApiKeyDraft.trim());
@@ -53,6 +74,23 @@ export const ApiKeysSettings: React.FC = () => {
     setOpenAiApiKeyDraft("");
   };
 
+  const handleSaveCustomServer = async () => {
+    await updateCustomTranscriptionServer(
+      customBaseUrlDraft.trim(),
+      customModelDraft.trim(),
+    );
+  };
+
+  const handleSaveCustomKey = async () => {
+    await updateCustomTranscriptionApiKey(customApiKeyDraft.trim());
+    setCustomApiKeyDraft("");
+  };
+
+  const handleClearCustomKey = async () => {
+    await updateCustomTranscriptionApiKey("");
+    setCustomApiKeyDraft("");
+  };
+
   return (
     <div className="mx-auto w-full max-w-3xl space-y-6">
       <div>
@@ -212,6 +250,125 @@ export const ApiKeysSettings: React.FC = () => {
           </div>
         </div>
       </div>
+
+      <div className="rounded-[20px] border border-white/8 bg-[rgba(255,255,255,0.026)] p-5 shadow-[inset_0_1px_0_rgba(255,255,255,0.02)]">
+        <h2 className="text-xl font-semibold tracking-tight text-text">
+          {t("settings.apiKeys.custom.title", {
+            defaultValue: "Custom Server",
+          })}
+        </h2>
+        <p className="mt-1 text-sm text-text/48">
+          {t("settings.apiKeys.custom.description", {
+            defaultValue:
+              "Any OpenAI-compatible /audio/transcriptions server, such as whisper.cpp or faster-whisper. Select the Custom Server model to use it.",
+          })}
+        </p>
+
+        <div className="mt-5 space-y-4">
+          <div className="space-y-2.5">
+            <label className="text-xs font-medium uppercase tracking-[0.18em] text-text/34">
+              {t("settings.apiKeys.custom.baseUrlLabel", {
+                defaultValue: "Base URL",
+              })}
+            </label>
+            <Input
+              type="text"
+              value={customBaseUrlDraft}
+              onChange={(event) => setCustomBaseUrlDraft(event.target.value)}
+              placeholder="http://localhost:8080/v1"
+              disabled={isCustomServerUpdating}
+              className="w-full"
+            />
+          </div>
+
+          <div className="space-y-2.5">
+            <label className="text-xs font-medium uppercase tracking-[0.18em] text-text/34">
+              {t("settings.apiKeys.custom.modelLabel", {
+                defaultValue: "Model",
+              })}
+            </label>
+            <Input
+              type="text"
+              value={customModelDraft}
+              onChange={(event) => setCustomModelDraft(event.target.value)}
+              placeholder="whisper-1"
+              disabled={isCustomServerUpdating}
+              className="w-full"
+            />
+          </div>
+
+          <div className="flex flex-wrap gap-2 pt-1">
+            <Button
+              type="button"
+              variant="primary-soft"
+              size="sm"
+              onClick={() => {
+                void handleSaveCustomServer();
+              }}
+              disabled={isCustomServerUpdating || !customServerChanged}
+            >
+              {t("settings.apiKeys.custom.saveServer", {
+                defaultValue: "Save server",
+              })}
+            </Button>
+          </div>
+
+          <div className="space-y-2.5">
+            <label className="text-xs font-medium uppercase tracking-[0.18em] text-text/34">
+              {t("settings.apiKeys.custom.keyLabel", {
+                defaultValue: "API key (optional)",
+              })}
+            </label>
+            <Input
+              type="password"
+              value={customApiKeyDraft}
+              onChange={(event) => setCustomApiKeyDraft(event.target.value)}
+              placeholder={
+                hasStoredCustomSecret
+                  ? t("settings.apiKeys.custom.stored", {
+                      defaultValue: "Saved key",
+                    })
+                  : ""
+              }
+              disabled={isCustomKeyUpdating}
+              className="w-full"
+            />
+          </div>
+
+          <div className="flex flex-wrap gap-2 pt-1">
+            <Button
+              type="button"
+              variant="primary-soft"
+              size="sm"
+              onClick={() => {
+                void handleSaveCustomKey();
+              }}
+              disabled={
+                isCustomKeyUpdating ||
+                hasStoredCustomSecret ||
+                customApiKeyDraft.trim().length === 0
+              }
+            >
+              {t("settings.apiKeys.save", { defaultValue: "Save key" })}
+            </Button>
+            {hasStoredCustomSecret && (
+              <Button
+                type="button"
+                variant="ghost"
+                size="sm"
+                onClick={() => {
+                  void handleClearCustomKey();
+                }}
+                disabled={isCustomKeyUpdating}
+              >
+                {t("settings.apiKeys.clear", {
+                  defaultValue: "Clear key",
+                })}
+              </Button>
+            )}
+          </div>
+        </div>
+      </div>
     </div>
   );
 };
@@ -1,4 +1,4 @@
-import React, { useCallback, useEffect, useReducer } from "react";
+import React, { useCallback, useEffect, useReducer, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { listen } from "@tauri-apps/api/event";
 import { getCurrentWebview } from "@tauri-apps/api/webview";
@@ -15,7 +15,7 @@ import {
   Upload,
 } from "lucide-react";
 import { toast } from "sonner";
-import { commands, type SavedFileTranscription } from "@/bindings";
+import { commands, type HistoryEntry } from "@/bindings";
 import { Alert } from "@/components/ui/Alert";
 import { Button } from "@/components/ui/Button";
 import { Textarea } from "@/components/ui/Textarea";
@@ -26,6 +26,7 @@ import {
 } from "@/lib/utils/premiumFeatures";
 
 const SUPPORTED_EXTENSIONS = ["wav", "mp3", "m4a", "aac", "ogg"] as const;
+const RECENT_FILE_TRANSCRIPTIONS = 5;
 
 interface FileTranscriptionProgressEvent {
   percentage: number;
@@ -139,8 +140,8 @@ const fileNameFromPath = (path: string) => {
 
 const useFileTranscriptionController = () => {
   const { t } = useTranslation();
-  const { installAccess, refreshInstallAccess, settings, refreshSettings } =
-    useSettings();
+  const { installAccess, refreshInstallAccess } = useSettings();
+  const [history, setHistory] = useState<HistoryEntry[]>([]);
   const [
     {
       isDragActive,
@@ -154,7 +155,6 @@ const useFileTranscriptionController = () => {
     dispatch,
   ] = useReducer(fileTranscriptionReducer, fileTranscriptionInitialState);
 
-  const history = settings?.file_transcription_history ?? [];
   const supportedFormatsText = SUPPORTED_EXTENSIONS.join(", ");
   const accessLoaded = installAccess !== null;
   const premiumLocked = isPremiumFeatureLocked(installAccess);
@@ -166,6 +166,41 @@ const useFileTranscriptionController = () => {
     void refreshInstallAccess();
   }, [refreshInstallAccess]);
 
+  // File transcriptions are saved to history, so the recent list is read
+  // from there and follows edits and deletes made on the History page.
+  const loadHistory = useCallback(async () => {
+    const result = await commands.getHistoryEntries(
+      null,
+      RECENT_FILE_TRANSCRIPTIONS,
+      { source: "file_imports", folder_id: null, tag: null },
+    );
+    if (result.status === "ok") {
+      setHistory(result.data.entries);
+    } else {
+      console.error("Failed to load file transcriptions:", result.error);
+    }
+  }, []);
+
+  useEffect(() => {
+    void loadHistory();
+    let unlisten: (() => void) | undefined;
+    listen("history-updated", () => {
+      void loadHistory();
+    })
+      .then((fn) => {
+        unlisten = fn;
+      })
+      .catch((error) => {
+        console.error("Failed to listen for history updates:", error);
+      });
+
+    return () => {
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, [loadHistory]);
+
   const selectPath = useCallback(
     (path: string) => {
       dispatch({
@@ -295,7 +330,7 @@ const useFileTranscriptionController = () => {
             defaultValue: "Transcription finished.",
           }),
         });
-        await refreshSettings();
+        await loadHistory();
         return;
       }
 
@@ -329,9 +364,9 @@ const useFileTranscriptionController = () => {
     }
   }, [
     isProcessing,
+    loadHistory,
     lockedMessage,
     premiumLocked,
-    refreshSettings,
     selectedFilePath,
     t,
   ]);
@@ -371,8 +406,8 @@ const useFileTranscriptionController = () => {
   const clearPersistedResult = useCallback(async () => {
     clearResult();
     await commands.clearFileTranscriptionHistory();
-    await refreshSettings();
-  }, [clearResult, refreshSettings]);
+    await loadHistory();
+  }, [clearResult, loadHistory]);
 
   useEffect(() => {
     let unlisten: (() => void) | undefined;
@@ -460,7 +495,7 @@ const useFileTranscriptionController = () => {
 };
 
 interface FileTranscriptionHistoryListProps {
-  history: SavedFileTranscription[];
+  history: HistoryEntry[];
   onCopyTranscript: (transcriptText: string) => void;
 }
 
@@ -468,12 +503,7 @@ const FileTranscriptionHistoryList: React.FC<
   FileTranscriptionHistoryListProps
 > = ({ history, onCopyTranscript }) => {
   const { t } = useTranslation();
-  const latestEntry = history[0];
-  const latestTranscriptText =
-    latestEntry?.post_processed_text || latestEntry?.transcription_text;
-  const latestEntryKey = latestEntry
-    ? `${latestEntry.source_path ?? latestEntry.file_name}-${latestTranscriptText}`
-    : null;
+  const latestEntryId = history[0]?.id;
 
   if (history.length === 0) {
     return null;
@@ -490,19 +520,18 @@ const FileTranscriptionHistoryList: React.FC<
         <p className="text-sm text-text/55">
           {t("settings.fileTranscription.historyDescription", {
             defaultValue:
-              "Uttr keeps the five most recent file transcriptions from this screen.",
+              "Your five most recent file transcriptions. All of them are also kept in History.",
           })}
         </p>
       </div>
 
-      {history.map((entry: SavedFileTranscription) => {
+      {history.map((entry: HistoryEntry) => {
         const transcriptText =
           entry.post_processed_text || entry.transcription_text;
-        const entryKey = `${entry.source_path ?? entry.file_name}-${transcriptText}`;
 
         return (
           <div
-            key={entryKey}
+            key={entry.id}
             className="rounded-[18px] border border-white/7 bg-white/[0.02] p-4"
           >
             <div className="mb-3 flex flex-wrap items-start justify-between gap-3">
@@ -513,11 +542,11 @@ const FileTranscriptionHistoryList: React.FC<
                   })}
                 </p>
                 <p className="mt-1 text-sm font-medium text-text">
-                  {entry.file_name}
+                  {entry.title}
                 </p>
               </div>
               <div className="flex items-center gap-2">
-                {entryKey === latestEntryKey && (
+                {entry.id === latestEntryId && (
                   <span className="rounded-full border border-logo-primary/20 bg-logo-primary/10 px-3 py-1 text-xs font-medium text-logo-primary">
                     {t("settings.fileTranscription.latestBadge", {
                       defaultValue: "Latest",
@@ -832,7 +861,7 @@ export const FileTranscriptionSettings: React.FC = () => {
           <Alert variant="info">
             {t("settings.fileTranscription.emptyState", {
               defaultValue:
-                "Uttr keeps your five most recent file transcriptions here. They are not added to transcription history.",
+                "Your five most recent file transcriptions appear here. They are also saved to History.",
             })}
           </Alert>
         )}
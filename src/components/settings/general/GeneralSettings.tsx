@@ -11,6 +11,8 @@ import { AlwaysOnMicrophone } from "../AlwaysOnMicrophone";
 import { AutostartToggle } from "../AutostartToggle";
 import { ShowTrayIcon } from "../ShowTrayIcon";
 import { TypingToolSetting } from "../TypingTool";
+import { TranscriptionFallbackPolicySelector } from "../TranscriptionFallbackPolicy";
+import { VoiceCommands } from "../VoiceCommands";
 import { PostProcessingToggle } from "../PostProcessingToggle";
 import {
   PostProcessingSettingsAdvanced,
@@ -18,6 +20,9 @@ import {
 } from "../post-processing/PostProcessingSettings";
 import { HistoryLimit } from "../HistoryLimit";
 import { RecordingRetentionPeriodSelector } from "../RecordingRetentionPeriod";
+import { SourceRetentionPeriods } from "../SourceRetentionPeriods";
+import { HistoryEncryptionToggle } from "../HistoryEncryptionToggle";
+import { HistoryVaultFolder } from "../HistoryVaultFolder";
 import { AppLanguageSelector } from "../AppLanguageSelector";
 import { useSettings } from "../../../hooks/useSettings";
 
@@ -26,6 +31,8 @@ export const GeneralSettings: React.FC = () => {
   const { getSetting, updateSetting, isUpdating } = useSettings();
   const postProcessEnabled = getSetting("post_process_enabled") || false;
   const editModeEnabled = Boolean(getSetting("edit_mode_enabled"));
+  const spokenFormattingEnabled =
+    getSetting("inverse_text_normalization_enabled") ?? false;
   const postProcessPromptPreset =
     (getSetting("post_process_cleaning_prompt_preset") as string | undefined) ??
     "strict";
@@ -52,6 +59,22 @@ export const GeneralSettings: React.FC = () => {
       </SettingsGroup>
       <SettingsGroup title={t("settings.advanced.groups.transcription")}>
         <TypingToolSetting descriptionMode="tooltip" grouped={true} />
+        <TranscriptionFallbackPolicySelector
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+        <ToggleSwitch
+          checked={spokenFormattingEnabled}
+          onChange={(checked) =>
+            updateSetting("inverse_text_normalization_enabled", checked)
+          }
+          isUpdating={isUpdating("inverse_text_normalization_enabled")}
+          label="Spoken Formatting"
+          description='Turn spoken punctuation and numbers into text, e.g. "comma" becomes "," and "twenty five percent" becomes "25%". Applies when the language is set to English or Spanish. Works offline.'
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+        <VoiceCommands descriptionMode="tooltip" grouped={true} />
         <ShortcutInput
           shortcutId="transcribe_with_post_process"
           grouped={true}
@@ -78,6 +101,9 @@ export const GeneralSettings: React.FC = () => {
           descriptionMode="tooltip"
           grouped={true}
         />
+        <SourceRetentionPeriods descriptionMode="tooltip" grouped={true} />
+        <HistoryEncryptionToggle descriptionMode="tooltip" grouped={true} />
+        <HistoryVaultFolder descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
     </div>
   );
@@ -0,0 +1,210 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { Button } from "../../ui/Button";
+import { Dropdown } from "../../ui/Dropdown";
+import { useModelStore } from "@/stores/modelStore";
+import { LANGUAGES } from "@/lib/constants/languages";
+import { useSettings } from "../../../hooks/useSettings";
+import {
+  commands,
+  type HistoryAlternate,
+  type HistoryEntry,
+} from "@/bindings";
+
+const errorMessage = (error: unknown) =>
+  error instanceof Error ? error.message : String(error);
+
+interface HistoryEntryAlternatesProps {
+  entry: HistoryEntry;
+  onClose: () => void;
+}
+
+// Runs an entry's recording through another model or language and lists the
+// results next to the current text until one is used or discarded.
+export const HistoryEntryAlternates: React.FC<HistoryEntryAlternatesProps> = ({
+  entry,
+  onClose,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting } = useSettings();
+  const { models, initialize } = useModelStore();
+  const [modelId, setModelId] = useState(
+    entry.transcription_model ?? getSetting("selected_model") ?? "",
+  );
+  const [language, setLanguage] = useState(
+    getSetting("selected_language") || "auto",
+  );
+  const [postProcess, setPostProcess] = useState(
+    Boolean(entry.post_processed_text),
+  );
+  const [alternates, setAlternates] = useState<HistoryAlternate[]>([]);
+  const [running, setRunning] = useState(false);
+
+  useEffect(() => {
+    void initialize();
+    void commands.getHistoryEntryAlternates(entry.id).then((result) => {
+      if (result.status === "ok") {
+        setAlternates(result.data);
+      } else {
+        console.error("Failed to load alternates:", result.error);
+      }
+    });
+  }, [entry.id, initialize]);
+
+  const modelName = (id: string | null) =>
+    models.find((model) => model.id === id)?.name ?? id ?? "";
+
+  const handleRun = async () => {
+    setRunning(true);
+    try {
+      const result = await commands.retranscribeHistoryEntry(
+        entry.id,
+        modelId || null,
+        language,
+        postProcess,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      setAlternates((current) => [result.data, ...current]);
+    } catch (error) {
+      console.error("Failed to transcribe entry again:", error);
+      toast.error(errorMessage(error));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  const handleApply = async (alternateId: number) => {
+    try {
+      const result = await commands.applyHistoryEntryAlternate(
+        entry.id,
+        alternateId,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      onClose();
+    } catch (error) {
+      console.error("Failed to use alternate transcript:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  const handleDiscard = async (alternateId: number) => {
+    try {
+      const result = await commands.deleteHistoryEntryAlternate(
+        entry.id,
+        alternateId,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      setAlternates((current) =>
+        current.filter((alternate) => alternate.id !== alternateId),
+      );
+    } catch (error) {
+      console.error("Failed to discard alternate transcript:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  return (
+    <div className="space-y-3 rounded-lg border border-white/8 bg-white/[0.02] p-3">
+      <div className="flex flex-wrap items-center gap-2">
+        <Dropdown
+          options={models
+            .filter((model) => model.is_downloaded)
+            .map((model) => ({ value: model.id, label: model.name }))}
+          selectedValue={modelId}
+          onSelect={setModelId}
+          placeholder={t("settings.history.retranscribe.model", {
+            defaultValue: "Model",
+          })}
+          disabled={running}
+        />
+        <Dropdown
+          options={LANGUAGES}
+          selectedValue={language}
+          onSelect={setLanguage}
+          disabled={running}
+        />
+        <label className="flex items-center gap-1.5 text-xs text-text/60">
+          <input
+            type="checkbox"
+            checked={postProcess}
+            onChange={(event) => setPostProcess(event.target.checked)}
+            disabled={running}
+          />
+          {t("settings.history.retranscribe.postProcess", {
+            defaultValue: "Post-process",
+          })}
+        </label>
+      </div>
+      <div className="flex items-center gap-2">
+        <Button
+          type="button"
+          variant="primary-soft"
+          size="sm"
+          disabled={running}
+          onClick={() => {
+            void handleRun();
+          }}
+        >
+          {running
+            ? t("settings.history.retranscribe.running", {
+                defaultValue: "Transcribing…",
+              })
+            : t("settings.history.retranscribe.run", {
+                defaultValue: "Transcribe again",
+              })}
+        </Button>
+        <Button type="button" variant="ghost" size="sm" onClick={onClose}>
+          {t("settings.history.retranscribe.close", { defaultValue: "Close" })}
+        </Button>
+      </div>
+      {alternates.map((alternate) => (
+        <div
+          key={alternate.id}
+          className="space-y-2 border-t border-white/6 pt-3"
+        >
+          <p className="text-xs text-text/50">
+            {[modelName(alternate.transcription_model), alternate.language]
+              .filter(Boolean)
+              .join(" · ")}
+          </p>
+          <p className="whitespace-pre-wrap text-sm text-text/80">
+            {alternate.post_processed_text || alternate.transcription_text}
+          </p>
+          <div className="flex items-center gap-2">
+            <Button
+              type="button"
+              variant="secondary"
+              size="sm"
+              onClick={() => {
+                void handleApply(alternate.id);
+              }}
+            >
+              {t("settings.history.retranscribe.apply", {
+                defaultValue: "Use this version",
+              })}
+            </Button>
+            <Button
+              type="button"
+              variant="danger-ghost"
+              size="sm"
+              onClick={() => {
+                void handleDiscard(alternate.id);
+              }}
+            >
+              {t("settings.history.retranscribe.discard", {
+                defaultValue: "Discard",
+              })}
+            </Button>
+          </div>
+        </div>
+      ))}
+    </div>
+  );
+};
@@ -0,0 +1,131 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { Button } from "../../ui/Button";
+import { Dropdown } from "../../ui/Dropdown";
+import { Textarea } from "../../ui/Textarea";
+import { useSettings } from "../../../hooks/useSettings";
+import {
+  commands,
+  type CleaningPromptPreset,
+  type HistoryEntry,
+} from "@/bindings";
+
+const errorMessage = (error: unknown) =>
+  error instanceof Error ? error.message : String(error);
+
+interface HistoryEntryReprocessorProps {
+  entry: HistoryEntry;
+  onClose: () => void;
+}
+
+// Runs post-processing again on the raw transcript, e.g. to turn a dictated
+// brain-dump into an email. The replaced output can be restored from the
+// entry's earlier versions.
+export const HistoryEntryReprocessor: React.FC<
+  HistoryEntryReprocessorProps
+> = ({ entry, onClose }) => {
+  const { t } = useTranslation();
+  const { settings } = useSettings();
+  const providers = settings?.post_process_providers ?? [];
+  const [providerId, setProviderId] = useState(
+    settings?.post_process_provider_id ?? "",
+  );
+  const [preset, setPreset] = useState<CleaningPromptPreset>("strict");
+  const [customPrompt, setCustomPrompt] = useState("");
+  const [running, setRunning] = useState(false);
+
+  const handleRun = async () => {
+    setRunning(true);
+    try {
+      const result = await commands.reprocessHistoryEntry(
+        entry.id,
+        preset,
+        preset === "custom" ? customPrompt : null,
+        providerId || null,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      onClose();
+    } catch (error) {
+      console.error("Failed to post-process entry again:", error);
+      toast.error(errorMessage(error));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  return (
+    <div className="space-y-3 rounded-lg border border-white/8 bg-white/[0.02] p-3">
+      <div className="flex flex-wrap items-center gap-2">
+        <Dropdown
+          options={providers.map((provider) => ({
+            value: provider.id,
+            label: provider.label,
+          }))}
+          selectedValue={providerId}
+          onSelect={setProviderId}
+          placeholder={t("settings.history.reprocess.provider", {
+            defaultValue: "Provider",
+          })}
+          disabled={running}
+        />
+        <Dropdown
+          options={[
+            {
+              value: "strict",
+              label: t("settings.history.reprocess.default", {
+                defaultValue: "Default prompt",
+              }),
+            },
+            {
+              value: "custom",
+              label: t("settings.history.reprocess.custom", {
+                defaultValue: "Custom prompt",
+              }),
+            },
+          ]}
+          selectedValue={preset}
+          onSelect={(value) => setPreset(value as CleaningPromptPreset)}
+          disabled={running}
+        />
+      </div>
+      {preset === "custom" && (
+        <Textarea
+          value={customPrompt}
+          onChange={(event) => setCustomPrompt(event.target.value)}
+          placeholder={t("settings.history.reprocess.promptPlaceholder", {
+            defaultValue: "Rewrite this dictation as a short, friendly email.",
+          })}
+          className="w-full font-normal"
+          disabled={running}
+        />
+      )}
+      <div className="flex items-center gap-2">
+        <Button
+          type="button"
+          variant="primary-soft"
+          size="sm"
+          disabled={
+            running || (preset === "custom" && customPrompt.trim() === "")
+          }
+          onClick={() => {
+            void handleRun();
+          }}
+        >
+          {running
+            ? t("settings.history.reprocess.running", {
+                defaultValue: "Processing…",
+              })
+            : t("settings.history.reprocess.run", {
+                defaultValue: "Post-process again",
+              })}
+        </Button>
+        <Button type="button" variant="ghost" size="sm" onClick={onClose}>
+          {t("settings.history.reprocess.close", { defaultValue: "Close" })}
+        </Button>
+      </div>
+    </div>
+  );
+};
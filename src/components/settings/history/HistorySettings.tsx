@@ -1,27 +1,114 @@
-import React, { useState, useEffect, useCallback, useReducer } from "react";
+import React, {
+  useState,
+  useEffect,
+  useCallback,
+  useReducer,
+  useRef,
+} from "react";
 import { useTranslation } from "react-i18next";
 import { AudioPlayer } from "../../ui/AudioPlayer";
+import Badge from "../../ui/Badge";
 import { Button } from "../../ui/Button";
-import { Copy, Star, Check, Trash2, FolderOpen } from "lucide-react";
+import { Dropdown } from "../../ui/Dropdown";
+import { Input } from "../../ui/Input";
+import { Textarea } from "../../ui/Textarea";
+import {
+  Copy,
+  Star,
+  Check,
+  Trash2,
+  FolderOpen,
+  Download,
+  Upload,
+  Pencil,
+  Tags,
+  BarChart3,
+  RefreshCw,
+  Wand2,
+} from "lucide-react";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { open, save } from "@tauri-apps/plugin-dialog";
 import { readFile } from "@tauri-apps/plugin-fs";
-import { commands, type HistoryEntry } from "@/bindings";
+import { toast } from "sonner";
+import {
+  commands,
+  type HistoryCursor,
+  type HistoryEntry,
+  type HistoryExportSummary,
+  type HistoryFolder,
+  type HistoryPage,
+  type HistoryRevision,
+  type HistorySearchResult,
+  type HistorySourceFilter,
+  type HistoryTag,
+  type Result,
+  type VocabularySuggestion,
+} from "@/bindings";
 import { formatDateTime } from "@/utils/dateFormat";
 import { useOsType } from "@/hooks/useOsType";
+import { useSettings } from "@/hooks/useSettings";
 import { logFrontendStartup } from "@/lib/startupLog";
+import { HistoryEntryAlternates } from "./HistoryEntryAlternates";
+import { HistoryEntryReprocessor } from "./HistoryEntryReprocessor";
+import { HistoryStatsPanel } from "./HistoryStatsPanel";
 
-const MAX_VISIBLE_HISTORY = 20;
+const HISTORY_PAGE_SIZE = 50;
+// Must match MAX_PAGE_SIZE in managers/history.rs.
+const MAX_HISTORY_PAGE_SIZE = 500;
+const SEARCH_DEBOUNCE_MS = 200;
+// Must match SEARCH_MATCH_START/SEARCH_MATCH_END in managers/history.rs.
+const SEARCH_MATCH_START = "\uE000";
+const SEARCH_MATCH_END = "\uE001";
+const SECONDS_PER_DAY = 24 * 60 * 60;
 type HistoryTab = "dictations" | "sessions";
 type HistoryMode = "dictations" | "meetings" | "all";
+type HistoryDateRange = "any" | "week" | "month" | "year";
+
+const DATE_RANGE_DAYS: Record<HistoryDateRange, number | null> = {
+  any: null,
+  week: 7,
+  month: 31,
+  year: 365,
+};
+
+const HISTORY_TAB_SOURCES: Record<HistoryTab, HistorySourceFilter> = {
+  dictations: "dictations",
+  sessions: "meetings",
+};
+
+const historyTabsForMode = (mode: HistoryMode): HistoryTab[] =>
+  mode === "all"
+    ? ["dictations", "sessions"]
+    : mode === "meetings"
+      ? ["sessions"]
+      : ["dictations"];
+
+// Narrows history to one folder and/or tag; null means no restriction.
+interface HistoryOrganizationFilter {
+  folderId: number | null;
+  tag: string | null;
+}
 
 interface HistoryFocusRequest {
   entryId: number | null;
   token: number;
 }
 
+interface HistoryTabPage {
+  entries: HistoryEntry[];
+  nextCursor: HistoryCursor | null;
+  totalCount: number;
+}
+
+const emptyHistoryTabPage: HistoryTabPage = {
+  entries: [],
+  nextCursor: null,
+  totalCount: 0,
+};
+
 interface HistorySettingsState {
-  historyEntries: HistoryEntry[];
+  pages: Record<HistoryTab, HistoryTabPage>;
   loading: boolean;
   highlightedEntryId: number | null;
   manualActiveTab: HistoryTab;
@@ -29,14 +116,19 @@ interface HistorySettingsState {
 }
 
 type HistorySettingsAction =
-  | { type: "entries_loaded"; entries: HistoryEntry[] }
+  | {
+      type: "page_loaded";
+      tab: HistoryTab;
+      page: HistoryPage;
+      append: boolean;
+    }
   | { type: "loading_finished" }
   | { type: "choose_tab"; tab: HistoryTab; ignoredFocusToken: number | null }
   | { type: "highlight"; entryId: number }
   | { type: "clear_highlight"; entryId: number };
 
 const historySettingsInitialState: HistorySettingsState = {
-  historyEntries: [],
+  pages: { dictations: emptyHistoryTabPage, sessions: emptyHistoryTabPage },
   loading: true,
   highlightedEntryId: null,
   manualActiveTab: "dictations",
@@ -48,8 +140,23 @@ const historySettingsReducer = (
   action: HistorySettingsAction,
 ): HistorySettingsState => {
   switch (action.type) {
-    case "entries_loaded":
-      return { ...state, historyEntries: action.entries, loading: false };
+    case "page_loaded": {
+      const previous = state.pages[action.tab];
+      return {
+        ...state,
+        pages: {
+          ...state.pages,
+          [action.tab]: {
+            entries: action.append
+              ? [...previous.entries, ...action.page.entries]
+              : action.page.entries,
+            nextCursor: action.page.next_cursor,
+            // Only the first page carries a count.
+            totalCount: action.page.total_count ?? previous.totalCount,
+          },
+        },
+      };
+    }
     case "loading_finished":
       return { ...state, loading: false };
     case "choose_tab":
@@ -105,6 +212,98 @@ const formatHistoryPreviewText = (text: string): string => {
   return preview || text.replace(/\s+/g, " ").trim();
 };
 
+const HighlightedSnippet: React.FC<{ snippet: string }> = ({ snippet }) => (
+  <>
+    {snippet.split(SEARCH_MATCH_START).map((part, index) => {
+      if (index === 0) {
+        return <React.Fragment key={index}>{part}</React.Fragment>;
+      }
+      const [match, rest = ""] = part.split(SEARCH_MATCH_END);
+      return (
+        <React.Fragment key={index}>
+          <mark className="rounded bg-logo-primary/20 px-0.5 text-text">
+            {match}
+          </mark>
+          {rest}
+        </React.Fragment>
+      );
+    })}
+  </>
+);
+
+const useHistorySearch = (
+  recordingSource: string | null,
+  organization: HistoryOrganizationFilter,
+  loadedPages: Record<HistoryTab, HistoryTabPage>,
+) => {
+  const [query, setQuery] = useState("");
+  const [savedOnly, setSavedOnly] = useState(false);
+  const [dateRange, setDateRange] = useState<HistoryDateRange>("any");
+  const [results, setResults] = useState<HistorySearchResult[] | null>(null);
+  const searchActive =
+    query.trim().length > 0 || savedOnly || dateRange !== "any";
+
+  useEffect(() => {
+    if (!searchActive) {
+      setResults(null);
+      return;
+    }
+
+    let cancelled = false;
+    const timeoutId = window.setTimeout(async () => {
+      const days = DATE_RANGE_DAYS[dateRange];
+      try {
+        const result = await commands.searchHistoryEntries(
+          query,
+          {
+            recording_source: recordingSource,
+            from_timestamp:
+              days === null
+                ? null
+                : Math.floor(Date.now() / 1000) - days * SECONDS_PER_DAY,
+            to_timestamp: null,
+            saved: savedOnly ? true : null,
+            folder_id: organization.folderId,
+            tag: organization.tag,
+          },
+          null,
+        );
+        if (!cancelled && result.status === "ok") {
+          setResults(result.data);
+        }
+      } catch (error) {
+        console.error("Failed to search history entries:", error);
+      }
+    }, SEARCH_DEBOUNCE_MS);
+
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timeoutId);
+    };
+    // loadedPages reruns the search when history changes.
+  }, [
+    searchActive,
+    query,
+    savedOnly,
+    dateRange,
+    recordingSource,
+    organization.folderId,
+    organization.tag,
+    loadedPages,
+  ]);
+
+  return {
+    query,
+    setQuery,
+    savedOnly,
+    setSavedOnly,
+    dateRange,
+    setDateRange,
+    searchActive,
+    results: searchActive ? results : null,
+  };
+};
+
 const toggleSaved = async (id: number) => {
   try {
     await commands.toggleHistoryEntrySaved(id);
@@ -131,6 +330,35 @@ const deleteAudioEntry = async (id: number) => {
   }
 };
 
+const HISTORY_BUNDLE_FILTERS = [{ name: "Uttr history", extensions: ["zip"] }];
+
+const errorMessage = (error: unknown) =>
+  error instanceof Error ? error.message : String(error);
+
+const unwrapResult = <T,>(result: Result<T, string>): T => {
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+};
+
+// Resolves to null when the save dialog is cancelled.
+const exportHistoryBundle = async (
+  ids: number[] | null,
+  defaultPath: string,
+): Promise<HistoryExportSummary | null> => {
+  const path = await save({ defaultPath, filters: HISTORY_BUNDLE_FILTERS });
+  if (path === null) {
+    return null;
+  }
+
+  const result = await commands.exportHistoryBundle(ids, path);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+};
+
 const openRecordingsFolder = async () => {
   try {
     await commands.openRecordingsFolder();
@@ -156,7 +384,7 @@ const useHistorySettingsController = ({
   const osType = useOsType();
   const [
     {
-      historyEntries,
+      pages,
       loading,
       highlightedEntryId,
       manualActiveTab,
@@ -164,12 +392,17 @@ const useHistorySettingsController = ({
     },
     dispatch,
   ] = useReducer(historySettingsReducer, historySettingsInitialState);
-  const dictationEntries = historyEntries.filter(
-    (entry) => entry.recording_source !== "full_system_audio",
-  );
-  const sessionEntries = historyEntries.filter(
-    (entry) => entry.recording_source === "full_system_audio",
-  );
+  const [folders, setFolders] = useState<HistoryFolder[]>([]);
+  const [tags, setTags] = useState<HistoryTag[]>([]);
+  const [organization, setOrganization] = useState<HistoryOrganizationFilter>({
+    folderId: null,
+    tag: null,
+  });
+  const organizationFilterActive =
+    organization.folderId !== null || organization.tag !== null;
+  const dictationEntries = pages.dictations.entries;
+  const sessionEntries = pages.sessions.entries;
+  const historyEntries = [...dictationEntries, ...sessionEntries];
   const showTabs = mode === "all";
   const focusedEntryId = focusRequest?.entryId ?? null;
   const focusToken = focusRequest?.token ?? null;
@@ -191,19 +424,32 @@ const useHistorySettingsController = ({
       : mode === "dictations"
         ? "dictations"
         : (focusedEntryTab ?? manualActiveTab);
-  const activeEntries =
-    mode === "meetings"
-      ? sessionEntries
-      : mode === "dictations"
-        ? dictationEntries
-        : activeTab === "sessions"
-          ? sessionEntries
-          : dictationEntries;
   const meetingsAreActive =
     mode === "meetings" || (mode === "all" && activeTab === "sessions");
-  const visibleEntries = meetingsAreActive
-    ? activeEntries
-    : activeEntries.slice(0, MAX_VISIBLE_HISTORY);
+  const search = useHistorySearch(
+    meetingsAreActive ? "full_system_audio" : null,
+    organization,
+    pages,
+  );
+  const searchSnippets = new Map(
+    (search.results ?? []).map((result) => [result.entry.id, result.snippet]),
+  );
+  const activeEntries = search.results
+    ? search.results
+        .map((result) => result.entry)
+        .filter(
+          (entry) =>
+            meetingsAreActive ||
+            entry.recording_source !== "full_system_audio",
+        )
+    : meetingsAreActive
+      ? sessionEntries
+      : dictationEntries;
+  const visibleEntries = activeEntries;
+  const activePage = pages[meetingsAreActive ? "sessions" : "dictations"];
+  const remainingCount = search.results
+    ? 0
+    : activePage.totalCount - activePage.entries.length;
   const focusedEntryVisible =
     focusedEntryId !== null &&
     historyEntries.some((entry) => entry.id === focusedEntryId);
@@ -219,26 +465,96 @@ const useHistorySettingsController = ({
           defaultValue: "Transcriptions",
         });
   const emptyLabel =
-    mode === "meetings"
-      ? t("settings.history.emptySessions", {
-          defaultValue: "No meetings yet.",
+    search.searchActive || organizationFilterActive
+      ? t("settings.history.noSearchResults", {
+          defaultValue: "No matching transcriptions.",
         })
-      : t("settings.history.emptyDictations", {
-          defaultValue: "No transcriptions yet.",
+      : mode === "meetings"
+        ? t("settings.history.emptySessions", {
+            defaultValue: "No meetings yet.",
+          })
+        : t("settings.history.emptyDictations", {
+            defaultValue: "No transcriptions yet.",
+          });
+
+  const loadedCountsRef = useRef<Record<HistoryTab, number>>({
+    dictations: 0,
+    sessions: 0,
+  });
+  loadedCountsRef.current = {
+    dictations: dictationEntries.length,
+    sessions: sessionEntries.length,
+  };
+
+  const loadHistoryPage = useCallback(
+    async (tab: HistoryTab, cursor: HistoryCursor | null, limit: number) => {
+      const result = await commands.getHistoryEntries(cursor, limit, {
+        source: HISTORY_TAB_SOURCES[tab],
+        folder_id: organization.folderId,
+        tag: organization.tag,
+      });
+      if (result.status === "ok") {
+        dispatch({
+          type: "page_loaded",
+          tab,
+          page: result.data,
+          append: cursor !== null,
         });
+      }
+    },
+    [organization],
+  );
+
+  const loadOrganization = useCallback(async () => {
+    const [folderResult, tagResult] = await Promise.all([
+      commands.getHistoryFolders(),
+      commands.getHistoryTags(),
+    ]);
+    if (folderResult.status === "ok") {
+      setFolders(folderResult.data);
+    }
+    if (tagResult.status === "ok") {
+      setTags(tagResult.data);
+    }
+  }, []);
 
   const loadHistoryEntries = useCallback(async () => {
     try {
-      const result = await commands.getHistoryEntries();
-      if (result.status === "ok") {
-        dispatch({ type: "entries_loaded", entries: result.data });
-      }
+      // Reload as many entries as are already shown so an update does not
+      // collapse a list the user has paged through.
+      await Promise.all([
+        ...historyTabsForMode(mode).map((tab) =>
+          loadHistoryPage(
+            tab,
+            null,
+            Math.min(
+              Math.max(HISTORY_PAGE_SIZE, loadedCountsRef.current[tab]),
+              MAX_HISTORY_PAGE_SIZE,
+            ),
+          ),
+        ),
+        loadOrganization(),
+      ]);
     } catch (error) {
       console.error("Failed to load history entries:", error);
     } finally {
       dispatch({ type: "loading_finished" });
     }
-  }, []);
+  }, [loadHistoryPage, loadOrganization, mode]);
+
+  const loadMoreEntries = async () => {
+    const tab = meetingsAreActive ? "sessions" : "dictations";
+    const cursor = pages[tab].nextCursor;
+    if (cursor === null) {
+      return;
+    }
+
+    try {
+      await loadHistoryPage(tab, cursor, HISTORY_PAGE_SIZE);
+    } catch (error) {
+      console.error("Failed to load more history entries:", error);
+    }
+  };
 
   useEffect(() => {
     loadHistoryEntries();
@@ -265,6 +581,73 @@ const useHistorySettingsController = ({
     };
   }, [loadHistoryEntries]);
 
+  const exportAllEntries = async () => {
+    try {
+      const summary = await exportHistoryBundle(null, "uttr-history.zip");
+      if (summary) {
+        toast.success(
+          t("settings.history.bundle.exported", {
+            defaultValue: "Exported {{count}} entries.",
+            count: summary.entries,
+          }),
+        );
+      }
+    } catch (error) {
+      console.error("Failed to export history:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  const importBundle = async () => {
+    const selected = await open({
+      multiple: false,
+      directory: false,
+      filters: HISTORY_BUNDLE_FILTERS,
+    });
+    if (typeof selected !== "string") {
+      return;
+    }
+
+    try {
+      const result = await commands.importHistoryBundle(selected);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      toast.success(
+        t("settings.history.bundle.imported", {
+          defaultValue:
+            "Imported {{count}} entries ({{skipped}} were already here).",
+          count: result.data.imported,
+          skipped: result.data.skipped,
+        }),
+      );
+    } catch (error) {
+      console.error("Failed to import history:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  const renameFolder = async (folder: HistoryFolder, name: string) => {
+    try {
+      unwrapResult(await commands.renameHistoryFolder(folder.id, name));
+      return true;
+    } catch (error) {
+      console.error("Failed to rename history folder:", error);
+      toast.error(errorMessage(error));
+      return false;
+    }
+  };
+
+  const deleteFolder = async (folder: HistoryFolder) => {
+    try {
+      unwrapResult(await commands.deleteHistoryFolder(folder.id));
+      setOrganization((current) => ({ ...current, folderId: null }));
+    } catch (error) {
+      console.error("Failed to delete history folder:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
   const chooseTab = (tab: HistoryTab) => {
     dispatch({ type: "choose_tab", tab, ignoredFocusToken: focusToken });
   };
@@ -311,14 +694,22 @@ const useHistorySettingsController = ({
       try {
         const result = await commands.getAudioFilePath(fileName);
         if (result.status === "ok") {
-          if (osType === "linux") {
-            const fileData = await readFile(result.data);
-            const blob = new Blob([fileData], { type: "audio/wav" });
+          const { path, temporary } = result.data;
+          // Decrypted copies are read into memory and deleted right away.
+          if (osType === "linux" || temporary) {
+            try {
+              const fileData = await readFile(path);
+              const blob = new Blob([fileData], { type: "audio/wav" });
 
-            return URL.createObjectURL(blob);
+              return URL.createObjectURL(blob);
+            } finally {
+              if (temporary) {
+                void commands.releasePlayableAudio(path);
+              }
+            }
           }
 
-          return convertFileSrc(result.data, "asset");
+          return convertFileSrc(path, "asset");
         }
         return null;
       } catch (error) {
@@ -334,17 +725,29 @@ const useHistorySettingsController = ({
     historyEntries,
     loading,
     highlightedEntryId,
-    dictationEntries,
-    sessionEntries,
     showTabs,
     activeTab,
+    pages,
     visibleEntries,
+    remainingCount,
     meetingsAreActive,
+    search,
+    searchSnippets,
+    folders,
+    tags,
+    organization,
+    setOrganization,
+    organizationFilterActive,
     containerClass,
     titleLabel,
     emptyLabel,
     compact,
     chooseTab,
+    loadMoreEntries,
+    exportAllEntries,
+    importBundle,
+    renameFolder,
+    deleteFolder,
     getAudioUrl,
     onOpenSessionEntry,
   };
@@ -356,20 +759,36 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
     historyEntries,
     loading,
     highlightedEntryId,
-    dictationEntries,
-    sessionEntries,
     showTabs,
     activeTab,
+    pages,
     visibleEntries,
+    remainingCount,
     meetingsAreActive,
+    search,
+    searchSnippets,
+    folders,
+    tags,
+    organization,
+    setOrganization,
+    organizationFilterActive,
     containerClass,
     titleLabel,
     emptyLabel,
     compact,
     chooseTab,
+    loadMoreEntries,
+    exportAllEntries,
+    importBundle,
+    renameFolder,
+    deleteFolder,
     getAudioUrl,
     onOpenSessionEntry,
   } = useHistorySettingsController(props);
+  const selectedFolder = folders.find(
+    (folder) => folder.id === organization.folderId,
+  );
+  const [showStats, setShowStats] = useState(false);
 
   if (loading) {
     return (
@@ -398,7 +817,7 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
     );
   }
 
-  if (historyEntries.length === 0) {
+  if (historyEntries.length === 0 && !organizationFilterActive) {
     return (
       <div className={containerClass}>
         <div className="space-y-2">
@@ -409,10 +828,27 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
               </h2>
             </div>
             {!compact && (
-              <OpenRecordingsButton
-                onClick={openRecordingsFolder}
-                label={t("settings.history.openFolder")}
-              />
+              <div className="flex items-center gap-2">
+                <Button
+                  onClick={() => {
+                    void importBundle();
+                  }}
+                  variant="secondary"
+                  size="sm"
+                  className="flex items-center gap-2 rounded-full"
+                >
+                  <Upload className="w-4 h-4" />
+                  <span>
+                    {t("settings.history.bundle.import", {
+                      defaultValue: "Import",
+                    })}
+                  </span>
+                </Button>
+                <OpenRecordingsButton
+                  onClick={openRecordingsFolder}
+                  label={t("settings.history.openFolder")}
+                />
+              </div>
             )}
           </div>
           <div className="overflow-visible rounded-[18px] border border-white/7 bg-white/[0.02]">
@@ -438,21 +874,58 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
               {titleLabel}
             </h1>
           )}
-          {!compact && !meetingsAreActive && (
-            <p className="text-sm text-text/50">
-              {t("settings.history.showingLatest", {
-                count: MAX_VISIBLE_HISTORY,
-              })}
-            </p>
-          )}
         </div>
         {!compact && (
-          <OpenRecordingsButton
-            onClick={openRecordingsFolder}
-            label={t("settings.history.openFolder")}
-          />
+          <div className="flex items-center gap-2">
+            <Button
+              onClick={() => setShowStats(!showStats)}
+              variant={showStats ? "primary-soft" : "secondary"}
+              size="sm"
+              className="flex items-center gap-2 rounded-full"
+            >
+              <BarChart3 className="w-4 h-4" />
+              <span>
+                {t("settings.history.stats.title", { defaultValue: "Usage" })}
+              </span>
+            </Button>
+            <Button
+              onClick={() => {
+                void importBundle();
+              }}
+              variant="secondary"
+              size="sm"
+              className="flex items-center gap-2 rounded-full"
+            >
+              <Upload className="w-4 h-4" />
+              <span>
+                {t("settings.history.bundle.import", {
+                  defaultValue: "Import",
+                })}
+              </span>
+            </Button>
+            <Button
+              onClick={() => {
+                void exportAllEntries();
+              }}
+              variant="secondary"
+              size="sm"
+              className="flex items-center gap-2 rounded-full"
+            >
+              <Download className="w-4 h-4" />
+              <span>
+                {t("settings.history.bundle.exportAll", {
+                  defaultValue: "Export",
+                })}
+              </span>
+            </Button>
+            <OpenRecordingsButton
+              onClick={openRecordingsFolder}
+              label={t("settings.history.openFolder")}
+            />
+          </div>
         )}
       </div>
+      {!compact && showStats && <HistoryStatsPanel />}
       {showTabs && (
         <div className="flex rounded-full border border-white/8 bg-white/[0.025] p-1 w-fit">
           <button
@@ -466,7 +939,7 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
           >
             {t("settings.history.dictations", { defaultValue: "Dictations" })}
             <span className="ml-2 text-xs text-text/42">
-              {dictationEntries.length}
+              {pages.dictations.totalCount}
             </span>
           </button>
           <button
@@ -480,11 +953,126 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
           >
             {t("settings.history.sessions", { defaultValue: "Meetings" })}
             <span className="ml-2 text-xs text-text/42">
-              {sessionEntries.length}
+              {pages.sessions.totalCount}
             </span>
           </button>
         </div>
       )}
+      <div className="flex flex-wrap items-center gap-2">
+        <Input
+          type="search"
+          value={search.query}
+          onChange={(event) => search.setQuery(event.target.value)}
+          placeholder={t("settings.history.searchPlaceholder", {
+            defaultValue: "Search transcriptions",
+          })}
+          className="min-w-[220px] flex-1"
+        />
+        <Dropdown
+          options={[
+            {
+              value: "any",
+              label: t("settings.history.dateRange.any", {
+                defaultValue: "Any time",
+              }),
+            },
+            {
+              value: "week",
+              label: t("settings.history.dateRange.week", {
+                defaultValue: "Past week",
+              }),
+            },
+            {
+              value: "month",
+              label: t("settings.history.dateRange.month", {
+                defaultValue: "Past month",
+              }),
+            },
+            {
+              value: "year",
+              label: t("settings.history.dateRange.year", {
+                defaultValue: "Past year",
+              }),
+            },
+          ]}
+          selectedValue={search.dateRange}
+          onSelect={(value) => search.setDateRange(value as HistoryDateRange)}
+        />
+        <Button
+          type="button"
+          variant={search.savedOnly ? "primary-soft" : "secondary"}
+          size="sm"
+          onClick={() => search.setSavedOnly(!search.savedOnly)}
+          className="flex items-center gap-2 rounded-full"
+        >
+          <Star
+            width={14}
+            height={14}
+            fill={search.savedOnly ? "currentColor" : "none"}
+          />
+          <span>
+            {t("settings.history.savedOnly", { defaultValue: "Saved" })}
+          </span>
+        </Button>
+        {folders.length > 0 && (
+          <Dropdown
+            options={[
+              {
+                value: "all",
+                label: t("settings.history.folders.all", {
+                  defaultValue: "All folders",
+                }),
+              },
+              ...folders.map((folder) => ({
+                value: String(folder.id),
+                label: `${folder.name} (${folder.entry_count})`,
+              })),
+            ]}
+            selectedValue={
+              organization.folderId === null
+                ? "all"
+                : String(organization.folderId)
+            }
+            onSelect={(value) =>
+              setOrganization((current) => ({
+                ...current,
+                folderId: value === "all" ? null : Number(value),
+              }))
+            }
+          />
+        )}
+        {(tags.length > 0 || organization.tag !== null) && (
+          <Dropdown
+            options={[
+              {
+                value: "",
+                label: t("settings.history.tags.all", {
+                  defaultValue: "All tags",
+                }),
+              },
+              ...tags.map((tag) => ({
+                value: tag.name,
+                label: `#${tag.name} (${tag.entry_count})`,
+              })),
+            ]}
+            selectedValue={organization.tag ?? ""}
+            onSelect={(value) =>
+              setOrganization((current) => ({
+                ...current,
+                tag: value === "" ? null : value,
+              }))
+            }
+          />
+        )}
+      </div>
+      {selectedFolder && (
+        <HistoryFolderActions
+          key={selectedFolder.id}
+          folder={selectedFolder}
+          onRename={(name) => renameFolder(selectedFolder, name)}
+          onDelete={() => deleteFolder(selectedFolder)}
+        />
+      )}
       <div className="space-y-2">
         <div className="overflow-visible rounded-[18px] border border-white/7 bg-white/[0.02]">
           {visibleEntries.length === 0 ? (
@@ -497,46 +1085,401 @@ export const HistorySettings: React.FC<HistorySettingsProps> = (props) => {
                 <HistoryEntryComponent
                   key={entry.id}
                   entry={entry}
+                  snippet={searchSnippets.get(entry.id)}
                   highlighted={entry.id === highlightedEntryId}
                   onToggleSaved={() => toggleSaved(entry.id)}
                   onCopyText={(text) => copyToClipboard(text)}
                   onOpenSessionEntry={onOpenSessionEntry}
                   getAudioUrl={getAudioUrl}
                   deleteAudio={deleteAudioEntry}
+                  folders={folders}
+                  onSelectTag={(tag) =>
+                    setOrganization((current) => ({ ...current, tag }))
+                  }
                 />
               ))}
             </div>
           )}
         </div>
+        {remainingCount > 0 && (
+          <div className="flex justify-center">
+            <Button
+              type="button"
+              variant="secondary"
+              size="sm"
+              onClick={() => {
+                void loadMoreEntries();
+              }}
+              className="rounded-full"
+            >
+              {t("settings.history.loadMore", {
+                defaultValue: "Load more ({{count}} older)",
+                count: remainingCount,
+              })}
+            </Button>
+          </div>
+        )}
       </div>
     </div>
   );
 };
 
+interface HistoryFolderActionsProps {
+  folder: HistoryFolder;
+  onRename: (name: string) => Promise<boolean>;
+  onDelete: () => Promise<void>;
+}
+
+const HistoryFolderActions: React.FC<HistoryFolderActionsProps> = ({
+  folder,
+  onRename,
+  onDelete,
+}) => {
+  const { t } = useTranslation();
+  const [renaming, setRenaming] = useState(false);
+  const [name, setName] = useState(folder.name);
+
+  if (renaming) {
+    return (
+      <div className="flex flex-wrap items-center gap-2">
+        <Input
+          variant="compact"
+          value={name}
+          onChange={(event) => setName(event.target.value)}
+          className="min-w-[200px]"
+          autoFocus
+        />
+        <Button
+          type="button"
+          variant="primary-soft"
+          size="sm"
+          disabled={name.trim().length === 0 || name.trim() === folder.name}
+          onClick={() => {
+            void onRename(name).then((renamed) => setRenaming(!renamed));
+          }}
+        >
+          {t("settings.history.folders.save", { defaultValue: "Save" })}
+        </Button>
+        <Button
+          type="button"
+          variant="ghost"
+          size="sm"
+          onClick={() => {
+            setName(folder.name);
+            setRenaming(false);
+          }}
+        >
+          {t("settings.history.folders.cancel", { defaultValue: "Cancel" })}
+        </Button>
+      </div>
+    );
+  }
+
+  return (
+    <div className="flex flex-wrap items-center gap-2 text-sm text-text/60">
+      <span>
+        {t("settings.history.folders.count", {
+          defaultValue: "{{count}} in {{name}}",
+          count: folder.entry_count,
+          name: folder.name,
+        })}
+      </span>
+      <Button
+        type="button"
+        variant="ghost"
+        size="sm"
+        onClick={() => setRenaming(true)}
+      >
+        {t("settings.history.folders.rename", { defaultValue: "Rename" })}
+      </Button>
+      <Button
+        type="button"
+        variant="ghost"
+        size="sm"
+        onClick={() => {
+          void onDelete();
+        }}
+        title={t("settings.history.folders.deleteHint", {
+          defaultValue: "Entries stay in history without a folder.",
+        })}
+      >
+        {t("settings.history.folders.delete", {
+          defaultValue: "Delete folder",
+        })}
+      </Button>
+    </div>
+  );
+};
+
+interface HistoryEntryOrganizerProps {
+  entry: HistoryEntry;
+  folders: HistoryFolder[];
+  onClose: () => void;
+}
+
+// Dropdown value for creating a folder while organizing an entry.
+const NEW_FOLDER_OPTION = "new";
+const NO_FOLDER_OPTION = "none";
+
+const parseTags = (text: string) =>
+  text
+    .split(",")
+    .map((tag) => tag.trim())
+    .filter((tag) => tag.length > 0);
+
+const HistoryEntryOrganizer: React.FC<HistoryEntryOrganizerProps> = ({
+  entry,
+  folders,
+  onClose,
+}) => {
+  const { t } = useTranslation();
+  const entryTags = entry.tags ?? [];
+  const [folderOption, setFolderOption] = useState(
+    entry.folder_id === null ? NO_FOLDER_OPTION : String(entry.folder_id),
+  );
+  const [newFolderName, setNewFolderName] = useState("");
+  const [tagsText, setTagsText] = useState(entryTags.join(", "));
+  const [note, setNote] = useState(entry.note ?? "");
+  const [saving, setSaving] = useState(false);
+  const creatingFolder = folderOption === NEW_FOLDER_OPTION;
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      let folderId =
+        folderOption === NO_FOLDER_OPTION ? null : Number(folderOption);
+      if (creatingFolder) {
+        const folder = unwrapResult(
+          await commands.createHistoryFolder(newFolderName),
+        );
+        folderId = folder.id;
+      }
+      if (folderId !== entry.folder_id) {
+        unwrapResult(await commands.setHistoryEntryFolder(entry.id, folderId));
+      }
+
+      const tags = parseTags(tagsText);
+      if (tags.join("\n") !== entryTags.join("\n")) {
+        unwrapResult(await commands.setHistoryEntryTags(entry.id, tags));
+      }
+
+      if (note.trim() !== (entry.note ?? "")) {
+        unwrapResult(
+          await commands.setHistoryEntryNote(
+            entry.id,
+            note.trim() ? note : null,
+          ),
+        );
+      }
+      onClose();
+    } catch (error) {
+      console.error("Failed to organize entry:", error);
+      toast.error(errorMessage(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div className="space-y-2 rounded-lg border border-white/8 bg-white/[0.02] p-3">
+      <div className="flex flex-wrap items-center gap-2">
+        <Dropdown
+          options={[
+            {
+              value: NO_FOLDER_OPTION,
+              label: t("settings.history.folders.none", {
+                defaultValue: "No folder",
+              }),
+            },
+            ...folders.map((folder) => ({
+              value: String(folder.id),
+              label: folder.name,
+            })),
+            {
+              value: NEW_FOLDER_OPTION,
+              label: t("settings.history.folders.new", {
+                defaultValue: "New folder…",
+              }),
+            },
+          ]}
+          selectedValue={folderOption}
+          onSelect={setFolderOption}
+        />
+        {creatingFolder && (
+          <Input
+            variant="compact"
+            value={newFolderName}
+            onChange={(event) => setNewFolderName(event.target.value)}
+            placeholder={t("settings.history.folders.namePlaceholder", {
+              defaultValue: "Folder name, e.g. a client",
+            })}
+            className="min-w-[200px] flex-1"
+            autoFocus
+          />
+        )}
+      </div>
+      <Input
+        variant="compact"
+        value={tagsText}
+        onChange={(event) => setTagsText(event.target.value)}
+        placeholder={t("settings.history.tags.placeholder", {
+          defaultValue: "Tags, separated by commas",
+        })}
+        className="w-full"
+      />
+      <Textarea
+        value={note}
+        onChange={(event) => setNote(event.target.value)}
+        placeholder={t("settings.history.note.placeholder", {
+          defaultValue: "Add a note",
+        })}
+        className="w-full font-normal"
+      />
+      <div className="flex items-center gap-2">
+        <Button
+          type="button"
+          variant="primary-soft"
+          size="sm"
+          disabled={
+            saving || (creatingFolder && newFolderName.trim().length === 0)
+          }
+          onClick={() => {
+            void handleSave();
+          }}
+        >
+          {t("settings.history.organize.save", { defaultValue: "Save" })}
+        </Button>
+        <Button type="button" variant="ghost" size="sm" onClick={onClose}>
+          {t("settings.history.organize.cancel", { defaultValue: "Cancel" })}
+        </Button>
+      </div>
+    </div>
+  );
+};
+
+interface VocabularySuggestionsProps {
+  suggestions: VocabularySuggestion[];
+  onDismiss: () => void;
+}
+
+// Terms proposed from a correction. Ones custom words can already fix go
+// there; the rest become post-processing vocabulary hints.
+const VocabularySuggestions: React.FC<VocabularySuggestionsProps> = ({
+  suggestions,
+  onDismiss,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting } = useSettings();
+  const [added, setAdded] = useState<string[]>([]);
+
+  const handleAdd = async (suggestion: VocabularySuggestion) => {
+    const key = suggestion.fixable_by_custom_words
+      ? "custom_words"
+      : "custom_vocabulary_terms";
+    const current = getSetting(key) ?? [];
+    await updateSetting(key, [...current, suggestion.corrected]);
+    setAdded((previous) => [...previous, suggestion.corrected]);
+  };
+
+  return (
+    <div className="space-y-2 rounded-lg border border-white/8 bg-white/[0.02] px-3 py-2">
+      <div className="flex items-center justify-between gap-3">
+        <p className="text-xs text-text/60">
+          {t("settings.history.vocabulary.title", {
+            defaultValue: "Add these spellings to your vocabulary?",
+          })}
+        </p>
+        <Button type="button" variant="ghost" size="sm" onClick={onDismiss}>
+          {t("settings.history.vocabulary.dismiss", {
+            defaultValue: "Dismiss",
+          })}
+        </Button>
+      </div>
+      {suggestions.map((suggestion) => {
+        const isAdded = added.includes(suggestion.corrected);
+        return (
+          <div
+            key={suggestion.corrected}
+            className="flex items-center justify-between gap-3 text-sm"
+          >
+            <span className="min-w-0 truncate text-text/74">
+              <span className="text-text/42 line-through">
+                {suggestion.heard}
+              </span>{" "}
+              {suggestion.corrected}
+            </span>
+            <Button
+              type="button"
+              variant="primary-soft"
+              size="sm"
+              disabled={isAdded}
+              onClick={() => {
+                void handleAdd(suggestion);
+              }}
+            >
+              {isAdded
+                ? t("settings.history.vocabulary.added", {
+                    defaultValue: "Added",
+                  })
+                : suggestion.fixable_by_custom_words
+                  ? t("settings.history.vocabulary.addWord", {
+                      defaultValue: "Add to custom words",
+                    })
+                  : t("settings.history.vocabulary.addTerm", {
+                      defaultValue: "Add to vocabulary",
+                    })}
+            </Button>
+          </div>
+        );
+      })}
+    </div>
+  );
+};
+
 interface HistoryEntryProps {
   entry: HistoryEntry;
+  snippet?: string;
   highlighted: boolean;
   onToggleSaved: () => void;
   onCopyText: (text: string) => void;
   onOpenSessionEntry?: (entry: HistoryEntry) => void;
   getAudioUrl: (fileName: string) => Promise<string | null>;
   deleteAudio: (id: number) => Promise<void>;
+  folders: HistoryFolder[];
+  onSelectTag: (tag: string) => void;
 }
 
 const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   entry,
+  snippet,
   highlighted,
   onToggleSaved,
   onCopyText,
   onOpenSessionEntry,
   getAudioUrl,
   deleteAudio,
+  folders,
+  onSelectTag,
 }) => {
   const { t, i18n } = useTranslation();
   const [showCopied, setShowCopied] = useState(false);
+  const [editing, setEditing] = useState(false);
+  const [organizing, setOrganizing] = useState(false);
+  const [retranscribing, setRetranscribing] = useState(false);
+  const [reprocessing, setReprocessing] = useState(false);
+  const [draft, setDraft] = useState("");
+  const [revisions, setRevisions] = useState<HistoryRevision[]>([]);
+  const [suggestions, setSuggestions] = useState<VocabularySuggestion[]>([]);
   const displayText = entry.post_processed_text || entry.transcription_text;
   const previewText = formatHistoryPreviewText(displayText);
   const isSession = entry.recording_source === "full_system_audio";
+  // Edits apply to the text the entry shows and copies, except that a
+  // meeting's transcript is edited rather than its summary.
+  const editableText = isSession ? entry.transcription_text : displayText;
+  const entryTags = entry.tags ?? [];
+  const folderName = folders.find(
+    (folder) => folder.id === entry.folder_id,
+  )?.name;
 
   const handleLoadAudio = useCallback(
     () => getAudioUrl(entry.file_name),
@@ -567,6 +1510,69 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     }
   };
 
+  const startEditing = async () => {
+    setOrganizing(false);
+    setRetranscribing(false);
+    setReprocessing(false);
+    setDraft(editableText);
+    setEditing(true);
+    setSuggestions([]);
+    try {
+      const result = await commands.getHistoryEntryRevisions(entry.id);
+      if (result.status === "ok") {
+        setRevisions(result.data);
+      }
+    } catch (error) {
+      console.error("Failed to load entry revisions:", error);
+    }
+  };
+
+  const handleSaveEdit = async () => {
+    try {
+      const result = await commands.submitHistoryCorrection(entry.id, draft);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      setEditing(false);
+      setSuggestions(result.data);
+    } catch (error) {
+      console.error("Failed to update entry:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  const handleRevert = async (revisionId: number) => {
+    try {
+      const result = await commands.revertHistoryEntry(entry.id, revisionId);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      setEditing(false);
+    } catch (error) {
+      console.error("Failed to restore entry revision:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
+  const handleExportEntry = async () => {
+    try {
+      const summary = await exportHistoryBundle(
+        [entry.id],
+        `uttr-${entry.timestamp}.zip`,
+      );
+      if (summary) {
+        toast.success(
+          t("settings.history.bundle.exportedEntry", {
+            defaultValue: "Exported entry.",
+          }),
+        );
+      }
+    } catch (error) {
+      console.error("Failed to export entry:", error);
+      toast.error(errorMessage(error));
+    }
+  };
+
   const formattedDate = formatDateTime(String(entry.timestamp), i18n.language);
 
   return (
@@ -579,32 +1585,217 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
       }`}
     >
       <div className="flex items-start justify-between gap-4">
-        <div className="space-y-2">
+        <div
+          className={`space-y-2 ${
+            editing ||
+            organizing ||
+            retranscribing ||
+            reprocessing ||
+            suggestions.length > 0
+              ? "min-w-0 flex-1"
+              : ""
+          }`}
+        >
           <p className="text-sm font-medium text-text/86">{formattedDate}</p>
+          {editing ? (
+            <div className="space-y-2">
+              <Textarea
+                value={draft}
+                onChange={(event) => setDraft(event.target.value)}
+                className="w-full font-normal"
+                autoFocus
+              />
+              <div className="flex flex-wrap items-center gap-2">
+                <Button
+                  type="button"
+                  variant="primary-soft"
+                  size="sm"
+                  onClick={() => {
+                    void handleSaveEdit();
+                  }}
+                  disabled={draft.trim().length === 0 || draft === editableText}
+                >
+                  {t("settings.history.edit.save", { defaultValue: "Save" })}
+                </Button>
+                <Button
+                  type="button"
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => setEditing(false)}
+                >
+                  {t("settings.history.edit.cancel", {
+                    defaultValue: "Cancel",
+                  })}
+                </Button>
+                {revisions.length > 0 && (
+                  <Dropdown
+                    options={revisions.map((revision) => ({
+                      value: String(revision.id),
+                      label: t("settings.history.edit.revision", {
+                        defaultValue: "Version replaced {{date}}",
+                        date: formatDateTime(
+                          String(revision.created_at),
+                          i18n.language,
+                        ),
+                      }),
+                    }))}
+                    selectedValue={null}
+                    placeholder={t("settings.history.edit.restore", {
+                      defaultValue: "Restore earlier version",
+                    })}
+                    onSelect={(value) => {
+                      void handleRevert(Number(value));
+                    }}
+                  />
+                )}
+              </div>
+            </div>
+          ) : (
+            <button
+              type="button"
+              onClick={handlePrimaryClick}
+              className={`w-full text-left text-[15px] leading-7 text-text/74 transition-colors hover:text-text ${
+                isSession ? "cursor-pointer" : "cursor-copy"
+              }`}
+              title={
+                isSession
+                  ? t("settings.history.openSession", {
+                      defaultValue: "Open meeting",
+                    })
+                  : t("settings.history.copyToClipboard")
+              }
+              style={{
+                display: "-webkit-box",
+                WebkitLineClamp: 3,
+                WebkitBoxOrient: "vertical",
+                overflow: "hidden",
+              }}
+            >
+              {snippet ? <HighlightedSnippet snippet={snippet} /> : previewText}
+            </button>
+          )}
+          {organizing && (
+            <HistoryEntryOrganizer
+              entry={entry}
+              folders={folders}
+              onClose={() => setOrganizing(false)}
+            />
+          )}
+          {retranscribing && (
+            <HistoryEntryAlternates
+              entry={entry}
+              onClose={() => setRetranscribing(false)}
+            />
+          )}
+          {reprocessing && (
+            <HistoryEntryReprocessor
+              entry={entry}
+              onClose={() => setReprocessing(false)}
+            />
+          )}
+          {!editing &&
+            !organizing &&
+            !retranscribing &&
+            !reprocessing &&
+            (folderName || entryTags.length > 0 || entry.note) && (
+              <div className="space-y-2">
+                {(folderName || entryTags.length > 0) && (
+                  <div className="flex flex-wrap items-center gap-1.5">
+                    {folderName && (
+                      <Badge variant="secondary">{folderName}</Badge>
+                    )}
+                    {entryTags.map((tag) => (
+                      <button
+                        key={tag}
+                        type="button"
+                        onClick={() => onSelectTag(tag)}
+                        className="cursor-pointer"
+                        title={t("settings.history.tags.filter", {
+                          defaultValue: "Show entries tagged {{tag}}",
+                          tag,
+                        })}
+                      >
+                        <Badge variant="primary">#{tag}</Badge>
+                      </button>
+                    ))}
+                  </div>
+                )}
+                {entry.note && (
+                  <p className="whitespace-pre-wrap text-sm italic text-text/60">
+                    {entry.note}
+                  </p>
+                )}
+              </div>
+            )}
+          {!editing && suggestions.length > 0 && (
+            <VocabularySuggestions
+              suggestions={suggestions}
+              onDismiss={() => setSuggestions([])}
+            />
+          )}
+        </div>
+        <div className="flex items-center gap-1 opacity-0 transition-opacity group-hover:opacity-100 group-focus-within:opacity-100">
           <button
             type="button"
-            onClick={handlePrimaryClick}
-            className={`w-full text-left text-[15px] leading-7 text-text/74 transition-colors hover:text-text ${
-              isSession ? "cursor-pointer" : "cursor-copy"
-            }`}
-            title={
-              isSession
-                ? t("settings.history.openSession", {
-                    defaultValue: "Open meeting",
-                  })
-                : t("settings.history.copyToClipboard")
-            }
-            style={{
-              display: "-webkit-box",
-              WebkitLineClamp: 3,
-              WebkitBoxOrient: "vertical",
-              overflow: "hidden",
+            onClick={() => {
+              void startEditing();
             }}
+            className="rounded-lg p-2 text-text/42 transition-colors cursor-pointer hover:bg-white/[0.04] hover:text-text"
+            title={t("settings.history.edit.title", {
+              defaultValue: "Edit transcription",
+            })}
           >
-            {previewText}
+            <Pencil width={16} height={16} />
           </button>
-        </div>
-        <div className="flex items-center gap-1 opacity-0 transition-opacity group-hover:opacity-100 group-focus-within:opacity-100">
+          <button
+            type="button"
+            onClick={() => {
+              setEditing(false);
+              setRetranscribing(false);
+              setReprocessing(false);
+              setOrganizing((current) => !current);
+            }}
+            className="rounded-lg p-2 text-text/42 transition-colors cursor-pointer hover:bg-white/[0.04] hover:text-text"
+            title={t("settings.history.organize.title", {
+              defaultValue: "Tags, note and folder",
+            })}
+          >
+            <Tags width={16} height={16} />
+          </button>
+          {!entry.audio_purged && !isSession && (
+            <button
+              type="button"
+              onClick={() => {
+                setEditing(false);
+                setOrganizing(false);
+                setReprocessing(false);
+                setRetranscribing((current) => !current);
+              }}
+              className="rounded-lg p-2 text-text/42 transition-colors cursor-pointer hover:bg-white/[0.04] hover:text-text"
+              title={t("settings.history.retranscribe.title", {
+                defaultValue: "Transcribe again",
+              })}
+            >
+              <RefreshCw width={16} height={16} />
+            </button>
+          )}
+          {!isSession && (
+            <button
+              type="button"
+              onClick={() => {
+                setEditing(false);
+                setOrganizing(false);
+                setRetranscribing(false);
+                setReprocessing((current) => !current);
+              }}
+              className="rounded-lg p-2 text-text/42 transition-colors cursor-pointer hover:bg-white/[0.04] hover:text-text"
+              title={t("settings.history.reprocess.title", {
+                defaultValue: "Post-process again",
+              })}
+            >
+              <Wand2 width={16} height={16} />
+            </button>
+          )}
           <button
             type="button"
             onClick={handleCopyText}
@@ -637,6 +1828,18 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               fill={entry.saved ? "currentColor" : "none"}
             />
           </button>
+          <button
+            type="button"
+            onClick={() => {
+              void handleExportEntry();
+            }}
+            className="rounded-lg p-2 text-text/42 transition-colors cursor-pointer hover:bg-white/[0.04] hover:text-text"
+            title={t("settings.history.bundle.exportEntry", {
+              defaultValue: "Export entry",
+            })}
+          >
+            <Download width={16} height={16} />
+          </button>
           <button
             type="button"
             onClick={handleDeleteEntry}
@@ -647,10 +1850,23 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
           </button>
         </div>
       </div>
-      <AudioPlayer
-        onLoadRequest={handleLoadAudio}
-        className="w-full border-t border-white/6 pt-3"
-      />
+      {entry.audio_purged ? (
+        <p className="border-t border-white/6 pt-3 text-xs text-text/40">
+          {entry.source_path
+            ? t("settings.history.fileImport", {
+                defaultValue: "Transcribed from {{path}}",
+                path: entry.source_path,
+              })
+            : t("settings.history.audioPurged", {
+                defaultValue: "Recording removed by audio retention",
+              })}
+        </p>
+      ) : (
+        <AudioPlayer
+          onLoadRequest={handleLoadAudio}
+          className="w-full border-t border-white/6 pt-3"
+        />
+      )}
     </div>
   );
 };
@@ -0,0 +1,223 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { listen } from "@tauri-apps/api/event";
+import { Dropdown } from "../../ui/Dropdown";
+import {
+  commands,
+  type HistoryStats,
+  type HistoryStatsPeriod,
+} from "@/bindings";
+
+type StatsRange = "week" | "month" | "year" | "all";
+
+const SECONDS_PER_DAY = 24 * 60 * 60;
+const STATS_RANGE_DAYS: Record<StatsRange, number | null> = {
+  week: 7,
+  month: 31,
+  year: 365,
+  all: null,
+};
+
+const formatDuration = (milliseconds: number) => {
+  const minutes = Math.round(milliseconds / 60_000);
+  if (minutes < 60) {
+    return `${minutes}m`;
+  }
+  return `${Math.floor(minutes / 60)}h ${minutes % 60}m`;
+};
+
+const StatTile: React.FC<{ label: string; value: string }> = ({
+  label,
+  value,
+}) => (
+  <div className="rounded-2xl border border-white/7 bg-white/[0.02] px-4 py-3">
+    <div className="text-[11px] font-medium uppercase tracking-[0.18em] text-text/40">
+      {label}
+    </div>
+    <div className="mt-1 text-xl font-semibold text-text">{value}</div>
+  </div>
+);
+
+const WordsChart: React.FC<{ periods: HistoryStatsPeriod[] }> = ({
+  periods,
+}) => {
+  const maxWords = Math.max(1, ...periods.map((period) => period.word_count));
+  return (
+    <div className="flex h-24 items-end gap-1">
+      {periods.map((period) => (
+        <div
+          key={period.start_date}
+          className="flex-1 rounded-t bg-logo-primary/50"
+          style={{ height: `${(period.word_count / maxWords) * 100}%` }}
+          title={`${period.start_date}: ${period.word_count}`}
+        />
+      ))}
+    </div>
+  );
+};
+
+export const HistoryStatsPanel: React.FC = () => {
+  const { t, i18n } = useTranslation();
+  const [range, setRange] = useState<StatsRange>("month");
+  const [stats, setStats] = useState<HistoryStats | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    const load = async () => {
+      const days = STATS_RANGE_DAYS[range];
+      const from =
+        days === null
+          ? null
+          : Math.floor(Date.now() / 1000) - days * SECONDS_PER_DAY;
+      const result = await commands.getHistoryStats(from, null, null);
+      if (cancelled) {
+        return;
+      }
+      if (result.status === "ok") {
+        setStats(result.data);
+      } else {
+        console.error("Failed to load history stats:", result.error);
+      }
+    };
+
+    void load();
+    const unlisten = listen("history-updated", () => {
+      void load();
+    });
+    return () => {
+      cancelled = true;
+      void unlisten.then((stop) => stop());
+    };
+  }, [range]);
+
+  const numberFormat = new Intl.NumberFormat(i18n.language);
+  // Daily bars get too thin to read beyond a month.
+  const periods =
+    range === "week" || range === "month" ? stats?.days : stats?.weeks;
+  const sourceLabel = (source: string) =>
+    source === "full_system_audio"
+      ? t("settings.history.sessions", { defaultValue: "Meetings" })
+      : source === "dictation"
+        ? t("settings.history.dictations", { defaultValue: "Dictations" })
+        : source;
+
+  return (
+    <div className="space-y-3 rounded-[18px] border border-white/7 bg-white/[0.02] p-4">
+      <div className="flex items-center justify-between">
+        <h2 className="text-[11px] font-medium uppercase tracking-[0.18em] text-text/34">
+          {t("settings.history.stats.title", { defaultValue: "Usage" })}
+        </h2>
+        <Dropdown
+          options={[
+            {
+              value: "week",
+              label: t("settings.history.dateRange.week", {
+                defaultValue: "Past week",
+              }),
+            },
+            {
+              value: "month",
+              label: t("settings.history.dateRange.month", {
+                defaultValue: "Past month",
+              }),
+            },
+            {
+              value: "year",
+              label: t("settings.history.dateRange.year", {
+                defaultValue: "Past year",
+              }),
+            },
+            {
+              value: "all",
+              label: t("settings.history.dateRange.any", {
+                defaultValue: "Any time",
+              }),
+            },
+          ]}
+          selectedValue={range}
+          onSelect={(value) => setRange(value as StatsRange)}
+        />
+      </div>
+      {stats && (
+        <>
+          <div className="grid grid-cols-2 gap-2 sm:grid-cols-4">
+            <StatTile
+              label={t("settings.history.stats.words", {
+                defaultValue: "Words",
+              })}
+              value={numberFormat.format(stats.word_count)}
+            />
+            <StatTile
+              label={t("settings.history.stats.timeSaved", {
+                defaultValue: "Time saved",
+              })}
+              value={formatDuration(stats.time_saved_ms)}
+            />
+            <StatTile
+              label={t("settings.history.stats.wpm", {
+                defaultValue: "Words / min",
+              })}
+              value={
+                stats.average_wpm === null
+                  ? "–"
+                  : numberFormat.format(Math.round(stats.average_wpm))
+              }
+            />
+            <StatTile
+              label={t("settings.history.stats.entries", {
+                defaultValue: "Entries",
+              })}
+              value={numberFormat.format(stats.entry_count)}
+            />
+          </div>
+          {periods && periods.length > 0 && <WordsChart periods={periods} />}
+          <p className="text-xs text-text/50">
+            {t("settings.history.stats.timeSavedHint", {
+              defaultValue:
+                "Time saved compares dictations with typing at {{wpm}} words per minute.",
+              wpm: stats.typing_wpm,
+            })}
+          </p>
+          {stats.sources.length > 0 && (
+            <div className="flex flex-wrap gap-x-4 gap-y-1 text-sm text-text/70">
+              {stats.sources.map((source) => (
+                <span key={source.recording_source}>
+                  {sourceLabel(source.recording_source)}:{" "}
+                  {t("settings.history.stats.sourceUsage", {
+                    defaultValue: "{{entries}} entries · {{words}} words",
+                    entries: numberFormat.format(source.entry_count),
+                    words: numberFormat.format(source.word_count),
+                  })}
+                </span>
+              ))}
+            </div>
+          )}
+          {stats.apps.length > 0 && (
+            <div className="space-y-1">
+              <h3 className="text-xs font-medium text-text/60">
+                {t("settings.history.stats.apps", {
+                  defaultValue: "Most used apps",
+                })}
+              </h3>
+              {stats.apps.map((app) => (
+                <div
+                  key={app.app_name}
+                  className="flex justify-between text-sm text-text/80"
+                >
+                  <span className="truncate">{app.app_name}</span>
+                  <span className="text-text/50">
+                    {t("settings.history.stats.appUsage", {
+                      defaultValue: "{{entries}} entries · {{words}} words",
+                      entries: numberFormat.format(app.entry_count),
+                      words: numberFormat.format(app.word_count),
+                    })}
+                  </span>
+                </div>
+              ))}
+            </div>
+          )}
+        </>
+      )}
+    </div>
+  );
+};
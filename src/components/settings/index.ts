@@ -18,5 +18,8 @@ export { PostProcessingToggle } from "./PostProcessingToggle";
 export { PostProcessingSettingsApi } from "./PostProcessingSettingsApi";
 export { HistoryLimit } from "./HistoryLimit";
 export { RecordingRetentionPeriodSelector } from "./RecordingRetentionPeriod";
+export { SourceRetentionPeriods } from "./SourceRetentionPeriods";
+export { HistoryEncryptionToggle } from "./HistoryEncryptionToggle";
+export { HistoryVaultFolder } from "./HistoryVaultFolder";
 export { AutostartToggle } from "./AutostartToggle";
 export { UpdateChecksToggle } from "./UpdateChecksToggle";
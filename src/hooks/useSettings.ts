@@ -47,6 +47,11 @@ interface UseSettingsReturn {
     apiKey: string,
   ) => Promise<void>;
   updatePostProcessModel: (providerId: string, model: string) => Promise<void>;
+  updateCustomTranscriptionServer: (
+    baseUrl: string,
+    model: string,
+  ) => Promise<void>;
+  updateCustomTranscriptionApiKey: (apiKey: string) => Promise<void>;
   validateByokGroqKey: () => Promise<void>;
   fetchPostProcessModels: (providerId: string) => Promise<string[]>;
 }
@@ -84,6 +89,8 @@ export const useSettings = (): UseSettingsReturn => {
     updatePostProcessBaseUrl: store.updatePostProcessBaseUrl,
     updatePostProcessApiKey: store.updatePostProcessApiKey,
     updatePostProcessModel: store.updatePostProcessModel,
+    updateCustomTranscriptionServer: store.updateCustomTranscriptionServer,
+    updateCustomTranscriptionApiKey: store.updateCustomTranscriptionApiKey,
     validateByokGroqKey: store.validateByokGroqKey,
     fetchPostProcessModels: store.fetchPostProcessModels,
   };
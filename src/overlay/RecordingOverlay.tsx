@@ -46,6 +46,7 @@ const SUSTAINED_SPEECH_MIN_ENERGY = 0.12;
 const EFFECTIVE_WAVE_ENERGY_CAP = 0.56;
 const SUSTAINED_SPEECH_ENERGY_DECAY = 0.9;
 const IOS9_BASELINE_OFFSET_PX = 6;
+const PARTIAL_TEXT_MAX_CHARS = 48;
 const RECORDING_CURVES = [
   { color: "255,255,255", supportLine: true },
   { color: "102,217,255" },
@@ -58,6 +59,18 @@ const clamp = (value: number, min: number, max: number) =>
 const lerp = (start: number, end: number, amount: number) =>
   start + (end - start) * amount;
 
+// Keep the newest words visible; the overlay only has room for one line.
+const trimPartialText = (text: string) => {
+  const trimmed = text.trim();
+  if (trimmed.length <= PARTIAL_TEXT_MAX_CHARS) {
+    return trimmed;
+  }
+
+  const tail = trimmed.slice(-PARTIAL_TEXT_MAX_CHARS);
+  const wordStart = tail.indexOf(" ");
+  return `…${wordStart >= 0 ? tail.slice(wordStart + 1) : tail}`;
+};
+
 const startOverlayDrag = (event: React.MouseEvent<HTMLDivElement>) => {
   if (event.button !== 0) {
     return;
@@ -76,6 +89,7 @@ const useRecordingOverlayController = () => {
   const [overlayAlert, setOverlayAlert] = useState<OverlayAlertKind | null>(
     null,
   );
+  const [partialText, setPartialText] = useState("");
   const waveContainerRef = useRef<HTMLDivElement | null>(null);
   const siriWaveRef = useRef<SiriWave | null>(null);
   const waveMetricsRef = useRef({ width: 0, height: 0, ratio: 1 });
@@ -97,6 +111,8 @@ const useRecordingOverlayController = () => {
   const isProcessingState = state === "transcribing" || state === "processing";
   const shouldShowOverlayAlert = overlayAlert !== null;
   const shouldShowWarmingPane = isWarmingState && !shouldShowOverlayAlert;
+  const shouldShowPartialPane =
+    isRecordingState && !shouldShowOverlayAlert && partialText.length > 0;
 
   const setOverlayVisibility = (visible: boolean) => {
     isVisibleRef.current = visible;
@@ -116,6 +132,7 @@ const useRecordingOverlayController = () => {
   const setOverlayMode = (nextState: OverlayState) => {
     overlayStateRef.current = nextState;
     setState(nextState);
+    setPartialText("");
     if (nextState !== "recording") {
       resetSpeechTracking();
     }
@@ -265,8 +282,13 @@ const useRecordingOverlayController = () => {
     const unlistenFns: Array<() => void> = [];
 
     const setupEventListeners = async () => {
-      const [unlistenShow, unlistenHide, unlistenAlert, unlistenLevel] =
-        await Promise.all([
+      const [
+        unlistenShow,
+        unlistenHide,
+        unlistenAlert,
+        unlistenPartial,
+        unlistenLevel,
+      ] = await Promise.all([
           listen("show-overlay", async (event) => {
             const overlayState = event.payload as OverlayState;
             setOverlayVisibility(true);
@@ -283,6 +305,7 @@ const useRecordingOverlayController = () => {
           listen("hide-overlay", () => {
             lastHideAtRef.current = Date.now();
             setAlertKind(null);
+            setPartialText("");
             resetSpeechTracking();
             setOverlayVisibility(false);
             syncSiriWaveForOverlay();
@@ -291,6 +314,12 @@ const useRecordingOverlayController = () => {
             setAlertKind(event.payload);
             syncSiriWaveForOverlay();
           }),
+          listen<string>("overlay-partial", (event) => {
+            if (overlayStateRef.current !== "recording") {
+              return;
+            }
+            setPartialText(trimPartialText(event.payload));
+          }),
           listen<number[]>("mic-level", (event) => {
             const newLevels = event.payload as number[];
             // Fallback only when hidden: if this webview missed `show-overlay`,
@@ -406,6 +435,7 @@ const useRecordingOverlayController = () => {
         unlistenShow();
         unlistenHide();
         unlistenAlert();
+        unlistenPartial();
         unlistenLevel();
         return;
       }
@@ -414,6 +444,7 @@ const useRecordingOverlayController = () => {
         unlistenShow,
         unlistenHide,
         unlistenAlert,
+        unlistenPartial,
         unlistenLevel,
       );
     };
@@ -511,6 +542,8 @@ const useRecordingOverlayController = () => {
     isProcessingState,
     shouldShowOverlayAlert,
     shouldShowWarmingPane,
+    shouldShowPartialPane,
+    partialText,
     waveContainerRef,
     overlayAlertTitle,
     overlayAlertDescription,
@@ -527,6 +560,8 @@ const RecordingOverlay: React.FC = () => {
     isProcessingState,
     shouldShowOverlayAlert,
     shouldShowWarmingPane,
+    shouldShowPartialPane,
+    partialText,
     waveContainerRef,
     overlayAlertTitle,
     overlayAlertDescription,
@@ -551,9 +586,14 @@ const RecordingOverlay: React.FC = () => {
             shouldShowOverlayAlert || shouldShowWarmingPane
               ? "siriwave-host-hidden"
               : ""
-          }`}
+          } ${shouldShowPartialPane ? "siriwave-host-dimmed" : ""}`}
           aria-hidden
         />
+        {shouldShowPartialPane && (
+          <output className="overlay-partial-pane" aria-live="polite">
+            <div className="overlay-partial-text">{partialText}</div>
+          </output>
+        )}
         {shouldShowWarmingPane && (
           <output className="overlay-status-pane" aria-live="polite">
             <div className="overlay-status-title">{warmingTitle}</div>
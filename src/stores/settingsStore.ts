@@ -5,6 +5,7 @@ import type {
   AppSettings as Settings,
   AudioDevice,
   InstallAccessSnapshot,
+  VoiceCommand,
 } from "@/bindings";
 import { commands } from "@/bindings";
 import type { BrowserE2ETestState } from "@/types/browserE2E";
@@ -80,6 +81,11 @@ interface SettingsStore {
     apiKey: string,
   ) => Promise<void>;
   updatePostProcessModel: (providerId: string, model: string) => Promise<void>;
+  updateCustomTranscriptionServer: (
+    baseUrl: string,
+    model: string,
+  ) => Promise<void>;
+  updateCustomTranscriptionApiKey: (apiKey: string) => Promise<void>;
   validateByokGroqKey: () => Promise<void>;
   fetchPostProcessModels: (providerId: string) => Promise<string[]>;
   setPostProcessModelOptions: (providerId: string, models: string[]) => void;
@@ -139,6 +145,12 @@ const settingUpdaters: {
     ),
   recording_retention_period: (value) =>
     commands.updateRecordingRetentionPeriod(value as string),
+  meeting_retention_period: (value) =>
+    commands.updateMeetingRetentionPeriod(value as string),
+  dictation_audio_retention_period: (value) =>
+    commands.updateAudioRetentionPeriod(false, value as string),
+  meeting_audio_retention_period: (value) =>
+    commands.updateAudioRetentionPeriod(true, value as string),
   translate_to_english: (value) =>
     commands.changeTranslateToEnglishSetting(value as boolean),
   selected_language: (value) =>
@@ -160,6 +172,8 @@ const settingUpdaters: {
   auto_submit: (value) => commands.changeAutoSubmitSetting(value as boolean),
   auto_submit_key: (value) =>
     commands.changeAutoSubmitKeySetting(value as string),
+  transcription_fallback_policy: (value) =>
+    commands.changeTranscriptionFallbackPolicySetting(value as string),
   history_limit: (value) => commands.updateHistoryLimit(value as number),
   post_process_enabled: (value) =>
     commands.changePostProcessEnabledSetting(value as boolean),
@@ -174,6 +188,12 @@ const settingUpdaters: {
     commands.changeMuteWhileRecordingSetting(value as boolean),
   append_trailing_space: (value) =>
     commands.changeAppendTrailingSpaceSetting(value as boolean),
+  inverse_text_normalization_enabled: (value) =>
+    commands.changeInverseTextNormalizationSetting(value as boolean),
+  voice_commands_enabled: (value) =>
+    commands.changeVoiceCommandsEnabledSetting(value as boolean),
+  voice_commands: (value) =>
+    commands.changeVoiceCommandsSetting(value as VoiceCommand[]),
   log_level: (value) => commands.setLogLevel(value as any),
   app_language: (value) => commands.changeAppLanguageSetting(value as string),
   show_tray_icon: (value) =>
@@ -648,6 +668,48 @@ export const useSettingsStore = create<SettingsStore>()(
       return get().updatePostProcessSetting("model", providerId, model);
     },
 
+    updateCustomTranscriptionServer: async (baseUrl, model) => {
+      const { setUpdating, refreshSettings } = get();
+      const updateKey = "custom_transcription_server";
+
+      setUpdating(updateKey, true);
+
+      try {
+        const result = await commands.changeCustomTranscriptionServerSetting(
+          baseUrl,
+          model,
+        );
+        if (result.status === "error") {
+          throw new Error(result.error);
+        }
+        await refreshSettings();
+      } catch (error) {
+        console.error("Failed to update custom transcription server:", error);
+      } finally {
+        setUpdating(updateKey, false);
+      }
+    },
+
+    updateCustomTranscriptionApiKey: async (apiKey) => {
+      const { setUpdating, refreshSettings } = get();
+      const updateKey = "custom_transcription_api_key";
+
+      setUpdating(updateKey, true);
+
+      try {
+        const result =
+          await commands.changeCustomTranscriptionApiKeySetting(apiKey);
+        if (result.status === "error") {
+          throw new Error(result.error);
+        }
+        await refreshSettings();
+      } catch (error) {
+        console.error("Failed to update custom transcription API key:", error);
+      } finally {
+        setUpdating(updateKey, false);
+      }
+    },
+
     fetchPostProcessModels: async (providerId) => {
       const updateKey = `post_process_models_fetch:${providerId}`;
       const { setUpdating, setPostProcessModelOptions } = get();
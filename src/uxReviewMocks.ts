@@ -369,10 +369,28 @@ const installInitialNavigation = () => {
           support: review.fullSystemAudio.supportStatus,
           readiness: review.fullSystemAudio.readinessStatus,
         };
-      case "get_history_entries":
-        return review.historyEntries;
+      case "get_history_entries": {
+        const source = args.filter?.source ?? "all";
+        const entries = review.historyEntries.filter((entry: any) =>
+          source === "all"
+            ? true
+            : source === "file_imports"
+              ? entry.recording_source === "file_import"
+              : (source === "meetings") ===
+                (entry.recording_source === "full_system_audio"),
+        );
+        return { entries, next_cursor: null, total_count: entries.length };
+      }
+      case "get_history_folders":
+      case "get_history_tags":
+        return [];
       case "get_audio_file_path":
-        return `/tmp/${args.fileName || "uttr-review.wav"}`;
+        return {
+          path: `/tmp/${args.fileName || "uttr-review.wav"}`,
+          temporary: false,
+        };
+      case "release_playable_audio":
+        return null;
       case "start_full_system_audio_session":
         review.sessionWindowState = sessionStates.live;
         dispatchTauriEvent("session-window-state", review.sessionWindowState);